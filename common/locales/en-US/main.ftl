@@ -71,6 +71,7 @@ warning-messages = Warning Messages
 
 messages = Messages
     .edit = Edit
+    .custom-emojis = Custom
     .cancel-edit = Cancel Edit
     .new = New Message
     .replying = Replying to:
@@ -101,11 +102,22 @@ messages = Messages
     .pin-button-unpin = Unpin
     .pinned-none = There are no pinned messages in this chat
     .pinned-max = You reached the maximum amount of pinned messages for this chat
+    .shared = Shared
+    .shared-media = Media
+    .shared-files = Files
+    .shared-links = Links
+    .shared-none = Nothing was shared in this chat yet
     .missing-emoji-picker = Emoji extension is disabled
     .unknown-identity = User not found:
     .emoji-suggestion = Suggested Emoji
     .username-suggestion = Suggested Users
     .control-group = More
+    .voice-message = Record a voice message
+    .voice-message-send = Send voice message
+    .voice-message-cancel = Discard recording
+    .voice-message-failed = Couldn't record a voice message: { $error }
+    .voice-message-play = Play / Pause
+    .voice-message-speed = Playback speed
     
 favorites = Favorites
     .favorites = Favorites
@@ -118,6 +130,21 @@ friends = Friends
     .add = Add Someone
     .go-to-friends = Go to Friends
     .placeholder = Username#0000...
+    .scan-qr = Scan a QR code image
+    .paste-qr = Paste QR code image
+    .qr-not-found = No QR code could be read from the image
+    .request-note-placeholder = Add a note, sent as your first message once they accept (optional)
+    .mutual-groups = Mutual groups: { $names }
+    .verify = Verify
+    .verified = Verified
+    .verify-description = Compare these words or numbers with what { $name } sees, in person or over a call you trust. If they match, you are talking to the right person.
+    .verify-scan = Scan their code
+    .verify-match = The safety numbers match!
+    .verify-mismatch = The safety numbers don't match. This may not be who you think it is.
+    .mark-verified = Mark as verified
+    .unverify = Remove verification
+    .key-changed = Safety number changed
+    .key-changed-description = This is not who you verified. Either your own account was made again, or the account you verified is no longer your friend and this one uses its name, which may be someone pretending to be them. Verify them again before trusting them.
     .block = Block
     .all = All Friends
     .remove = Unfriend
@@ -151,12 +178,38 @@ friends = Friends
     .share-to-chat = Share
     .search = Search Friends
     .search-placeholder = Search Friend
+    .tags = Tags
+    .edit-tags = Edit Tags
+    .add-tag-placeholder = Add a tag, press enter to save
+    .remove-tag = Remove Tag
+    .all-tags = All
+    .start-tag-group = Group Chat With Everyone
+    .sort-alphabetical = Alphabetical
+    .sort-online-first = Online First
+    .sort-recently-chatted = Recently Chatted
+    .online = Online
+    .offline = Offline
+    .recent = Recent
+    .export-contacts = Export Contacts
+    .import-contacts = Import Contacts
+    .import = Import
+    .contacts-exported = Contacts exported!
+    .contacts-export-failed = Couldn't export the contacts: { $error }
+    .contacts-import-failed = Couldn't read the contacts: { $error }
+    .contacts-imported = { $requests } friend requests sent, { $blocked } users blocked.
+    .contacts-import-errors = { $count } contacts couldn't be imported.
+    .import-summary = { $new } new, { $friends } already friends, { $pending } pending, { $blocked } blocked
+    .import-new = New
+    .import-friend = Friend
+    .import-block = Will Be Blocked
+    .import-own = You
 
 files = Files
     .file = File
     .files = Files
     .new-folder = New Folder
     .upload = Upload
+    .upload-folder = Upload Folder
     .storage-max-size = Max Size:
     .storage-current-size = Used Space:
     .rename = Rename
@@ -166,11 +219,17 @@ files = Files
     .one-file-to-upload = File to Upload 1!
     .files-to-upload = Files to Upload { $num }!
     .no-thumbnail-preview = No Thumbnail available for preview
+    .image-size-reduced = { $before } → { $after }
+    .play = Play
+    .preview-truncated = Only the beginning of this file is shown. Download it to see everything.
     .file-already-opened = File already opened
     .directory-already-with-name = There is already a directory with this name
     .no-size-available = No size available for file: { $file }
     .file-already-with-name = There is already a file with this name
     .uploading-file = Uploading File...
+    .uploading-folder = Uploading Folder... { $done }/{ $total }
+    .not-enough-space = Not enough space available
+    .upload-failures = { $count } file(s) couldn't be uploaded: { $files }
     .finishing-upload = Finishing...
     .cancelling-upload = Cancelling...
     .checking-duplicated-name = Checking duplicated name...
@@ -187,6 +246,99 @@ files = Files
     .go-to-files = Go to Files
     .download-failed = Failed to download file { $file }
     .download-success = Downloaded file { $file }
+    .zip-incomplete = Some files could not be added to the zip, so it was removed.
+    .name-conflict = Name Conflict
+    .name-conflict-description = { $count } item(s) with the same name already exist in { $folder }: { $names }
+    .keep-both = Keep Both
+    .move-failures = { $count } item(s) couldn't be moved: { $names }
+    .copy-failures = { $count } item(s) couldn't be copied: { $names }
+    .move-inside-itself = A folder can't be moved inside itself
+    .copy-inside-itself = A folder can't be copied inside itself
+    .replace-itself = An item can't replace the folder it is in, or itself
+    .name-taken = { $name } already exists in this folder
+    .reserved-name = { $name } is reserved for the trash
+    .replace = Replace
+    .skip = Skip
+    .folder = Folder
+    .search-placeholder = Search all files...
+    .no-results = No files found
+    .all-types = All Types
+    .images = Images
+    .videos = Videos
+    .audio = Audio
+    .documents = Documents
+    .any-size = Any Size
+    .small-files = Under 1 MB
+    .medium-files = 1 MB to 100 MB
+    .large-files = Over 100 MB
+    .any-date = Any Date
+    .today = Today
+    .past-week = Past Week
+    .past-month = Past Month
+    .past-year = Past Year
+    .sort-name = Name
+    .sort-size = Size
+    .sort-date = Date Added
+    .sort-type = Type
+    .ascending = Ascending
+    .descending = Descending
+    .grid-view = Grid View
+    .list-view = List View
+    .trash = Trash
+    .move-to-trash = Move to Trash
+    .trash-empty = The trash is empty
+    .trash-description = Deleted items are removed for good after { $days } days.
+    .trash-description-never = Deleted items stay here until the trash is emptied.
+    .trash-size = In Trash:
+    .restore = Restore
+    .delete-permanently = Delete Permanently
+    .empty-trash = Empty Trash
+    .empty-trash-confirm = All { $count } item(s) in the trash will be deleted permanently. This can't be undone.
+    .deleted-from = From { $path }
+    .days-left = { $days } day(s) left
+    .back-to-files = Back to Files
+    .transfers = Transfers
+    .no-transfers = No uploads or downloads yet.
+    .clear-finished = Clear Finished
+    .pause = Pause
+    .resume = Resume
+    .retry = Retry
+    .retry-failed = Couldn't retry { $file }
+    .transfer-speed = { $size }/s
+    .transfer-eta = { $time } left
+    .transfer-paused = Paused
+    .transfer-interrupted = Interrupted
+    .transfer-failed = Failed: { $reason }
+    .transfer-completed = Completed
+    .transfer-cancelled = Cancelled
+    .duplicates = Duplicates
+    .duplicates-description = Files which were uploaded more than once. Only files uploaded from this device are checked.
+    .duplicates-wasted = { $size } can be freed by keeping one copy of each file.
+    .no-duplicates = No duplicate files found.
+    .duplicate-copies = { $count } copies of { $size }
+    .keep-first = Keep Oldest
+    .duplicate-upload = { $file } is already in your storage at { $path }
+    .attachment-in-storage = { $file } is already in your storage at { $path }
+    .use-from-storage = Send from Storage
+    .sync-synced = Synced with your sync folder
+    .sync-syncing = Syncing
+    .sync-conflict = Conflicted copy, both versions were changed
+    .sync-failed = Sync failed: { $reason }
+    .sync-too-many-deletes = Sync stopped because it would delete { $count } files. If that's intended, delete them on the other side as well.
+    .usage = Storage Usage
+    .usage-quota = { $used } of { $max } used
+    .usage-by-type = By Type
+    .usage-by-folder = By Folder
+    .usage-files = { $count } file(s)
+    .largest-files = Largest Files
+    .other-files = Other
+    .open-folder = Open Folder
+    .no-usage = Nothing is stored yet.
+    .gallery-position = { $position } of { $count }
+    .zoom-in = Zoom In
+    .zoom-out = Zoom Out
+    .rotate = Rotate
+    .jump-to-message = Jump to Message
 
 settings = Settings
     .settings = Settings
@@ -219,6 +371,11 @@ settings-profile = Profile Settings
     .welcome-cta = First step, pick out a profile picture and maybe even a banner too!
     .copy-id = Copy ID
     .copy-did = Copy DID Key
+    .qr-code = QR Code
+    .qr-code-description = Others can scan this code to add you as a friend.
+    .save-qr = Save as PNG
+    .qr-saved = QR code saved!
+    .qr-save-failed = Failed to save the QR code
     .online-status = Online Status
     .online-status-description = Set the appearance of your online status
     .status-online = Online
@@ -251,6 +408,21 @@ settings-messages = Message Settings
     .emoji-conversion-description = Convert Emoji text like ':)' into an emoji symbol like '😊'.
     .markdown-support = Markdown support
     .markdown-support-description = Enables the support of the Markdown markup language in messaging. 
+    .link-previews = Link Previews
+    .link-previews-description = Shows a preview of the first link in a message. Fetching a preview contacts the linked website, which can see your IP address.
+    .link-previews-own-messages = Only Preview My Links
+    .link-previews-own-messages-description = Only fetch previews for links in messages you sent yourself.
+    .link-previews-whitelist = Trusted Domains Only
+    .link-previews-whitelist-description = Only fetch previews for links pointing to the domains listed below.
+    .link-previews-domains = Trusted Domains
+    .link-previews-domains-description = Comma separated list of domains. Subdomains are included. Press enter to save.
+    .emoji-packs = Custom Emoji Packs
+    .emoji-packs-description = Import a folder or a zip file with a manifest.json and images, then use its emojis and stickers with their :shortcode:. Friends without the pack will see the shortcode.
+    .import-folder = Import Folder
+    .import-zip = Import Zip
+    .emoji-pack-imported = Emoji pack imported.
+    .emoji-pack-import-failed = Failed to import the emoji pack: { $error }
+    .emoji-pack-count = { $num } emojis and stickers
 
 settings-privacy = Settings Privacy 
     .backup-recovery-phrase = Backup Recovery Phrase
@@ -281,9 +453,27 @@ settings-audio = Audio & Sound Settings
 
 settings-files = Files Settings
     .local-sync = Local Sync
-    .local-sync-description = When enabled, files will be synced to your local machine.
+    .local-sync-description = Keep a folder on this device and a folder in your storage the same. Changes on either side are copied to the other.
     .open-sync-folder = Open Sync Folder
     .open-sync-folder-description = Open the folder where your files are synced to.
+    .sync-folder = Sync Folder
+    .no-sync-folder = No folder chosen yet.
+    .choose-sync-folder = Choose Folder
+    .sync-storage-folder = Storage Folder
+    .sync-storage-folder-description = The folder in your storage which is kept in sync. Press enter to save.
+    .sync-root-not-allowed = Choose a folder inside your storage, the whole storage can't be synced.
+    .strip-metadata = Strip Image Metadata
+    .strip-metadata-description = Removes EXIF data such as the location and camera details from photos before they are sent or uploaded.
+    .resize-images = Resize Large Images
+    .resize-images-description = Downscales photos before they are sent or uploaded so they take less time and space. Resized photos are re-encoded, which loses some quality.
+    .max-image-size = Maximum Image Size
+    .max-image-size-description = The longest side of a resized image, in pixels.
+    .image-quality = Image Quality
+    .image-quality-description = The quality resized JPEG photos are saved with. Lower values give smaller files.
+    .trash-retention = Empty Trash After
+    .trash-retention-description = Deleted files are kept in the trash for this long before they are removed for good.
+    .days = { $days } days
+    .never = Never
 
 settings-keybinds = Keybind Settings
     .reset = Revert
@@ -424,6 +614,10 @@ quickprofile = Quick Profile
     .volume = User Volume
     .friend-request = Send Friend Request
     .pending-friend-request = Pending Friend Request
+    .nickname = Nickname
+    .nickname-placeholder = Only you see it, press enter to save
+    .note = Note
+    .note-placeholder = A private note, press enter to save
 
 toast_actions = Toast Actions
     .DisplayChat = Open Chat
@@ -3,8 +3,10 @@ pub mod notifications;
 pub mod sounds;
 pub mod state;
 pub mod testing;
+pub mod transfers;
 pub mod upload_file_channel;
 pub mod utils;
+pub mod voice_message;
 pub mod warp_runner;
 
 use anyhow::bail;
@@ -117,6 +119,20 @@ pub struct StaticArgs {
     pub crash_logs: PathBuf,
     /// recordings
     pub recordings: PathBuf,
+    /// custom emoji and sticker packs, one folder with a manifest.json per pack
+    pub emoji_packs_path: PathBuf,
+    /// fetched link previews, so that a site isn't contacted every time a message renders
+    pub link_preview_cache: PathBuf,
+    /// where items in the storage trash came from and when they were deleted
+    pub trash_index: PathBuf,
+    /// uploads and downloads which didn't finish, so they can be resumed
+    pub transfers_path: PathBuf,
+    /// the content hashes of storage files, used to find duplicates
+    pub hash_index: PathBuf,
+    /// notes of sent friend requests, which are sent once the request is accepted
+    pub request_notes: PathBuf,
+    /// the files which were in sync after the last local sync
+    pub sync_snapshot: PathBuf,
     /// seconds
     pub typing_indicator_refresh: u64,
     /// seconds
@@ -158,6 +174,13 @@ pub static STATIC_ARGS: Lazy<StaticArgs> = Lazy::new(|| {
         extensions_path: uplink_container.join("extensions"),
         crash_logs: uplink_container.join("crash-logs"),
         recordings: uplink_container.join("recordings"),
+        emoji_packs_path: uplink_container.join("emoji_packs"),
+        link_preview_cache: uplink_path.join("link_previews.json"),
+        trash_index: uplink_path.join("trash.json"),
+        transfers_path: uplink_path.join("transfers.json"),
+        hash_index: uplink_path.join("hashes.json"),
+        request_notes: uplink_path.join("request_notes.json"),
+        sync_snapshot: uplink_path.join("sync.json"),
         mock_cache_path: uplink_path.join("mock-state.json"),
         warp_path: warp_path.clone(),
         logger_path: uplink_path.join("debug.log"),
@@ -192,6 +215,9 @@ pub const MAX_FILES_PER_MESSAGE: usize = 8;
 
 pub const ROOT_DIR_NAME: &str = "root";
 
+// deleted storage items are kept in this directory of the root until the trash is emptied
+pub const TRASH_DIR_NAME: &str = ".trash";
+
 pub const VIDEO_FILE_EXTENSIONS: &[&str] =
     &[".mp4", ".mov", ".avi", ".flv", ".wmv", ".m4v", ".3gp"];
 
@@ -199,6 +225,10 @@ pub const AUDIO_FILE_EXTENSIONS: &[&str] = &[".mp3", ".wav", ".ogg", ".flac", ".
 
 pub const DOC_EXTENSIONS: &[&str] = &[".doc", ".docx", ".pdf", ".txt"];
 
+pub const IMAGE_FILE_EXTENSIONS: &[&str] = &[
+    ".png", ".jpg", ".jpeg", ".gif", ".webp", ".svg", ".bmp", ".ico",
+];
+
 pub fn is_video(file_name: &str) -> bool {
     VIDEO_FILE_EXTENSIONS
         .iter()
@@ -211,6 +241,18 @@ pub fn is_audio(file_name: &str) -> bool {
         .any(|x| file_name.to_lowercase().ends_with(x))
 }
 
+pub fn is_image(file_name: &str) -> bool {
+    IMAGE_FILE_EXTENSIONS
+        .iter()
+        .any(|x| file_name.to_lowercase().ends_with(x))
+}
+
+pub fn is_document(file_name: &str) -> bool {
+    DOC_EXTENSIONS
+        .iter()
+        .any(|x| file_name.to_lowercase().ends_with(x))
+}
+
 pub fn return_correct_icon(file_name: &str) -> Icon {
     if is_video(file_name) {
         return Icon::DocumentMedia;
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, rc::Weak};
+use std::{collections::HashMap, path::PathBuf, rc::Weak};
 
 use derive_more::Display;
 
@@ -13,9 +13,12 @@ use crate::warp_runner::ui_adapter;
 
 use super::{
     call,
+    contacts::FriendSort,
+    emoji_packs::EmojiPacks,
     identity::Identity,
     notifications::NotificationKind,
     route::To,
+    storage::{StorageLayout, StorageSort},
     ui::{EmojiDestination, Font, Theme, ToastNotification, WindowMeta},
 };
 
@@ -56,6 +59,9 @@ pub enum Action<'a> {
     TrackEmojiUsage(String),
     #[display(fmt = "SetEmojiPickerVisible")]
     SetEmojiPickerVisible(bool),
+    /// custom emoji and sticker packs, reloaded after one is imported or removed
+    #[display(fmt = "SetEmojiPacks")]
+    SetEmojiPacks(EmojiPacks),
     #[display(fmt = "SetTransformMarkdownText")]
     SetTransformMarkdownText(bool),
     #[display(fmt = "SetTransformAsciiEmojis")]
@@ -141,6 +147,21 @@ pub enum Action<'a> {
     Block(&'a DID),
     #[display(fmt = "Unblock")]
     Unblock(&'a DID),
+    /// a nickname which is only seen by the user. a blank one removes it
+    #[display(fmt = "SetNickname")]
+    SetNickname(&'a DID, String),
+    /// a private note about someone
+    #[display(fmt = "SetContactNote")]
+    SetContactNote(&'a DID, String),
+    #[display(fmt = "AddContactTag")]
+    AddContactTag(&'a DID, String),
+    #[display(fmt = "RemoveContactTag")]
+    RemoveContactTag(&'a DID, String),
+    /// remembers the current safety number with someone, after the user compared it with them
+    #[display(fmt = "VerifyContact")]
+    VerifyContact(&'a DID),
+    #[display(fmt = "UnverifyContact")]
+    UnverifyContact(&'a DID),
     /// Handles the display of "favorite" chats
     #[display(fmt = "Favorite")]
     Favorite(Uuid),
@@ -234,4 +255,34 @@ pub enum ConfigAction {
     SetAutoEnableExtensions(bool),
     #[display(fmt = "SetEchoCancellation {_0}")]
     SetEchoCancellation(bool),
+    #[display(fmt = "SetLinkPreviewsEnabled {_0}")]
+    SetLinkPreviewsEnabled(bool),
+    #[display(fmt = "SetLinkPreviewsOwnMessagesOnly {_0}")]
+    SetLinkPreviewsOwnMessagesOnly(bool),
+    #[display(fmt = "SetLinkPreviewsWhitelistOnly {_0}")]
+    SetLinkPreviewsWhitelistOnly(bool),
+    #[display(fmt = "SetLinkPreviewsWhitelist")]
+    SetLinkPreviewsWhitelist(Vec<String>),
+    #[display(fmt = "SetStripImageMetadata {_0}")]
+    SetStripImageMetadata(bool),
+    #[display(fmt = "SetResizeImages {_0}")]
+    SetResizeImages(bool),
+    #[display(fmt = "SetImageMaxDimension {_0}")]
+    SetImageMaxDimension(u32),
+    #[display(fmt = "SetImageQuality {_0}")]
+    SetImageQuality(u8),
+    #[display(fmt = "SetStorageLayout {_0:?}")]
+    SetStorageLayout(StorageLayout),
+    #[display(fmt = "SetStorageSort {_0:?}")]
+    SetStorageSort(StorageSort),
+    #[display(fmt = "SetFriendSort {_0:?}")]
+    SetFriendSort(FriendSort),
+    #[display(fmt = "SetTrashRetentionDays {_0}")]
+    SetTrashRetentionDays(u32),
+    #[display(fmt = "SetLocalSyncEnabled {_0}")]
+    SetLocalSyncEnabled(bool),
+    #[display(fmt = "SetLocalSyncPath {_0:?}")]
+    SetLocalSyncPath(PathBuf),
+    #[display(fmt = "SetLocalSyncRemotePath {_0}")]
+    SetLocalSyncRemotePath(String),
 }
@@ -1,9 +1,15 @@
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 use tracing::log;
 
 use crate::STATIC_ARGS;
 
-use super::action::ConfigAction;
+use super::{
+    action::ConfigAction,
+    contacts::FriendSort,
+    storage::{StorageLayout, StorageSort},
+};
 
 /// A struct that represents the configuration of the application.
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -31,6 +37,10 @@ pub struct Configuration {
     /// Notification-related configuration options.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// File-related configuration options.
+    #[serde(default)]
+    pub files: Files,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -45,14 +55,167 @@ pub struct General {
     pub show_splash: bool,
     #[serde(default)]
     pub enable_overlay: bool,
+    #[serde(default)]
+    pub friend_sort: FriendSort,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct Privacy {
     #[serde(default)]
     pub satellite_sync_nodes: bool,
     #[serde(default)]
     pub safer_file_scanning: bool,
+    #[serde(default)]
+    pub link_previews: LinkPreviews,
+}
+
+/// Controls which links get an embed. Fetching a preview contacts the linked site,
+/// which reveals our IP address to it.
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct LinkPreviews {
+    #[serde(default = "bool_true")]
+    pub enabled: bool,
+    /// only fetch previews for links in messages we sent ourselves
+    #[serde(default)]
+    pub own_messages_only: bool,
+    /// only fetch previews for domains in `whitelisted_domains`
+    #[serde(default)]
+    pub whitelist_only: bool,
+    #[serde(default)]
+    pub whitelisted_domains: Vec<String>,
+}
+
+impl Default for LinkPreviews {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            own_messages_only: false,
+            whitelist_only: false,
+            whitelisted_domains: vec![],
+        }
+    }
+}
+
+impl LinkPreviews {
+    /// returns true if a preview may be fetched for a link pointing to `host`.
+    /// a whitelisted domain also allows its subdomains.
+    pub fn allows(&self, host: &str, remote: bool) -> bool {
+        if !self.enabled || (remote && self.own_messages_only) {
+            return false;
+        }
+        if !self.whitelist_only {
+            return true;
+        }
+        let host = host.trim_end_matches('.').to_lowercase();
+        self.whitelisted_domains.iter().any(|domain| {
+            let domain = domain.trim().trim_end_matches('.').to_lowercase();
+            !domain.is_empty()
+                && (host == domain
+                    || host
+                        .strip_suffix(&domain)
+                        .map(|sub| sub.ends_with('.'))
+                        .unwrap_or(false))
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct Files {
+    #[serde(default)]
+    pub image_processing: ImageProcessing,
+    #[serde(default)]
+    pub storage_layout: StorageLayout,
+    #[serde(default)]
+    pub storage_sort: StorageSort,
+    /// deleted items are removed from the trash after this many days. 0 keeps them until the trash is emptied
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
+    #[serde(default)]
+    pub local_sync: LocalSync,
+}
+
+impl Default for Files {
+    fn default() -> Self {
+        Self {
+            image_processing: ImageProcessing::default(),
+            storage_layout: StorageLayout::default(),
+            storage_sort: StorageSort::default(),
+            trash_retention_days: default_trash_retention_days(),
+            local_sync: LocalSync::default(),
+        }
+    }
+}
+
+/// A local folder which is kept in sync with a folder in storage, both ways
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct LocalSync {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub local_path: Option<PathBuf>,
+    /// the storage folder, from the root and with forward slashes
+    #[serde(default = "default_sync_folder")]
+    pub remote_path: String,
+}
+
+impl Default for LocalSync {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            local_path: None,
+            remote_path: default_sync_folder(),
+        }
+    }
+}
+
+fn default_sync_folder() -> String {
+    "/Sync".into()
+}
+
+fn default_trash_retention_days() -> u32 {
+    30
+}
+
+/// How images are prepared before they are sent in a chat or uploaded to storage.
+/// Re-encoding an image drops its metadata, which may include where a photo was taken.
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ImageProcessing {
+    #[serde(default = "bool_true")]
+    pub strip_metadata: bool,
+    /// downscale images so that neither side is longer than `max_dimension`. it is opt-in, because
+    /// the image has to be re-encoded
+    #[serde(default)]
+    pub resize: bool,
+    #[serde(default = "default_max_dimension")]
+    pub max_dimension: u32,
+    /// JPEG quality from 1 to 100, used when an image gets resized
+    #[serde(default = "default_image_quality")]
+    pub quality: u8,
+}
+
+impl Default for ImageProcessing {
+    fn default() -> Self {
+        Self {
+            strip_metadata: true,
+            resize: false,
+            max_dimension: default_max_dimension(),
+            quality: default_image_quality(),
+        }
+    }
+}
+
+impl ImageProcessing {
+    pub fn is_enabled(&self) -> bool {
+        self.strip_metadata || self.resize
+    }
+}
+
+fn default_max_dimension() -> u32 {
+    2560
+}
+
+fn default_image_quality() -> u8 {
+    85
 }
 
 #[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
@@ -168,6 +331,33 @@ impl Configuration {
                 self.extensions.enable_automatically = flag
             }
             ConfigAction::SetEchoCancellation(flag) => self.audiovideo.echo_cancellation = flag,
+            ConfigAction::SetLinkPreviewsEnabled(flag) => self.privacy.link_previews.enabled = flag,
+            ConfigAction::SetLinkPreviewsOwnMessagesOnly(flag) => {
+                self.privacy.link_previews.own_messages_only = flag
+            }
+            ConfigAction::SetLinkPreviewsWhitelistOnly(flag) => {
+                self.privacy.link_previews.whitelist_only = flag
+            }
+            ConfigAction::SetLinkPreviewsWhitelist(domains) => {
+                self.privacy.link_previews.whitelisted_domains = domains
+            }
+            ConfigAction::SetStripImageMetadata(flag) => {
+                self.files.image_processing.strip_metadata = flag
+            }
+            ConfigAction::SetResizeImages(flag) => self.files.image_processing.resize = flag,
+            ConfigAction::SetImageMaxDimension(max) => {
+                self.files.image_processing.max_dimension = max
+            }
+            ConfigAction::SetImageQuality(quality) => {
+                self.files.image_processing.quality = quality.clamp(1, 100)
+            }
+            ConfigAction::SetStorageLayout(layout) => self.files.storage_layout = layout,
+            ConfigAction::SetStorageSort(sort) => self.files.storage_sort = sort,
+            ConfigAction::SetFriendSort(sort) => self.general.friend_sort = sort,
+            ConfigAction::SetTrashRetentionDays(days) => self.files.trash_retention_days = days,
+            ConfigAction::SetLocalSyncEnabled(flag) => self.files.local_sync.enabled = flag,
+            ConfigAction::SetLocalSyncPath(path) => self.files.local_sync.local_path = Some(path),
+            ConfigAction::SetLocalSyncRemotePath(path) => self.files.local_sync.remote_path = path,
         }
 
         if self.audiovideo != old_audiovideo {
@@ -184,3 +374,48 @@ impl Configuration {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn whitelist(domains: &[&str]) -> LinkPreviews {
+        LinkPreviews {
+            whitelist_only: true,
+            whitelisted_domains: domains.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn previews_follow_the_settings() {
+        let previews = LinkPreviews::default();
+        assert!(previews.allows("example.com", true));
+
+        let own_only = LinkPreviews {
+            own_messages_only: true,
+            ..Default::default()
+        };
+        assert!(own_only.allows("example.com", false));
+        assert!(!own_only.allows("example.com", true));
+
+        let disabled = LinkPreviews {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(!disabled.allows("example.com", false));
+    }
+
+    #[test]
+    fn whitelisted_domains_allow_their_subdomains() {
+        let previews = whitelist(&[" Example.com ", "docs.rs.", ""]);
+        assert!(previews.allows("example.com", false));
+        assert!(previews.allows("www.EXAMPLE.com.", false));
+        assert!(previews.allows("a.b.example.com", false));
+        assert!(previews.allows("docs.rs", false));
+        assert!(!previews.allows("notexample.com", false));
+        assert!(!previews.allows("example.com.evil.net", false));
+        assert!(!previews.allows("rs", false));
+        assert!(!whitelist(&[]).allows("example.com", false));
+    }
+}
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use warp::crypto::DID;
+
+/// the longest nickname which can be given to someone
+pub const MAX_NICKNAME_LENGTH: usize = 32;
+/// the longest tag which can be put on someone
+pub const MAX_TAG_LENGTH: usize = 24;
+
+/// what the user keeps about someone for themselves. it is never sent to anyone
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Contact {
+    #[serde(default)]
+    pub nickname: Option<String>,
+    #[serde(default)]
+    pub note: String,
+    // used to filter the friends list
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+    // set once the user compared safety numbers with them
+    #[serde(default)]
+    pub verified: Option<Verification>,
+}
+
+/// what was compared when someone was verified, to notice when it changes
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Verification {
+    pub safety_number: String,
+    pub username: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationStatus {
+    Unverified,
+    Verified,
+    /// the safety number is not the one which was compared, which only happens when the user's own key changed.
+    /// or someone uses the name of a verified contact who isn't a friend anymore, like an account which was made again
+    Changed,
+}
+
+impl Contact {
+    fn is_empty(&self) -> bool {
+        self.nickname.is_none()
+            && self.note.is_empty()
+            && self.tags.is_empty()
+            && self.verified.is_none()
+    }
+}
+
+/// How the friends list is ordered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FriendSort {
+    #[default]
+    Alphabetical,
+    OnlineFirst,
+    RecentlyChatted,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Contacts {
+    #[serde(default)]
+    pub all: HashMap<DID, Contact>,
+}
+
+impl Contacts {
+    pub fn get(&self, did: &DID) -> Option<&Contact> {
+        self.all.get(did)
+    }
+
+    pub fn nickname(&self, did: &DID) -> Option<&str> {
+        self.all.get(did).and_then(|c| c.nickname.as_deref())
+    }
+
+    /// a blank nickname removes it
+    pub fn set_nickname(&mut self, did: &DID, nickname: &str) {
+        let nickname: String = nickname.trim().chars().take(MAX_NICKNAME_LENGTH).collect();
+        self.update(did, |c| {
+            c.nickname = (!nickname.is_empty()).then_some(nickname)
+        });
+    }
+
+    pub fn set_note(&mut self, did: &DID, note: &str) {
+        let note = note.trim().to_string();
+        self.update(did, |c| c.note = note);
+    }
+
+    /// a blank tag is ignored
+    pub fn add_tag(&mut self, did: &DID, tag: &str) {
+        let tag: String = tag.trim().chars().take(MAX_TAG_LENGTH).collect();
+        if tag.is_empty() {
+            return;
+        }
+        self.update(did, |c| {
+            c.tags.insert(tag);
+        });
+    }
+
+    pub fn remove_tag(&mut self, did: &DID, tag: &str) {
+        self.update(did, |c| {
+            c.tags.remove(tag);
+        });
+    }
+
+    pub fn has_tag(&self, did: &DID, tag: &str) -> bool {
+        self.all
+            .get(did)
+            .map(|c| c.tags.contains(tag))
+            .unwrap_or_default()
+    }
+
+    /// every tag put on someone, in alphabetical order
+    pub fn tags(&self) -> BTreeSet<String> {
+        self.all
+            .values()
+            .flat_map(|c| c.tags.iter().cloned())
+            .collect()
+    }
+
+    pub fn tagged(&self, tag: &str) -> Vec<DID> {
+        self.all
+            .iter()
+            .filter(|(_, c)| c.tags.contains(tag))
+            .map(|(did, _)| did.clone())
+            .collect()
+    }
+
+    pub fn verify(&mut self, did: &DID, verification: Verification) {
+        self.update(did, |c| c.verified = Some(verification));
+    }
+
+    pub fn unverify(&mut self, did: &DID) {
+        self.update(did, |c| c.verified = None);
+    }
+
+    /// the safety number is derived from both keys, so it only changes for the same contact when the user's own key does.
+    /// a contact who made their account again has a new key, so someone with the name of a verified contact
+    /// who isn't among `friends` anymore is shown as changed too
+    pub fn verification_status(
+        &self,
+        did: &DID,
+        safety_number: &str,
+        username: &str,
+        friends: &HashSet<DID>,
+    ) -> VerificationStatus {
+        match self.all.get(did).and_then(|c| c.verified.as_ref()) {
+            Some(v) if v.safety_number == safety_number => VerificationStatus::Verified,
+            Some(_) => VerificationStatus::Changed,
+            None if !username.is_empty()
+                && self.all.iter().any(|(other, c)| {
+                    other != did
+                        && !friends.contains(other)
+                        && c.verified
+                            .as_ref()
+                            .map(|v| v.username.eq_ignore_ascii_case(username))
+                            .unwrap_or_default()
+                }) =>
+            {
+                VerificationStatus::Changed
+            }
+            None => VerificationStatus::Unverified,
+        }
+    }
+
+    // contacts without anything left are forgotten
+    fn update(&mut self, did: &DID, f: impl FnOnce(&mut Contact)) {
+        let contact = self.all.entry(did.clone()).or_default();
+        f(contact);
+        if contact.is_empty() {
+            self.all.remove(did);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    fn other_did() -> DID {
+        DID::from_str("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+            .expect("valid did")
+    }
+
+    #[test]
+    fn blank_contacts_are_removed() {
+        let did = DID::default();
+        let mut contacts = Contacts::default();
+
+        contacts.set_nickname(&did, "  Mom  ");
+        assert_eq!(contacts.nickname(&did), Some("Mom"));
+
+        contacts.set_note(&did, "birthday in may");
+        contacts.set_nickname(&did, " ");
+        assert_eq!(contacts.nickname(&did), None);
+        assert_eq!(
+            contacts.get(&did).map(|c| c.note.as_str()),
+            Some("birthday in may")
+        );
+
+        contacts.set_note(&did, "");
+        assert!(contacts.get(&did).is_none());
+    }
+
+    #[test]
+    fn tags_are_shared_between_contacts() {
+        let mut contacts = Contacts::default();
+        let first = DID::default();
+        let second = DID::from_str("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+            .expect("valid did");
+
+        contacts.add_tag(&first, " work ");
+        contacts.add_tag(&second, "work");
+        contacts.add_tag(&second, "family");
+        contacts.add_tag(&second, "  ");
+
+        assert_eq!(
+            contacts.tags().into_iter().collect::<Vec<_>>(),
+            vec!["family".to_string(), "work".to_string()]
+        );
+        assert_eq!(contacts.tagged("work").len(), 2);
+        assert!(contacts.has_tag(&first, "work"));
+
+        contacts.remove_tag(&first, "work");
+        assert!(contacts.get(&first).is_none());
+        assert_eq!(contacts.tagged("work"), vec![second]);
+    }
+
+    #[test]
+    fn verification_notices_changes() {
+        let mut contacts = Contacts::default();
+        let verified = DID::default();
+        let friends = HashSet::from([verified.clone()]);
+
+        assert_eq!(
+            contacts.verification_status(&verified, "1234", "Alice", &friends),
+            VerificationStatus::Unverified
+        );
+        contacts.verify(
+            &verified,
+            Verification {
+                safety_number: "1234".into(),
+                username: "Alice".into(),
+            },
+        );
+        assert_eq!(
+            contacts.verification_status(&verified, "1234", "Alice", &friends),
+            VerificationStatus::Verified
+        );
+        // the user's own key changed
+        assert_eq!(
+            contacts.verification_status(&verified, "5678", "Alice", &friends),
+            VerificationStatus::Changed
+        );
+        // someone else may share the name of a contact who is still a friend
+        assert_eq!(
+            contacts.verification_status(&other_did(), "5678", "alice", &friends),
+            VerificationStatus::Unverified
+        );
+
+        contacts.unverify(&verified);
+        assert!(contacts.get(&verified).is_none());
+    }
+
+    #[test]
+    fn re_created_accounts_are_shown_as_changed() {
+        let mut contacts = Contacts::default();
+        let old_key = DID::default();
+        let new_key = other_did();
+        contacts.verify(
+            &old_key,
+            Verification {
+                safety_number: "1234".into(),
+                username: "Alice".into(),
+            },
+        );
+
+        // the old account was removed as a friend and a new one with the same name sent a request
+        let friends = HashSet::new();
+        assert_eq!(
+            contacts.verification_status(&new_key, "5678", "alice", &friends),
+            VerificationStatus::Changed
+        );
+        assert_eq!(
+            contacts.verification_status(&new_key, "5678", "Bob", &friends),
+            VerificationStatus::Unverified
+        );
+        assert_eq!(
+            contacts.verification_status(&new_key, "5678", "", &friends),
+            VerificationStatus::Unverified
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use tracing::log;
+
+use crate::STATIC_ARGS;
+
+// every pack is a folder with a manifest.json and the images it lists
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp"];
+const MAX_IMAGE_SIZE: u64 = 2 * 1024 * 1024;
+const MAX_PACK_ENTRIES: usize = 1000;
+
+static SHORTCODE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9_+\-]{2,32}$").unwrap());
+
+/// the manifest.json of an emoji pack, mapping shortcodes to image files relative to the manifest
+/// {
+///     "name": "Party Parrots",
+///     "author": "someone",
+///     "emojis": { "parrot": "parrot.gif" },
+///     "stickers": { "wave": "stickers/wave.png" }
+/// }
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    name: String,
+    author: Option<String>,
+    #[serde(default)]
+    emojis: BTreeMap<String, String>,
+    #[serde(default)]
+    stickers: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    fn entries(&self) -> impl Iterator<Item = (&String, &String, bool)> {
+        self.emojis
+            .iter()
+            .map(|(code, file)| (code, file, false))
+            .chain(self.stickers.iter().map(|(code, file)| (code, file, true)))
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.name.trim().is_empty() {
+            bail!("the pack has no name");
+        }
+        if self.emojis.is_empty() && self.stickers.is_empty() {
+            bail!("the pack contains no emojis or stickers");
+        }
+        if self.emojis.len() + self.stickers.len() > MAX_PACK_ENTRIES {
+            bail!("the pack contains more than {MAX_PACK_ENTRIES} entries");
+        }
+        for (shortcode, file, _) in self.entries() {
+            if !SHORTCODE_REGEX.is_match(shortcode) {
+                bail!("invalid shortcode: {shortcode}");
+            }
+            if !is_image_path(file) {
+                bail!("{file} is not an image inside of the pack");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomEmoji {
+    pub shortcode: String,
+    pub path: PathBuf,
+    // stickers are shown bigger than emojis
+    pub sticker: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmojiPack {
+    // name of the folder in the emoji packs directory
+    pub id: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub emojis: Vec<CustomEmoji>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EmojiPacks {
+    packs: Vec<EmojiPack>,
+}
+
+impl EmojiPacks {
+    pub fn iter(&self) -> impl Iterator<Item = &EmojiPack> {
+        self.packs.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packs.is_empty()
+    }
+
+    /// if several packs use the same shortcode, the one which sorts first wins
+    pub fn find(&self, shortcode: &str) -> Option<&CustomEmoji> {
+        self.packs
+            .iter()
+            .flat_map(|pack| pack.emojis.iter())
+            .find(|emoji| emoji.shortcode == shortcode)
+    }
+
+    pub fn get_matching(&self, pattern: &str, exact: bool) -> Vec<&CustomEmoji> {
+        self.packs
+            .iter()
+            .flat_map(|pack| pack.emojis.iter())
+            .filter(|emoji| {
+                (exact && emoji.shortcode == pattern)
+                    || (!exact && emoji.shortcode.starts_with(pattern))
+            })
+            .collect()
+    }
+}
+
+// only plain relative paths, so that a manifest can't point outside of its pack
+fn is_image_path(file: &str) -> bool {
+    let path = Path::new(file);
+    path.components().all(|c| matches!(c, Component::Normal(_)))
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+            .unwrap_or_default()
+}
+
+fn pack_id(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn load_pack(dir: &Path) -> anyhow::Result<EmojiPack> {
+    let manifest = fs::read_to_string(dir.join(MANIFEST_FILE))?;
+    let manifest: Manifest = serde_json::from_str(&manifest)?;
+    manifest.validate()?;
+    let emojis = manifest
+        .entries()
+        .filter_map(|(shortcode, file, sticker)| {
+            let path = dir.join(file);
+            if !path.is_file() {
+                log::warn!("emoji pack {}: {file} is missing", manifest.name);
+                return None;
+            }
+            Some(CustomEmoji {
+                shortcode: shortcode.clone(),
+                path,
+                sticker,
+            })
+        })
+        .collect();
+    Ok(EmojiPack {
+        id: dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        name: manifest.name,
+        author: manifest.author,
+        emojis,
+    })
+}
+
+pub fn get_available_emoji_packs() -> EmojiPacks {
+    let dirs = match fs::read_dir(&STATIC_ARGS.emoji_packs_path) {
+        Ok(dirs) => dirs,
+        Err(e) => {
+            log::debug!("no emoji packs: {e}");
+            return EmojiPacks::default();
+        }
+    };
+    let mut packs: Vec<EmojiPack> = dirs
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && !is_hidden(path))
+        .filter_map(|path| match load_pack(&path) {
+            Ok(pack) => Some(pack),
+            Err(e) => {
+                log::error!("failed to load emoji pack {}: {e}", path.display());
+                None
+            }
+        })
+        .collect();
+    packs.sort_by(|a, b| a.name.cmp(&b.name));
+    EmojiPacks { packs }
+}
+
+/// copies a folder or a zip file containing a manifest.json into the emoji packs directory.
+/// a pack with the same name gets replaced.
+pub fn import_emoji_pack(src: &Path) -> anyhow::Result<EmojiPack> {
+    let staging = STATIC_ARGS
+        .temp_files
+        .join(format!("emoji_pack_{}", uuid::Uuid::new_v4()));
+    let res = if src.is_dir() {
+        stage_from_dir(src, &staging)
+    } else {
+        stage_from_zip(src, &staging)
+    };
+    let res = res.and_then(|name| {
+        let dest = STATIC_ARGS.emoji_packs_path.join(pack_id(&name));
+        swap_in(&staging, &dest)?;
+        load_pack(&dest)
+    });
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    res
+}
+
+pub fn remove_emoji_pack(id: &str) -> io::Result<()> {
+    if id.is_empty() || id.contains(['/', '\\', '.']) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid pack"));
+    }
+    fs::remove_dir_all(STATIC_ARGS.emoji_packs_path.join(id))
+}
+
+// only the manifest and the images it lists are copied. returns the name of the pack
+fn stage_from_dir(src: &Path, staging: &Path) -> anyhow::Result<String> {
+    let manifest_str =
+        fs::read_to_string(src.join(MANIFEST_FILE)).context("manifest.json not found")?;
+    let manifest: Manifest = serde_json::from_str(&manifest_str)?;
+    manifest.validate()?;
+    fs::create_dir_all(staging)?;
+    fs::write(staging.join(MANIFEST_FILE), manifest_str)?;
+    for (_, file, _) in manifest.entries() {
+        let from = src.join(file);
+        if fs::metadata(&from)?.len() > MAX_IMAGE_SIZE {
+            bail!("{file} is too big");
+        }
+        let to = staging.join(file);
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&from, &to).with_context(|| format!("failed to copy {file}"))?;
+    }
+    Ok(manifest.name)
+}
+
+fn stage_from_zip(src: &Path, staging: &Path) -> anyhow::Result<String> {
+    let mut archive = zip::ZipArchive::new(File::open(src)?)?;
+    // the manifest may be at the root or inside of a single top level folder
+    let manifest_path = archive
+        .file_names()
+        .filter(|name| Path::new(name).file_name() == Some(MANIFEST_FILE.as_ref()))
+        .min_by_key(|name| name.len())
+        .map(PathBuf::from)
+        .context("manifest.json not found")?;
+    let base = manifest_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let manifest_str = io::read_to_string(archive.by_name(&manifest_path.to_string_lossy())?)?;
+    let manifest: Manifest = serde_json::from_str(&manifest_str)?;
+    manifest.validate()?;
+    fs::create_dir_all(staging)?;
+    fs::write(staging.join(MANIFEST_FILE), manifest_str)?;
+    for (_, file, _) in manifest.entries() {
+        let name = base.join(file);
+        let entry = archive
+            .by_name(&name.to_string_lossy().replace('\\', "/"))
+            .with_context(|| format!("{file} not found"))?;
+        if entry.size() > MAX_IMAGE_SIZE {
+            bail!("{file} is too big");
+        }
+        let to = staging.join(file);
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // the size in the header isn't trusted
+        io::copy(&mut entry.take(MAX_IMAGE_SIZE), &mut File::create(&to)?)?;
+    }
+    Ok(manifest.name)
+}
+
+// packs being swapped in are kept in hidden folders
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .map(|n| n.to_string_lossy().starts_with('.'))
+        .unwrap_or_default()
+}
+
+// the new pack is copied next to the old one before anything is replaced, so that a failed import
+// leaves the old pack in place
+fn swap_in(staging: &Path, dest: &Path) -> io::Result<()> {
+    let (parent, name) = match (dest.parent(), dest.file_name()) {
+        (Some(parent), Some(name)) => (parent, name.to_string_lossy()),
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid pack")),
+    };
+    fs::create_dir_all(parent)?;
+    let incoming = parent.join(format!(".{name}.incoming"));
+    let previous = parent.join(format!(".{name}.previous"));
+    // an import which was interrupted while swapping
+    if !dest.exists() && previous.exists() {
+        fs::rename(&previous, dest)?;
+    }
+    for leftover in [&incoming, &previous] {
+        if leftover.exists() {
+            fs::remove_dir_all(leftover)?;
+        }
+    }
+
+    if fs::rename(staging, &incoming).is_err() {
+        if let Err(e) = copy_dir(staging, &incoming) {
+            let _ = fs::remove_dir_all(&incoming);
+            return Err(e);
+        }
+    }
+    if dest.exists() {
+        if let Err(e) = fs::rename(dest, &previous) {
+            let _ = fs::remove_dir_all(&incoming);
+            return Err(e);
+        }
+    }
+    if let Err(e) = fs::rename(&incoming, dest) {
+        if previous.exists() {
+            let _ = fs::rename(&previous, dest);
+        }
+        let _ = fs::remove_dir_all(&incoming);
+        return Err(e);
+    }
+    if previous.exists() {
+        let _ = fs::remove_dir_all(&previous);
+    }
+    Ok(())
+}
+
+fn copy_dir(src: &Path, dest: &Path) -> io::Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let to = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to)?;
+        } else {
+            fs::copy(entry.path(), to)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn manifest(json: &str) -> Manifest {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn manifest_is_validated() {
+        let valid = manifest(r#"{"name": "a", "emojis": {"party": "party.gif"}}"#);
+        assert!(valid.validate().is_ok());
+
+        let bad_shortcode = manifest(r#"{"name": "a", "emojis": {"Party Time": "party.gif"}}"#);
+        assert!(bad_shortcode.validate().is_err());
+
+        let outside = manifest(r#"{"name": "a", "stickers": {"party": "../party.gif"}}"#);
+        assert!(outside.validate().is_err());
+
+        let not_an_image = manifest(r#"{"name": "a", "emojis": {"party": "party.svg"}}"#);
+        assert!(not_an_image.validate().is_err());
+
+        let empty = manifest(r#"{"name": "a"}"#);
+        assert!(empty.validate().is_err());
+    }
+
+    #[test]
+    fn first_pack_wins_on_shortcode_conflicts() {
+        let emoji = |pack: &str, code: &str| CustomEmoji {
+            shortcode: code.into(),
+            path: PathBuf::from(pack).join(code),
+            sticker: false,
+        };
+        let packs = EmojiPacks {
+            packs: vec![
+                EmojiPack {
+                    id: "a".into(),
+                    name: "a".into(),
+                    author: None,
+                    emojis: vec![emoji("a", "party"), emoji("a", "parrot")],
+                },
+                EmojiPack {
+                    id: "b".into(),
+                    name: "b".into(),
+                    author: None,
+                    emojis: vec![emoji("b", "party")],
+                },
+            ],
+        };
+        assert_eq!(packs.find("party").unwrap().path, PathBuf::from("a/party"));
+        assert_eq!(packs.get_matching("par", false).len(), 3);
+        assert!(packs.find("nope").is_none());
+    }
+
+    #[test]
+    fn failed_reimport_keeps_the_old_pack() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("packs").join("party");
+        let staging = dir.path().join("staging");
+        fs::create_dir_all(&dest).unwrap();
+        fs::write(dest.join(MANIFEST_FILE), "old").unwrap();
+
+        // nothing was staged, so there is nothing to swap in
+        assert!(swap_in(&staging, &dest).is_err());
+        assert_eq!(fs::read_to_string(dest.join(MANIFEST_FILE)).unwrap(), "old");
+
+        fs::create_dir_all(&staging).unwrap();
+        fs::write(staging.join(MANIFEST_FILE), "new").unwrap();
+        swap_in(&staging, &dest).unwrap();
+        assert_eq!(fs::read_to_string(dest.join(MANIFEST_FILE)).unwrap(), "new");
+        // only the pack is left in the packs folder
+        assert_eq!(fs::read_dir(dir.path().join("packs")).unwrap().count(), 1);
+    }
+}
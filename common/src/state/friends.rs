@@ -4,6 +4,9 @@ use warp::crypto::DID;
 
 use crate::STATIC_ARGS;
 
+// the longest note which can be sent with a friend request
+pub const MAX_REQUEST_NOTE_LENGTH: usize = 140;
+
 // warning: Friends implements Serialize
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct Friends {
@@ -13,6 +13,9 @@ pub struct Identity {
     //TODO: Use `Option<String>` in the future unless this is split away
     profile_image: String,
     profile_banner: String,
+    // given by the user, see `State::contacts`
+    #[serde(skip)]
+    nickname: Option<String>,
 }
 
 impl Hash for Identity {
@@ -26,6 +29,7 @@ impl PartialEq for Identity {
         self.identity.eq(&other.identity)
             && self.status.eq(&other.status)
             && self.platform.eq(&other.platform)
+            && self.nickname.eq(&other.nickname)
     }
 }
 
@@ -43,6 +47,7 @@ impl From<WarpIdentity> for Identity {
             platform: Default::default(),
             profile_image: String::new(),
             profile_banner: String::new(),
+            nickname: None,
         }
     }
 }
@@ -68,6 +73,7 @@ impl Identity {
             platform,
             profile_image: String::new(),
             profile_banner: String::new(),
+            nickname: None,
         }
     }
     pub fn identity_status(&self) -> IdentityStatus {
@@ -98,6 +104,38 @@ impl Identity {
         self.profile_banner.clone()
     }
 
+    /// the nickname given to this user if there is one, otherwise their username
+    pub fn display_name(&self) -> String {
+        self.nickname.clone().unwrap_or_else(|| self.username())
+    }
+
+    pub fn nickname(&self) -> Option<String> {
+        self.nickname.clone()
+    }
+
+    /// the username when a nickname is shown instead of it, so it can be shown on hover. empty otherwise
+    pub fn username_hint(&self) -> String {
+        match self.nickname {
+            Some(_) => self.username(),
+            None => String::new(),
+        }
+    }
+
+    pub fn set_nickname(&mut self, nickname: Option<String>) {
+        self.nickname = nickname;
+    }
+
+    /// whether the username or the nickname starts with `prefix`, ignoring case
+    pub fn name_starts_with(&self, prefix: &str) -> bool {
+        let prefix = prefix.to_lowercase();
+        self.username().to_lowercase().starts_with(&prefix)
+            || self
+                .nickname
+                .as_ref()
+                .map(|n| n.to_lowercase().starts_with(&prefix))
+                .unwrap_or_default()
+    }
+
     pub fn contains_default_picture(&self) -> bool {
         let picture = &self.profile_image;
 
@@ -2,7 +2,9 @@ pub mod action;
 pub mod call;
 pub mod chats;
 pub mod configuration;
+pub mod contacts;
 pub mod default_keybinds;
+pub mod emoji_packs;
 pub mod friends;
 pub mod identity;
 pub mod notifications;
@@ -32,6 +34,7 @@ use warp::constellation::Progression;
 use warp::multipass::identity::Platform;
 use warp::raygun::{ConversationType, Location};
 
+use crate::utils::safety_number::safety_number;
 use crate::STATIC_ARGS;
 
 use crate::{
@@ -41,6 +44,7 @@ use crate::{
         WarpEvent,
     },
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashSet, VecDeque};
 
@@ -55,6 +59,7 @@ use warp::{crypto::DID, multipass::identity::IdentityStatus, raygun};
 use tracing::log;
 
 use self::call::Call;
+use self::contacts::{Verification, VerificationStatus};
 use self::pending_message::PendingMessage;
 use self::storage::Storage;
 use self::ui::{Font, Layout};
@@ -77,6 +82,9 @@ pub struct State {
     pub settings: settings::Settings,
     pub ui: ui::UI,
     pub configuration: configuration::Configuration,
+    /// nicknames and notes the user gave to others
+    #[serde(default)]
+    pub contacts: contacts::Contacts,
     #[serde(skip)]
     identities: HashMap<DID, identity::Identity>,
     #[serde(skip)]
@@ -109,6 +117,7 @@ impl Clone for State {
             scope_ids: Default::default(),
             ui: Default::default(),
             configuration: self.configuration.clone(),
+            contacts: self.contacts.clone(),
             identities: HashMap::new(),
             initialized: self.initialized,
             warp_cmd_tx: None,
@@ -202,6 +211,12 @@ impl State {
             Action::RemoveFriend(friend) => self.remove_friend(friend),
             Action::Block(identity) => self.block(identity),
             Action::Unblock(identity) => self.unblock(identity),
+            Action::SetNickname(did, nickname) => self.contacts.set_nickname(did, &nickname),
+            Action::SetContactNote(did, note) => self.contacts.set_note(did, &note),
+            Action::AddContactTag(did, tag) => self.contacts.add_tag(did, &tag),
+            Action::RemoveContactTag(did, tag) => self.contacts.remove_tag(did, &tag),
+            Action::VerifyContact(did) => self.verify_contact(did),
+            Action::UnverifyContact(did) => self.contacts.unverify(did),
             // ===== UI =====
             // Favorites
             Action::Favorite(chat) => self.favorite(&chat),
@@ -232,6 +247,7 @@ impl State {
             Action::TrackEmojiUsage(emoji) => self.ui.track_emoji_usage(emoji),
             Action::SetEmojiDestination(destination) => self.ui.emoji_destination = destination,
             Action::SetEmojiPickerVisible(visible) => self.ui.emoji_picker_visible = visible,
+            Action::SetEmojiPacks(packs) => self.ui.emoji_packs = packs,
             Action::SetTransformMarkdownText(flag) => self.ui.transform_markdown_text(flag),
             Action::SetTransformAsciiEmojis(flag) => self.ui.transform_ascii_emojis(flag),
             // ===== Settings =====
@@ -375,7 +391,7 @@ impl State {
                         get_local_text("friends.new-request"),
                         get_local_text_with_args(
                             "friends.new-request-name",
-                            vec![("name", identity.username())],
+                            vec![("name", identity.display_name())],
                         ),
                         Some(crate::sounds::Sounds::Notification),
                         notify_rust::Timeout::Milliseconds(4),
@@ -449,7 +465,7 @@ impl State {
                 let own = self.get_own_identity().did_key();
                 let ping = message.is_mention_self(&own);
                 self.update_identity_status_hack(&message.inner.sender());
-                let id = self.identities.get(&message.inner.sender()).cloned();
+                let id = self.identity(&message.inner.sender());
                 // todo: don't load all the messages by default. if the user scrolled up, for example, this incoming message may not need to be fetched yet.
                 self.add_msg_to_chat(conversation_id, message);
 
@@ -482,7 +498,7 @@ impl State {
                     let text = match id {
                         Some(id) => get_local_text_with_args(
                             "messages.user-sent-message",
-                            vec![("user", id.username())],
+                            vec![("user", id.display_name())],
                         ),
                         None => get_local_text("messages.unknown-sent-message"),
                     };
@@ -812,6 +828,8 @@ impl State {
             state.settings.keybinds.extend(new_keybinds);
         }
 
+        state.ui.emoji_packs = emoji_packs::get_available_emoji_packs();
+
         // Reload themes from disc
         let themes = get_available_themes();
         let theme = themes.iter().find(|t| {
@@ -909,8 +927,7 @@ impl State {
     pub fn chat_participants(&self, chat: &Chat) -> Vec<Identity> {
         chat.participants
             .iter()
-            .filter_map(|did| self.identities.get(did))
-            .cloned()
+            .filter_map(|did| self.identity(did))
             .collect()
     }
 
@@ -1068,6 +1085,17 @@ impl State {
             })
             .cloned()
     }
+    /// the group chats someone is in along with the user
+    pub fn mutual_group_chats(&self, did: &DID) -> Vec<Chat> {
+        self.chats
+            .all
+            .values()
+            .filter(|chat| {
+                chat.conversation_type == ConversationType::Group && chat.participants.contains(did)
+            })
+            .cloned()
+            .collect()
+    }
     // assumes the messages are sorted by most recent to oldest
     pub fn update_chat_messages(
         &mut self,
@@ -1347,7 +1375,7 @@ impl State {
         // friends_by_first_letter HashMap
         for (_, friend) in friends {
             let first_letter = friend
-                .username()
+                .display_name()
                 .chars()
                 .next()
                 .expect("all friends should have a username")
@@ -1361,14 +1389,66 @@ impl State {
 
         for (_, list) in friends_by_first_letter.iter_mut() {
             list.sort_by(|a, b| {
-                a.username()
-                    .cmp(&b.username())
+                a.display_name()
+                    .cmp(&b.display_name())
                     .then(a.did_key().to_string().cmp(&b.did_key().to_string()))
             })
         }
 
         friends_by_first_letter
     }
+    pub fn verification_status(&self, did: &DID) -> VerificationStatus {
+        let username = self
+            .get_identity(did)
+            .map(|i| i.username())
+            .unwrap_or_default();
+        self.contacts.verification_status(
+            did,
+            &safety_number(&self.did_key(), did),
+            &username,
+            &self.friends.all,
+        )
+    }
+    fn verify_contact(&mut self, did: &DID) {
+        let verification = Verification {
+            safety_number: safety_number(&self.did_key(), did),
+            username: self
+                .get_identity(did)
+                .map(|i| i.username())
+                .unwrap_or_default(),
+        };
+        self.contacts.verify(did, verification);
+    }
+    /// splits the friends into those who are online and those who aren't, each sorted by name
+    pub fn get_friends_by_status(
+        friends: HashMap<DID, Identity>,
+    ) -> (Vec<Identity>, Vec<Identity>) {
+        let mut friends: Vec<Identity> = friends.into_values().collect();
+        friends.sort_by_key(|f| (f.display_name().to_lowercase(), f.did_key().to_string()));
+        friends
+            .into_iter()
+            .partition(|f| f.identity_status() != IdentityStatus::Offline)
+    }
+    /// the friends chatted with most recently come first. friends without a direct chat come last, sorted by name
+    pub fn get_friends_by_recent_chat(&self, friends: HashMap<DID, Identity>) -> Vec<Identity> {
+        let mut friends: Vec<(Option<DateTime<Utc>>, Identity)> = friends
+            .into_values()
+            .map(|f| {
+                let last_message = self
+                    .get_chat_with_friend(f.did_key())
+                    .and_then(|c| c.messages.back().map(|m| m.inner.date()));
+                (last_message, f)
+            })
+            .collect();
+        friends.sort_by(|(a_date, a), (b_date, b)| {
+            b_date.cmp(a_date).then(
+                a.display_name()
+                    .to_lowercase()
+                    .cmp(&b.display_name().to_lowercase()),
+            )
+        });
+        friends.into_iter().map(|(_, f)| f).collect()
+    }
     pub fn has_friend_with_did(&self, did: &DID) -> bool {
         self.friends.all.contains(did)
     }
@@ -1507,33 +1587,33 @@ impl State {
         self.friends
             .blocked
             .iter()
-            .filter_map(|did| self.identities.get(did))
-            .cloned()
+            .filter_map(|did| self.identity(did))
             .collect()
     }
     pub fn friend_identities(&self) -> Vec<Identity> {
         self.friends
             .all
             .iter()
-            .filter_map(|did| self.identities.get(did))
-            .cloned()
+            .filter_map(|did| self.identity(did))
             .collect()
     }
     pub fn get_identities_from_call(&self, call: &Call) -> Vec<Identity> {
         call.participants_joined
             .keys()
-            .filter_map(|id| self.identities.get(id))
-            .cloned()
+            .filter_map(|id| self.identity(id))
             .collect()
     }
     pub fn get_identities(&self, ids: &[DID]) -> Vec<Identity> {
-        ids.iter()
-            .filter_map(|id| self.identities.get(id))
-            .cloned()
-            .collect()
+        ids.iter().filter_map(|id| self.identity(id)).collect()
     }
     pub fn get_identity(&self, did: &DID) -> Option<Identity> {
-        self.identities.get(did).cloned()
+        self.identity(did)
+    }
+    // every identity leaves state through here, so that it is shown with its nickname
+    fn identity(&self, did: &DID) -> Option<Identity> {
+        let mut identity = self.identities.get(did).cloned()?;
+        identity.set_nickname(self.contacts.nickname(did).map(str::to_string));
+        Some(identity)
     }
     pub fn get_own_identity(&self) -> Identity {
         self.identities
@@ -1545,8 +1625,7 @@ impl State {
         self.friends
             .incoming_requests
             .iter()
-            .filter_map(|did| self.identities.get(did))
-            .cloned()
+            .filter_map(|did| self.identity(did))
             .collect()
     }
     /// Getters
@@ -1559,8 +1638,7 @@ impl State {
         self.friends
             .outgoing_requests
             .iter()
-            .filter_map(|did| self.identities.get(did))
-            .cloned()
+            .filter_map(|did| self.identity(did))
             .collect()
     }
     pub fn set_own_identity(&mut self, identity: Identity) {
@@ -1572,34 +1650,17 @@ impl State {
         &self,
         name_prefix: &str,
     ) -> (Vec<identity_search_result::Entry>, Vec<Identity>) {
-        let entries = self
+        // someone can be found by their username or by the nickname given to them
+        let identities: Vec<Identity> = self
             .identities
-            .values()
-            .filter(|id| {
-                let un = id.username();
-                if un.len() < name_prefix.len() {
-                    false
-                } else {
-                    un[..name_prefix.len()].eq_ignore_ascii_case(name_prefix)
-                }
-            })
-            .filter(|id| id.did_key() != self.did_key())
-            .map(|id| identity_search_result::Entry::from_identity(id.username(), id.did_key()))
+            .keys()
+            .filter(|did| **did != self.did_key())
+            .filter_map(|did| self.identity(did))
+            .filter(|id| id.name_starts_with(name_prefix))
             .collect();
-
-        let identities = self
-            .identities
-            .values()
-            .filter(|id| {
-                let un = id.username();
-                if un.len() < name_prefix.len() {
-                    false
-                } else {
-                    un[..name_prefix.len()].eq_ignore_ascii_case(name_prefix)
-                }
-            })
-            .filter(|f| f.did_key() != self.did_key())
-            .cloned()
+        let entries = identities
+            .iter()
+            .map(|id| identity_search_result::Entry::from_identity(id.display_name(), id.did_key()))
             .collect();
 
         (entries, identities)
@@ -1613,8 +1674,8 @@ impl State {
             let names: Vec<_> = chat
                 .participants
                 .iter()
-                .filter_map(|id| self.identities.get(id))
-                .map(|x| x.username())
+                .filter_map(|id| self.identity(id))
+                .map(|x| x.display_name())
                 .collect();
 
             names.join(",")
@@ -1637,8 +1698,9 @@ impl State {
                 let names: Vec<_> = v
                     .participants
                     .iter()
-                    .filter_map(|id| self.identities.get(id))
-                    .map(|x| x.username())
+                    .filter_map(|id| self.identity(id))
+                    .flat_map(|x| [Some(x.username()), x.nickname()])
+                    .flatten()
                     .collect();
 
                 let user_name_match = names.iter().any(|n| compare_str(n));
@@ -1668,8 +1730,9 @@ impl State {
                 let names: Vec<_> = v
                     .participants
                     .iter()
-                    .filter_map(|id| self.identities.get(id))
-                    .map(|x| x.username())
+                    .filter_map(|id| self.identity(id))
+                    .flat_map(|x| [Some(x.username()), x.nickname()])
+                    .flatten()
                     .collect();
 
                 let user_name_match = names.iter().any(|n| compare_str(n));
@@ -1716,7 +1779,7 @@ impl State {
     pub fn join_usernames(identities: &[Identity]) -> String {
         identities
             .iter()
-            .map(|x| x.username())
+            .map(|x| x.display_name())
             .collect::<Vec<String>>()
             .join(", ")
     }
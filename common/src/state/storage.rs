@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use warp::{constellation::directory::Directory, constellation::file::File};
 
@@ -28,3 +29,187 @@ pub struct Storage {
     #[serde(skip)]
     pub files_in_queue_to_upload: Vec<PathBuf>,
 }
+
+/// What to do when an item is moved or copied into a directory which already has an item with its name
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictResolution {
+    // keeps both, adding a number to the name of the new item
+    #[default]
+    Rename,
+    Replace,
+    Skip,
+}
+
+/// How the items of a directory are shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum StorageLayout {
+    #[default]
+    Grid,
+    List,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SortKey {
+    Name,
+    Size,
+    #[default]
+    Date,
+    Type,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StorageSort {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+impl Default for StorageSort {
+    // newest first, like before items could be sorted
+    fn default() -> Self {
+        Self {
+            key: SortKey::Date,
+            descending: true,
+        }
+    }
+}
+
+impl StorageSort {
+    /// sorting by the current key again flips the order
+    pub fn toggle(self, key: SortKey) -> Self {
+        if self.key == key {
+            Self {
+                key,
+                descending: !self.descending,
+            }
+        } else {
+            Self {
+                key,
+                descending: key != SortKey::Name && key != SortKey::Type,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    Image,
+    Video,
+    Audio,
+    Document,
+}
+
+impl FileKind {
+    pub fn of(file_name: &str) -> Option<Self> {
+        if crate::is_image(file_name) {
+            Some(Self::Image)
+        } else if crate::is_video(file_name) {
+            Some(Self::Video)
+        } else if crate::is_audio(file_name) {
+            Some(Self::Audio)
+        } else if crate::is_document(file_name) {
+            Some(Self::Document)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SizeFilter {
+    #[default]
+    Any,
+    // under 1 MB
+    Small,
+    // 1 to 100 MB
+    Medium,
+    // over 100 MB
+    Large,
+}
+
+impl SizeFilter {
+    pub fn matches(&self, size: usize) -> bool {
+        const MB: usize = 1_000_000;
+        match self {
+            Self::Any => true,
+            Self::Small => size < MB,
+            Self::Medium => (MB..=100 * MB).contains(&size),
+            Self::Large => size > 100 * MB,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateFilter {
+    #[default]
+    Any,
+    Today,
+    Week,
+    Month,
+    Year,
+}
+
+impl DateFilter {
+    pub fn matches(&self, date: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        let max_age = match self {
+            Self::Any => return true,
+            Self::Today => Duration::days(1),
+            Self::Week => Duration::weeks(1),
+            Self::Month => Duration::days(30),
+            Self::Year => Duration::days(365),
+        };
+        now - date <= max_age
+    }
+}
+
+/// What is searched for in storage. An empty query only filters the current directory
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageFilter {
+    pub query: String,
+    pub kind: Option<FileKind>,
+    pub size: SizeFilter,
+    pub date: DateFilter,
+}
+
+impl StorageFilter {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    pub fn is_search(&self) -> bool {
+        !self.query.trim().is_empty()
+    }
+
+    pub fn matches(&self, file: &File, now: DateTime<Utc>) -> bool {
+        let query = self.query.trim().to_lowercase();
+        (query.is_empty() || file.name().to_lowercase().contains(&query))
+            && (self.kind.is_none() || FileKind::of(&file.name()) == self.kind)
+            && self.size.matches(file.size())
+            && self.date.matches(file.creation(), now)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorting_by_the_same_key_flips_the_order() {
+        let sort = StorageSort::default().toggle(SortKey::Name);
+        assert_eq!(sort.key, SortKey::Name);
+        assert!(!sort.descending);
+        assert!(sort.toggle(SortKey::Name).descending);
+        assert!(sort.toggle(SortKey::Size).descending);
+    }
+
+    #[test]
+    fn sizes_and_dates_are_filtered() {
+        assert!(SizeFilter::Small.matches(999_999));
+        assert!(SizeFilter::Medium.matches(1_000_000));
+        assert!(!SizeFilter::Large.matches(100_000_000));
+
+        let now = Utc::now();
+        assert!(DateFilter::Today.matches(now - Duration::hours(23), now));
+        assert!(!DateFilter::Week.matches(now - Duration::days(8), now));
+        assert!(DateFilter::Any.matches(now - Duration::days(8000), now));
+    }
+}
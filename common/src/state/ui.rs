@@ -14,7 +14,7 @@ use std::{
 use tracing::log;
 use uuid::Uuid;
 
-use super::{call, notifications::Notifications};
+use super::{call, emoji_packs::EmojiPacks, notifications::Notifications};
 
 pub type EmojiList = HashMap<String, u64>;
 
@@ -208,6 +208,8 @@ pub struct UI {
     pub emoji_destination: Option<EmojiDestination>,
     #[serde(skip)]
     pub emoji_picker_visible: bool,
+    #[serde(skip)]
+    pub emoji_packs: EmojiPacks,
     #[serde(default = "bool_true")]
     transform_markdown_text: bool,
     #[serde(default = "bool_true")]
@@ -251,6 +253,7 @@ impl Default for UI {
             emojis: default_emojis(),
             emoji_destination: Default::default(),
             emoji_picker_visible: false,
+            emoji_packs: Default::default(),
             current_layout: Default::default(),
             overlays: Default::default(),
             extensions: Default::default(),
@@ -199,11 +199,17 @@ pub fn mention_to_did_key(id: &Identity) -> String {
 
 // Replacement pattern converting a user tag to a highlight div
 pub fn mention_replacement_pattern(id: &Identity, visual: bool) -> String {
+    // a nickname is typed in by the user, unlike usernames
+    let name = id
+        .display_name()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
     format!(
         r#"<div class="message-user-tag {}" value="{}">@{}</div>"#,
         if visual { "visual-only" } else { "" },
         id.did_key(),
-        id.username()
+        name
     )
 }
 
@@ -0,0 +1,442 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tracing::log;
+use uuid::Uuid;
+
+use crate::STATIC_ARGS;
+
+// how often the speed of a transfer is sampled
+const SPEED_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Every upload and download of this session, and the ones which didn't finish before uplink was closed
+pub static TRANSFERS: Lazy<Transfers> =
+    Lazy::new(|| Transfers::load(STATIC_ARGS.transfers_path.clone()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferKind {
+    Upload,
+    Download,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferStatus {
+    Active,
+    Paused,
+    // the reason is empty if uplink was closed during the transfer
+    Failed(String),
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transfer {
+    pub id: Uuid,
+    pub kind: TransferKind,
+    pub name: String,
+    // the file on this device
+    pub local_path: PathBuf,
+    // downloads are written here until they finish
+    pub partial_path: Option<PathBuf>,
+    // the storage directory of the file, with forward slashes. chat attachments have none and can't be resumed
+    pub remote_dir: Option<String>,
+    // the storage item an upload left behind, so that starting it again only replaces that item
+    #[serde(default)]
+    pub item: Option<Uuid>,
+    pub size: usize,
+    pub transferred: usize,
+    pub status: TransferStatus,
+    pub started_at: DateTime<Utc>,
+    // bytes per second
+    #[serde(skip)]
+    pub speed: f64,
+    #[serde(skip)]
+    last_sample: Option<(Instant, usize)>,
+}
+
+impl Transfer {
+    fn new(kind: TransferKind, name: String, local_path: PathBuf, size: usize) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            name,
+            local_path,
+            partial_path: None,
+            remote_dir: None,
+            item: None,
+            size,
+            transferred: 0,
+            status: TransferStatus::Active,
+            started_at: Utc::now(),
+            speed: 0.0,
+            last_sample: None,
+        }
+    }
+
+    pub fn upload(name: String, local_path: PathBuf, remote_dir: String, size: usize) -> Self {
+        Self {
+            remote_dir: Some(remote_dir),
+            ..Self::new(TransferKind::Upload, name, local_path, size)
+        }
+    }
+
+    pub fn download(name: String, local_path: PathBuf, partial_path: PathBuf, size: usize) -> Self {
+        Self {
+            partial_path: Some(partial_path),
+            ..Self::new(TransferKind::Download, name, local_path, size)
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            TransferStatus::Completed | TransferStatus::Cancelled | TransferStatus::Failed(_)
+        )
+    }
+
+    /// failed storage transfers can be started again
+    pub fn is_resumable(&self) -> bool {
+        matches!(self.status, TransferStatus::Failed(_)) && self.remote_dir.is_some()
+    }
+
+    pub fn percentage(&self) -> usize {
+        if self.size == 0 {
+            return 0;
+        }
+        (self.transferred * 100 / self.size).min(100)
+    }
+
+    pub fn eta(&self) -> Option<Duration> {
+        if self.status != TransferStatus::Active || self.speed < 1.0 || self.size == 0 {
+            return None;
+        }
+        let remaining = self.size.saturating_sub(self.transferred) as f64;
+        Some(Duration::from_secs_f64(remaining / self.speed))
+    }
+
+    // the speed is smoothed, so that it doesn't jump around with every chunk
+    fn record(&mut self, transferred: usize, now: Instant) {
+        self.transferred = transferred;
+        let (at, bytes) = match self.last_sample {
+            Some(sample) => sample,
+            None => {
+                self.last_sample = Some((now, transferred));
+                return;
+            }
+        };
+        let elapsed = now.duration_since(at);
+        if elapsed < SPEED_SAMPLE_INTERVAL {
+            return;
+        }
+        let speed = transferred.saturating_sub(bytes) as f64 / elapsed.as_secs_f64();
+        self.speed = if self.speed == 0.0 {
+            speed
+        } else {
+            self.speed * 0.7 + speed * 0.3
+        };
+        self.last_sample = Some((now, transferred));
+    }
+}
+
+/// Shared with the task moving the bytes, which waits while the transfer is paused
+#[derive(Debug, Default)]
+pub struct TransferControl {
+    paused: AtomicBool,
+    cancelled: AtomicBool,
+    resumed: Notify,
+}
+
+impl TransferControl {
+    /// waits while the transfer is paused. returns false once it was cancelled
+    pub async fn proceed(&self) -> bool {
+        while self.paused.load(Ordering::Acquire) && !self.is_cancelled() {
+            self.resumed.notified().await;
+        }
+        !self.is_cancelled()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+pub struct Transfers {
+    transfers: Mutex<Vec<Transfer>>,
+    // only transfers which are running in this session have one
+    controls: Mutex<HashMap<Uuid, Arc<TransferControl>>>,
+    path: PathBuf,
+}
+
+impl Transfers {
+    /// transfers which were running when uplink was closed are marked as failed, so they can be resumed
+    pub fn load(path: PathBuf) -> Self {
+        let mut transfers: Vec<Transfer> = std::fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        for transfer in transfers.iter_mut().filter(|t| !t.is_finished()) {
+            transfer.status = TransferStatus::Failed(String::new());
+        }
+        Self {
+            transfers: Mutex::new(transfers),
+            controls: Default::default(),
+            path,
+        }
+    }
+
+    // only what can still be resumed is kept
+    fn save(&self) {
+        let unfinished: Vec<Transfer> = self
+            .transfers
+            .lock()
+            .iter()
+            .filter(|t| {
+                !matches!(
+                    t.status,
+                    TransferStatus::Completed | TransferStatus::Cancelled
+                )
+            })
+            .cloned()
+            .collect();
+        let contents = match serde_json::to_vec(&unfinished) {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("failed to serialize transfers: {e}");
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(&self.path, contents) {
+            log::error!("failed to save transfers: {e}");
+        }
+    }
+
+    fn update(&self, id: Uuid, f: impl FnOnce(&mut Transfer)) -> bool {
+        match self.transfers.lock().iter_mut().find(|t| t.id == id) {
+            Some(transfer) => {
+                f(transfer);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn start(&self, transfer: Transfer) -> Arc<TransferControl> {
+        let control = Arc::new(TransferControl::default());
+        self.controls.lock().insert(transfer.id, control.clone());
+        self.transfers.lock().push(transfer);
+        self.save();
+        control
+    }
+
+    /// starts a failed transfer again. the caller is responsible for moving the bytes
+    pub fn restart(&self, id: Uuid) -> Option<Arc<TransferControl>> {
+        let restarted = self.update(id, |t| {
+            t.status = TransferStatus::Active;
+            t.speed = 0.0;
+            t.last_sample = None;
+        });
+        if !restarted {
+            return None;
+        }
+        let control = Arc::new(TransferControl::default());
+        self.controls.lock().insert(id, control.clone());
+        self.save();
+        Some(control)
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<Transfer> {
+        self.transfers.lock().iter().find(|t| t.id == id).cloned()
+    }
+
+    pub fn control(&self, id: Uuid) -> Option<Arc<TransferControl>> {
+        self.controls.lock().get(&id).cloned()
+    }
+
+    /// newest first
+    pub fn list(&self) -> Vec<Transfer> {
+        self.transfers.lock().iter().rev().cloned().collect()
+    }
+
+    /// where a storage download comes from, which is only known once warp finds the file
+    pub fn set_source(&self, id: Uuid, remote_dir: String, size: usize) {
+        self.update(id, |t| {
+            t.remote_dir = Some(remote_dir);
+            t.size = size;
+        });
+        self.save();
+    }
+
+    /// the item an unfinished upload created in storage
+    pub fn set_item(&self, id: Uuid, item: Uuid) {
+        self.update(id, |t| t.item = Some(item));
+        self.save();
+    }
+
+    /// an upload started again under another name, because its name was taken in the meantime
+    pub fn rename(&self, id: Uuid, name: String) {
+        self.update(id, |t| {
+            t.name = name;
+            t.item = None;
+        });
+        self.save();
+    }
+
+    pub fn progress(&self, id: Uuid, transferred: usize) {
+        self.update(id, |t| t.record(transferred, Instant::now()));
+    }
+
+    /// a transfer which was cancelled stays cancelled, even if it fails afterwards
+    pub fn finish(&self, id: Uuid, status: TransferStatus) {
+        self.controls.lock().remove(&id);
+        self.update(id, |t| {
+            if t.status == TransferStatus::Cancelled {
+                return;
+            }
+            if status == TransferStatus::Completed {
+                t.transferred = t.size.max(t.transferred);
+            }
+            t.speed = 0.0;
+            t.status = status;
+        });
+        self.save();
+    }
+
+    pub fn pause(&self, id: Uuid) {
+        if let Some(control) = self.control(id) {
+            control.paused.store(true, Ordering::Release);
+            self.update(id, |t| {
+                t.status = TransferStatus::Paused;
+                t.speed = 0.0;
+                t.last_sample = None;
+            });
+            self.save();
+        }
+    }
+
+    /// returns false if the transfer isn't running anymore and has to be started again
+    pub fn resume(&self, id: Uuid) -> bool {
+        let control = match self.control(id) {
+            Some(control) => control,
+            None => return false,
+        };
+        control.paused.store(false, Ordering::Release);
+        control.resumed.notify_one();
+        self.update(id, |t| t.status = TransferStatus::Active);
+        self.save();
+        true
+    }
+
+    pub fn cancel(&self, id: Uuid) {
+        match self.control(id) {
+            Some(control) => {
+                control.cancelled.store(true, Ordering::Release);
+                control.resumed.notify_one();
+            }
+            // nothing is writing to the partial download anymore
+            None => {
+                if let Some(path) = self.get(id).and_then(|t| t.partial_path) {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+        }
+        self.update(id, |t| {
+            t.status = TransferStatus::Cancelled;
+            t.speed = 0.0;
+        });
+        self.save();
+    }
+
+    pub fn clear_finished(&self) {
+        self.transfers
+            .lock()
+            .retain(|t| !t.is_finished() || t.is_resumable());
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn speed_and_eta_are_estimated() {
+        let mut transfer = Transfer::upload("a.bin".into(), "a.bin".into(), "/".into(), 3000);
+        let start = Instant::now();
+        transfer.record(0, start);
+        transfer.record(500, start + Duration::from_millis(100));
+        assert_eq!(transfer.speed, 0.0);
+        transfer.record(1000, start + Duration::from_secs(1));
+        assert_eq!(transfer.speed, 1000.0);
+        assert_eq!(transfer.percentage(), 33);
+        assert_eq!(transfer.eta(), Some(Duration::from_secs(2)));
+
+        transfer.status = TransferStatus::Paused;
+        assert_eq!(transfer.eta(), None);
+    }
+
+    #[tokio::test]
+    async fn unfinished_transfers_can_be_resumed_after_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transfers.json");
+        let transfers = Transfers::load(path.clone());
+        let upload = Transfer::upload("a.bin".into(), "a.bin".into(), "/".into(), 10);
+        let done = Transfer::upload("b.bin".into(), "b.bin".into(), "/".into(), 10);
+        let (upload_id, done_id) = (upload.id, done.id);
+        let control = transfers.start(upload);
+        transfers.start(done);
+        transfers.finish(done_id, TransferStatus::Completed);
+
+        transfers.pause(upload_id);
+        assert_eq!(
+            transfers.get(upload_id).map(|t| t.status),
+            Some(TransferStatus::Paused)
+        );
+        assert!(transfers.resume(upload_id));
+        assert!(control.proceed().await);
+
+        let reloaded = Transfers::load(path);
+        let list = reloaded.list();
+        assert_eq!(list.len(), 1);
+        assert!(list[0].is_resumable());
+        assert!(!reloaded.resume(upload_id));
+        assert!(reloaded.restart(upload_id).is_some());
+        assert_eq!(
+            reloaded.get(upload_id).map(|t| t.status),
+            Some(TransferStatus::Active)
+        );
+    }
+
+    #[test]
+    fn the_item_an_upload_left_is_remembered() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transfers.json");
+        let transfers = Transfers::load(path.clone());
+        let upload = Transfer::upload("a.bin".into(), "a.bin".into(), "/".into(), 10);
+        let id = upload.id;
+        let item = Uuid::new_v4();
+        transfers.start(upload);
+        transfers.set_item(id, item);
+        transfers.finish(id, TransferStatus::Failed("offline".into()));
+        assert_eq!(
+            Transfers::load(path).get(id).and_then(|t| t.item),
+            Some(item)
+        );
+
+        transfers.rename(id, "a (1).bin".into());
+        let renamed = transfers.get(id).unwrap();
+        assert_eq!(renamed.name, "a (1).bin");
+        assert_eq!(renamed.item, None);
+    }
+}
@@ -12,6 +12,10 @@ pub enum UploadFileAction<T> {
     UploadFiles(Vec<PathBuf>),
     Uploading((String, String, String)),
     Finishing(PathBuf, bool),
+    // a file with the same content is already in storage, at the given path
+    Duplicate(String, String),
+    // the files of a folder upload that failed, with the reason
+    UploadFailures(Vec<(String, String)>),
     Finished(T),
     Error,
 }
@@ -0,0 +1,340 @@
+use std::{collections::HashSet, fs, path::Path, str::FromStr};
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use warp::crypto::DID;
+
+use crate::state::State;
+
+const CSV_HEADER: [&str; 6] = ["did", "kind", "username", "nickname", "note", "tags"];
+// tags are kept in a single csv column
+const TAG_SEPARATOR: char = ';';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContactKind {
+    Friend,
+    Blocked,
+}
+
+/// someone in an exported contact list, with what the user kept about them
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ContactEntry {
+    pub did: DID,
+    pub kind: ContactKind,
+    // the username when the list was exported. only used to tell people apart in the preview
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub nickname: Option<String>,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct ContactList {
+    contacts: Vec<ContactEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactFormat {
+    Json,
+    Csv,
+}
+
+impl ContactFormat {
+    /// anything but a .csv file is read as json
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// What importing someone does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStatus {
+    /// a friend request is sent to them
+    New,
+    Friend,
+    /// a friend request was already sent to them, or received from them
+    Pending,
+    /// they are blocked here, so no request is sent
+    Blocked,
+    /// they were blocked on the other device and get blocked here too
+    Block,
+    /// the user's own identity, which is skipped
+    Own,
+}
+
+/// the friends and blocked users, sorted by username
+pub fn collect(state: &State) -> Vec<ContactEntry> {
+    let friends = state.friends();
+    let mut entries: Vec<ContactEntry> = friends
+        .all
+        .iter()
+        .map(|did| (did, ContactKind::Friend))
+        .chain(
+            friends
+                .blocked
+                .iter()
+                .map(|did| (did, ContactKind::Blocked)),
+        )
+        .map(|(did, kind)| {
+            let contact = state.contacts.get(did).cloned().unwrap_or_default();
+            ContactEntry {
+                did: did.clone(),
+                kind,
+                username: state
+                    .get_identity(did)
+                    .map(|i| i.username())
+                    .unwrap_or_default(),
+                nickname: contact.nickname,
+                note: contact.note,
+                tags: contact.tags.into_iter().collect(),
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        a.username
+            .to_lowercase()
+            .cmp(&b.username.to_lowercase())
+            .then(a.did.to_string().cmp(&b.did.to_string()))
+    });
+    entries
+}
+
+pub fn import_status(state: &State, entry: &ContactEntry) -> ImportStatus {
+    let friends = state.friends();
+    if entry.did == state.did_key() {
+        ImportStatus::Own
+    } else if state.is_blocked(&entry.did) {
+        ImportStatus::Blocked
+    } else if entry.kind == ContactKind::Blocked {
+        ImportStatus::Block
+    } else if state.has_friend_with_did(&entry.did) {
+        ImportStatus::Friend
+    } else if friends.outgoing_requests.contains(&entry.did)
+        || friends.incoming_requests.contains(&entry.did)
+    {
+        ImportStatus::Pending
+    } else {
+        ImportStatus::New
+    }
+}
+
+pub fn export(path: &Path, entries: &[ContactEntry]) -> anyhow::Result<()> {
+    let contents = match ContactFormat::from_path(path) {
+        ContactFormat::Json => serde_json::to_string_pretty(&ContactList {
+            contacts: entries.to_vec(),
+        })?,
+        ContactFormat::Csv => to_csv(entries),
+    };
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn import(path: &Path) -> anyhow::Result<Vec<ContactEntry>> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    parse(&contents, ContactFormat::from_path(path))
+}
+
+/// someone listed twice is only kept the first time
+pub fn parse(contents: &str, format: ContactFormat) -> anyhow::Result<Vec<ContactEntry>> {
+    let entries = match format {
+        ContactFormat::Json => serde_json::from_str::<ContactList>(contents)?.contacts,
+        ContactFormat::Csv => from_csv(contents)?,
+    };
+    let mut seen = HashSet::new();
+    Ok(entries
+        .into_iter()
+        .filter(|e| seen.insert(e.did.clone()))
+        .collect())
+}
+
+fn to_csv(entries: &[ContactEntry]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+    for entry in entries {
+        let kind = match entry.kind {
+            ContactKind::Friend => "friend",
+            ContactKind::Blocked => "blocked",
+        };
+        let fields = [
+            entry.did.to_string(),
+            kind.to_string(),
+            entry.username.clone(),
+            entry.nickname.clone().unwrap_or_default(),
+            entry.note.clone(),
+            entry.tags.join(&TAG_SEPARATOR.to_string()),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+// fields with a separator, a quote or a line break are quoted
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn from_csv(contents: &str) -> anyhow::Result<Vec<ContactEntry>> {
+    let mut rows = csv_rows(contents).into_iter();
+    let header = match rows.next() {
+        Some(header) => header,
+        None => return Ok(vec![]),
+    };
+    // the columns may be in any order, only the did is required
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    };
+    let did_column = match column("did") {
+        Some(c) => c,
+        None => bail!("the file has no did column"),
+    };
+    let kind_column = column("kind");
+    let username_column = column("username");
+    let nickname_column = column("nickname");
+    let note_column = column("note");
+    let tags_column = column("tags");
+
+    let mut entries = vec![];
+    for row in rows {
+        let field = |c: Option<usize>| {
+            c.and_then(|c| row.get(c))
+                .map(|f| f.trim().to_string())
+                .unwrap_or_default()
+        };
+        let did = field(Some(did_column));
+        if did.is_empty() {
+            continue;
+        }
+        let did = DID::from_str(&did).with_context(|| format!("invalid did: {did}"))?;
+        let kind = if field(kind_column).eq_ignore_ascii_case("blocked") {
+            ContactKind::Blocked
+        } else {
+            ContactKind::Friend
+        };
+        let nickname = field(nickname_column);
+        entries.push(ContactEntry {
+            did,
+            kind,
+            username: field(username_column),
+            nickname: (!nickname.is_empty()).then_some(nickname),
+            note: field(note_column),
+            tags: field(tags_column)
+                .split(TAG_SEPARATOR)
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect(),
+        });
+    }
+    Ok(entries)
+}
+
+// splits csv into rows of fields. quoted fields may contain separators and line breaks
+fn csv_rows(contents: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                } else {
+                    row.clear();
+                }
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entries() -> Vec<ContactEntry> {
+        vec![
+            ContactEntry {
+                did: DID::default(),
+                kind: ContactKind::Friend,
+                username: "Alice".into(),
+                nickname: Some("Al".into()),
+                note: "met at \"the\" conference, in may\nsecond line".into(),
+                tags: vec!["work".into(), "conference".into()],
+            },
+            ContactEntry {
+                did: DID::from_str("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+                    .expect("valid did"),
+                kind: ContactKind::Blocked,
+                username: "Mallory".into(),
+                nickname: None,
+                note: String::new(),
+                tags: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let csv = to_csv(&entries());
+        assert_eq!(
+            parse(&csv, ContactFormat::Csv).expect("valid csv"),
+            entries()
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let json = serde_json::to_string(&ContactList {
+            contacts: entries(),
+        })
+        .expect("serializable");
+        assert_eq!(
+            parse(&json, ContactFormat::Json).expect("valid json"),
+            entries()
+        );
+    }
+
+    #[test]
+    fn csv_needs_only_a_did() {
+        let did = DID::default().to_string();
+        let csv = format!("DID\r\n{did}\r\n\r\n{did}\r\n");
+        let parsed = parse(&csv, ContactFormat::Csv).expect("valid csv");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].kind, ContactKind::Friend);
+        assert!(parse("did\nnot-a-did\n", ContactFormat::Csv).is_err());
+    }
+}
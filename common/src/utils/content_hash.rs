@@ -0,0 +1,207 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::log;
+use uuid::Uuid;
+
+/// the sha256 of a file as hex. it is read in chunks, so large files aren't loaded at once
+pub fn hash_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// a file in storage, with its path from the root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredFile {
+    pub id: Uuid,
+    pub path: String,
+    pub size: usize,
+}
+
+/// storage files which have the same content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub hash: String,
+    pub size: usize,
+    // oldest first, so the first one is usually the one to keep
+    pub files: Vec<StoredFile>,
+}
+
+impl DuplicateGroup {
+    /// the space which is freed by keeping only one of the files
+    pub fn wasted(&self) -> usize {
+        self.size * self.files.len().saturating_sub(1)
+    }
+}
+
+/// the content hash of every storage file uploaded from this device. warp doesn't keep one, so it is saved locally
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HashIndex {
+    hashes: HashMap<Uuid, String>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl HashIndex {
+    pub fn load(path: PathBuf) -> Self {
+        let mut index: Self = std::fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        index.path = path;
+        index
+    }
+
+    pub fn save(&self) {
+        let contents = match serde_json::to_vec(&self) {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("failed to serialize hash index: {e}");
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(&self.path, contents) {
+            log::error!("failed to save hash index: {e}");
+        }
+    }
+
+    /// like `copy` and `retain`, it isn't saved until `save` is called, so that a batch of uploads
+    /// writes the index once
+    pub fn insert(&mut self, id: Uuid, hash: String) {
+        self.hashes.insert(id, hash);
+    }
+
+    /// a copy has the same content as the original
+    pub fn copy(&mut self, from: Uuid, to: Uuid) {
+        if let Some(hash) = self.hashes.get(&from).cloned() {
+            self.insert(to, hash);
+        }
+    }
+
+    /// forgets the files which were deleted. returns whether any were
+    pub fn retain(&mut self, existing: &HashSet<Uuid>) -> bool {
+        let count = self.hashes.len();
+        self.hashes.retain(|id, _| existing.contains(id));
+        self.hashes.len() != count
+    }
+
+    /// the first of `files` with this content
+    pub fn find<'a>(&self, hash: &str, files: &'a [StoredFile]) -> Option<&'a StoredFile> {
+        files
+            .iter()
+            .find(|file| self.hashes.get(&file.id).map(String::as_str) == Some(hash))
+    }
+
+    /// the files with the same content, the groups wasting the most space first.
+    /// files which weren't uploaded from this device have no hash and are left out
+    pub fn duplicates(&self, files: &[StoredFile]) -> Vec<DuplicateGroup> {
+        let mut groups: HashMap<&str, DuplicateGroup> = HashMap::new();
+        for file in files {
+            let hash = match self.hashes.get(&file.id) {
+                Some(hash) => hash,
+                None => continue,
+            };
+            groups
+                .entry(hash)
+                .or_insert_with(|| DuplicateGroup {
+                    hash: hash.clone(),
+                    size: file.size,
+                    files: vec![],
+                })
+                .files
+                .push(file.clone());
+        }
+        let mut groups: Vec<DuplicateGroup> = groups
+            .into_values()
+            .filter(|group| group.files.len() > 1)
+            .collect();
+        groups.sort_by(|a, b| b.wasted().cmp(&a.wasted()).then(a.hash.cmp(&b.hash)));
+        groups
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stored(path: &str, size: usize) -> StoredFile {
+        StoredFile {
+            id: Uuid::new_v4(),
+            path: path.into(),
+            size,
+        }
+    }
+
+    #[test]
+    fn files_are_hashed_by_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        std::fs::write(&a, b"abc").unwrap();
+        std::fs::write(&b, b"abc").unwrap();
+        assert_eq!(
+            hash_file(&a).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(hash_file(&a).unwrap(), hash_file(&b).unwrap());
+    }
+
+    #[test]
+    fn duplicates_are_grouped() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut index = HashIndex::load(dir.path().join("hashes.json"));
+        let files = vec![
+            stored("/a.png", 10),
+            stored("/photos/a.png", 10),
+            stored("/b.mp4", 100),
+            stored("/b (1).mp4", 100),
+            stored("/unique.txt", 5),
+            stored("/unknown.txt", 5),
+        ];
+        index.insert(files[0].id, "a".into());
+        index.insert(files[1].id, "a".into());
+        index.insert(files[2].id, "b".into());
+        index.copy(files[2].id, files[3].id);
+        index.insert(files[4].id, "c".into());
+        index.save();
+
+        let groups = index.duplicates(&files);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].hash, "b");
+        assert_eq!(groups[0].wasted(), 100);
+        assert_eq!(groups[1].files, files[..2].to_vec());
+        assert_eq!(index.find("c", &files), Some(&files[4]));
+
+        let loaded = HashIndex::load(dir.path().join("hashes.json"));
+        assert_eq!(loaded.duplicates(&files), groups);
+    }
+
+    #[test]
+    fn deleted_files_are_forgotten() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut index = HashIndex::load(dir.path().join("hashes.json"));
+        let files = vec![stored("/a.png", 10), stored("/b.png", 10)];
+        index.insert(files[0].id, "a".into());
+        index.insert(files[1].id, "a".into());
+
+        let existing = HashSet::from([files[0].id]);
+        assert!(index.retain(&existing));
+        assert!(!index.retain(&existing));
+        assert!(index.duplicates(&files).is_empty());
+        assert_eq!(index.find("a", &files), Some(&files[0]));
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    ffi::OsStr,
+    fs::{self, File},
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::bail;
+use flate2::read::GzDecoder;
+
+// big files are cut off, the preview is only meant to give an idea of what is inside
+const MAX_TEXT_BYTES: u64 = 256 * 1024;
+const MAX_HEX_BYTES: u64 = 4 * 1024;
+const MAX_PDF_PAGES: usize = 10;
+const MAX_ARCHIVE_ENTRIES: usize = 1000;
+const PDF_PAGE_WIDTH: &str = "1000";
+const TAR_BLOCK_SIZE: u64 = 512;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    pub name: String,
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilePreview {
+    // the extension is used to pick the highlighting
+    Text {
+        text: String,
+        extension: String,
+        truncated: bool,
+    },
+    // the rendered pages, in order
+    Pdf(Vec<PathBuf>),
+    Archive {
+        entries: Vec<ArchiveEntry>,
+        truncated: bool,
+    },
+    Hex {
+        lines: Vec<String>,
+        truncated: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+/// builds a preview of a downloaded file. the type is taken from `file_name`, as downloads may be renamed.
+/// PDF pages are rendered into `pages_dir`. files that aren't text, PDF or an archive are shown as hex.
+/// this reads the file and may run pdftoppm, so it shouldn't be called from the ui thread
+pub fn load_file_preview(
+    path: &Path,
+    file_name: &str,
+    pages_dir: &Path,
+) -> anyhow::Result<FilePreview> {
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(OsStr::to_str)
+        .map(|s| s.to_lowercase())
+        .unwrap_or_default();
+    if extension == "pdf" {
+        return Ok(FilePreview::Pdf(render_pdf_pages(path, pages_dir)?));
+    }
+    if let Some(kind) = archive_kind(file_name) {
+        let (entries, truncated) = list_archive(path, kind)?;
+        return Ok(FilePreview::Archive { entries, truncated });
+    }
+
+    let mut bytes = vec![];
+    let size = fs::metadata(path)?.len();
+    File::open(path)?
+        .take(MAX_TEXT_BYTES)
+        .read_to_end(&mut bytes)?;
+    let truncated = size > MAX_TEXT_BYTES;
+    if let Some(text) = as_text(&bytes, truncated) {
+        return Ok(FilePreview::Text {
+            text,
+            extension,
+            truncated,
+        });
+    }
+    bytes.truncate(MAX_HEX_BYTES as usize);
+    Ok(FilePreview::Hex {
+        lines: hex_dump(&bytes),
+        truncated: size > MAX_HEX_BYTES,
+    })
+}
+
+fn archive_kind(file_name: &str) -> Option<ArchiveKind> {
+    let name = file_name.to_lowercase();
+    if name.ends_with(".zip") {
+        Some(ArchiveKind::Zip)
+    } else if name.ends_with(".tar") {
+        Some(ArchiveKind::Tar)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveKind::TarGz)
+    } else {
+        None
+    }
+}
+
+// binary files usually have a NUL byte early on. a multi byte character may have been cut in half at the end
+fn as_text(bytes: &[u8], truncated: bool) -> Option<String> {
+    if bytes.iter().take(8 * 1024).any(|b| *b == 0) {
+        return None;
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text.to_string()),
+        Err(e) if truncated && e.error_len().is_none() => {
+            Some(String::from_utf8_lossy(&bytes[..e.valid_up_to()]).to_string())
+        }
+        Err(_) => None,
+    }
+}
+
+/// formats bytes like `hexdump -C`
+pub fn hex_dump(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let hex: Vec<String> = (0..16)
+                .map(|i| match chunk.get(i) {
+                    Some(b) => format!("{b:02x}"),
+                    None => "  ".into(),
+                })
+                .collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|b| {
+                    if b.is_ascii_graphic() || *b == b' ' {
+                        *b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!(
+                "{:08x}  {}  {}  |{ascii}|",
+                idx * 16,
+                hex[..8].join(" "),
+                hex[8..].join(" ")
+            )
+        })
+        .collect()
+}
+
+fn render_pdf_pages(path: &Path, pages_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut pages = find_pages(pages_dir);
+    if !pages.is_empty() {
+        return Ok(pages);
+    }
+    fs::create_dir_all(pages_dir)?;
+    let status = Command::new("pdftoppm")
+        .args([
+            "-png",
+            "-scale-to",
+            PDF_PAGE_WIDTH,
+            "-f",
+            "1",
+            "-l",
+            &MAX_PDF_PAGES.to_string(),
+        ])
+        .arg(path)
+        .arg(pages_dir.join("page"))
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    pages = find_pages(pages_dir);
+    if !status.success() || pages.is_empty() {
+        bail!("pdftoppm failed to render {}", path.display());
+    }
+    Ok(pages)
+}
+
+// pdftoppm pads the page numbers depending on the page count, e.g. page-01.png
+fn find_pages(pages_dir: &Path) -> Vec<PathBuf> {
+    let mut pages: Vec<PathBuf> = fs::read_dir(pages_dir)
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| p.extension().map(|e| e == "png").unwrap_or_default())
+                .collect()
+        })
+        .unwrap_or_default();
+    pages.sort();
+    pages
+}
+
+fn list_archive(path: &Path, kind: ArchiveKind) -> anyhow::Result<(Vec<ArchiveEntry>, bool)> {
+    let file = BufReader::new(File::open(path)?);
+    match kind {
+        ArchiveKind::Zip => list_zip(file),
+        ArchiveKind::Tar => list_tar(file),
+        ArchiveKind::TarGz => list_tar(GzDecoder::new(file)),
+    }
+}
+
+// the central directory is enough to list a zip, nothing gets decompressed
+fn list_zip<R: Read + io::Seek>(reader: R) -> anyhow::Result<(Vec<ArchiveEntry>, bool)> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut entries = vec![];
+    for idx in 0..archive.len().min(MAX_ARCHIVE_ENTRIES) {
+        let entry = archive.by_index_raw(idx)?;
+        entries.push(ArchiveEntry {
+            name: entry.name().to_string(),
+            size: entry.size(),
+            is_dir: entry.is_dir(),
+        });
+    }
+    Ok((entries, archive.len() > MAX_ARCHIVE_ENTRIES))
+}
+
+// tar has no index, so the headers are read one after the other and the contents are skipped
+fn list_tar<R: Read>(mut reader: R) -> anyhow::Result<(Vec<ArchiveEntry>, bool)> {
+    let mut entries = vec![];
+    let mut header = [0u8; TAR_BLOCK_SIZE as usize];
+    let mut long_name: Option<String> = None;
+    loop {
+        if !read_block(&mut reader, &mut header)? || header.iter().all(|b| *b == 0) {
+            return Ok((entries, false));
+        }
+        let size = parse_octal(&header[124..136])?;
+        let padded_size = size.div_ceil(TAR_BLOCK_SIZE) * TAR_BLOCK_SIZE;
+        match header[156] {
+            // gnu long names are stored as the contents of an extra entry before the real one
+            b'L' => {
+                let mut name = vec![];
+                (&mut reader).take(size).read_to_end(&mut name)?;
+                skip(&mut reader, padded_size - size)?;
+                long_name = Some(c_string(&name));
+                continue;
+            }
+            // pax headers can also hold the path
+            b'x' => {
+                let mut data = vec![];
+                (&mut reader).take(size).read_to_end(&mut data)?;
+                skip(&mut reader, padded_size - size)?;
+                long_name = pax_path(&data);
+                continue;
+            }
+            _ => {}
+        }
+
+        let name = match long_name.take() {
+            Some(name) => name,
+            None => {
+                let name = c_string(&header[..100]);
+                let prefix = c_string(&header[345..500]);
+                if &header[257..262] == b"ustar" && !prefix.is_empty() {
+                    format!("{prefix}/{name}")
+                } else {
+                    name
+                }
+            }
+        };
+        // global pax headers don't describe a file
+        if header[156] != b'g' {
+            if entries.len() == MAX_ARCHIVE_ENTRIES {
+                return Ok((entries, true));
+            }
+            entries.push(ArchiveEntry {
+                is_dir: header[156] == b'5' || name.ends_with('/'),
+                name,
+                size,
+            });
+        }
+        skip(&mut reader, padded_size)?;
+    }
+}
+
+// returns false at the end of the archive
+fn read_block<R: Read>(reader: &mut R, block: &mut [u8]) -> anyhow::Result<bool> {
+    let mut read = 0;
+    while read < block.len() {
+        match reader.read(&mut block[read..])? {
+            0 if read == 0 => return Ok(false),
+            0 => bail!("the archive ended in the middle of a header"),
+            n => read += n,
+        }
+    }
+    Ok(true)
+}
+
+fn skip<R: Read>(reader: &mut R, len: u64) -> io::Result<()> {
+    io::copy(&mut reader.take(len), &mut io::sink()).map(|_| ())
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).to_string()
+}
+
+fn parse_octal(bytes: &[u8]) -> anyhow::Result<u64> {
+    let text = c_string(bytes);
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(0);
+    }
+    Ok(u64::from_str_radix(text, 8)?)
+}
+
+// records look like "30 path=some/long/name\n"
+fn pax_path(data: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(data).lines().find_map(|line| {
+        let (_, record) = line.split_once(' ')?;
+        record.strip_prefix("path=").map(str::to_string)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tar_header(name: &str, size: u64, kind: u8) -> Vec<u8> {
+        let mut header = vec![0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        let size = format!("{size:011o}");
+        header[124..135].copy_from_slice(size.as_bytes());
+        header[156] = kind;
+        header[257..262].copy_from_slice(b"ustar");
+        header
+    }
+
+    #[test]
+    fn tar_entries_are_listed() {
+        let mut tar = tar_header("docs/", 0, b'5');
+        tar.extend(tar_header("docs/readme.md", 5, b'0'));
+        tar.extend(b"hello");
+        tar.extend([0u8; 507]);
+        tar.extend(tar_header("././@LongLink", 12, b'L'));
+        tar.extend(b"a/long/name\0");
+        tar.extend([0u8; 500]);
+        tar.extend(tar_header("a/long/na", 0, b'0'));
+        tar.extend([0u8; 1024]);
+
+        let (entries, truncated) = list_tar(&tar[..]).unwrap();
+        assert!(!truncated);
+        assert_eq!(
+            entries,
+            vec![
+                ArchiveEntry {
+                    name: "docs/".into(),
+                    size: 0,
+                    is_dir: true
+                },
+                ArchiveEntry {
+                    name: "docs/readme.md".into(),
+                    size: 5,
+                    is_dir: false
+                },
+                ArchiveEntry {
+                    name: "a/long/name".into(),
+                    size: 0,
+                    is_dir: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn text_is_told_apart_from_binary() {
+        assert_eq!(as_text(b"fn main() {}", false), Some("fn main() {}".into()));
+        assert_eq!(as_text(b"\x7fELF\0\0", false), None);
+        // a character cut in half by the size limit
+        assert_eq!(
+            as_text("ab€".as_bytes()[..3].as_ref(), true),
+            Some("ab".into())
+        );
+        assert_eq!(as_text("ab€".as_bytes()[..3].as_ref(), false), None);
+    }
+
+    #[test]
+    fn hex_dump_matches_hexdump() {
+        let lines = hex_dump(b"Hello, world!\n\x00\x01\xff");
+        assert_eq!(
+            lines,
+            vec![
+                "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|",
+                "00000010  ff                                                |.|",
+            ]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use tracing::log;
+use walkdir::WalkDir;
+
+/// a local folder to upload to storage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderUpload {
+    pub path: PathBuf,
+    // relative to `path`. parents come before their children
+    pub directories: Vec<PathBuf>,
+    // each local file with the directory it goes in, relative to `path`
+    pub files: Vec<(PathBuf, PathBuf)>,
+}
+
+impl FolderUpload {
+    /// walks the folder. entries which can't be read are skipped
+    pub fn read(path: &Path) -> Self {
+        let mut folder = Self {
+            path: path.to_path_buf(),
+            directories: vec![],
+            files: vec![],
+        };
+        for entry in WalkDir::new(path).min_depth(1).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    log::warn!("skipping an entry of {}: {e}", path.display());
+                    continue;
+                }
+            };
+            let relative = match entry.path().strip_prefix(path) {
+                Ok(relative) => relative.to_path_buf(),
+                Err(_) => continue,
+            };
+            if entry.file_type().is_dir() {
+                folder.directories.push(relative);
+            } else if entry.file_type().is_file() {
+                let parent = relative.parent().map(Path::to_path_buf).unwrap_or_default();
+                folder.files.push((entry.into_path(), parent));
+            }
+        }
+        folder
+    }
+
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// joins a path relative to the uploaded folder onto its name in storage, which always uses forward slashes
+pub fn remote_path(root: &str, relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .fold(root.to_string(), |path, part| format!("{path}/{part}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn folders_are_walked_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("photos");
+        fs::create_dir_all(root.join("2023/summer")).unwrap();
+        fs::create_dir_all(root.join("empty")).unwrap();
+        fs::write(root.join("cover.png"), b"").unwrap();
+        fs::write(root.join("2023/summer/beach.jpg"), b"").unwrap();
+
+        let folder = FolderUpload::read(&root);
+        assert_eq!(folder.name(), "photos");
+        assert_eq!(
+            folder.directories,
+            vec![
+                PathBuf::from("2023"),
+                PathBuf::from("2023/summer"),
+                PathBuf::from("empty")
+            ]
+        );
+        assert_eq!(
+            folder.files,
+            vec![
+                (
+                    root.join("2023/summer/beach.jpg"),
+                    PathBuf::from("2023/summer")
+                ),
+                (root.join("cover.png"), PathBuf::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn remote_paths_use_forward_slashes() {
+        assert_eq!(remote_path("photos", Path::new("")), "photos");
+        assert_eq!(
+            remote_path("photos (1)", &PathBuf::from("2023").join("summer")),
+            "photos (1)/2023/summer"
+        );
+    }
+}
@@ -0,0 +1,421 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use anyhow::Context;
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageFormat};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tracing::log;
+use warp::raygun::Location;
+
+use crate::{state::configuration::ImageProcessing, STATIC_ARGS};
+
+const PROCESSED_IMAGES_DIR: &str = "processed_images";
+// the processed copies are kept on disk, so only the last few are cached
+const MAX_CACHED_IMAGES: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessedImage {
+    pub path: PathBuf,
+    pub original_size: u64,
+    pub size: u64,
+}
+
+// the attachment preview and the upload share the result, so an image is only processed once
+type CacheKey = (PathBuf, Option<SystemTime>, ImageProcessing);
+static PROCESSED_IMAGES: Lazy<Mutex<ProcessedImages>> =
+    Lazy::new(|| Mutex::new(ProcessedImages::new(MAX_CACHED_IMAGES)));
+
+// the oldest image is dropped, together with its copy, when the cache is full
+struct ProcessedImages {
+    images: HashMap<CacheKey, Option<ProcessedImage>>,
+    order: VecDeque<CacheKey>,
+    capacity: usize,
+}
+
+impl ProcessedImages {
+    fn new(capacity: usize) -> Self {
+        Self {
+            images: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<&Option<ProcessedImage>> {
+        self.images.get(key)
+    }
+
+    fn insert(&mut self, key: CacheKey, image: Option<ProcessedImage>) {
+        if self.images.insert(key.clone(), image).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > self.capacity {
+            let evicted = match self.order.pop_front() {
+                Some(key) => self.images.remove(&key).flatten(),
+                None => break,
+            };
+            // every copy has a directory of its own
+            if let Some(dir) = evicted.as_ref().and_then(|image| image.path.parent()) {
+                let _ = fs::remove_dir_all(dir);
+            }
+        }
+    }
+}
+
+/// copies a JPEG or PNG without its metadata, downscaling it if needed. the copy is written to the temp files directory.
+/// returns None for other files and if there is nothing to change.
+pub fn process_image(
+    path: &Path,
+    options: &ImageProcessing,
+) -> anyhow::Result<Option<ProcessedImage>> {
+    if !options.is_enabled() {
+        return Ok(None);
+    }
+    // animated gifs would lose their animation and webp can't be encoded
+    let format = match ImageFormat::from_path(path) {
+        Ok(format @ (ImageFormat::Jpeg | ImageFormat::Png)) => format,
+        _ => return Ok(None),
+    };
+    let metadata = fs::metadata(path)?;
+    let key = (path.to_path_buf(), metadata.modified().ok(), *options);
+    if let Some(res) = PROCESSED_IMAGES.lock().get(&key) {
+        let still_exists = res.as_ref().map(|p| p.path.exists()).unwrap_or(true);
+        if still_exists {
+            return Ok(res.clone());
+        }
+    }
+
+    let dest_dir = STATIC_ARGS
+        .temp_files
+        .join(PROCESSED_IMAGES_DIR)
+        .join(uuid::Uuid::new_v4().to_string());
+    let res = process(path, format, metadata.len(), options, &dest_dir);
+    if !matches!(res, Ok(Some(_))) {
+        let _ = fs::remove_dir_all(&dest_dir);
+    }
+    let res = res?;
+    PROCESSED_IMAGES.lock().insert(key, res.clone());
+    Ok(res)
+}
+
+fn process(
+    path: &Path,
+    format: ImageFormat,
+    original_size: u64,
+    options: &ImageProcessing,
+    dest_dir: &Path,
+) -> anyhow::Result<Option<ProcessedImage>> {
+    let (width, height) = image::image_dimensions(path)?;
+    let too_big = options.resize && width.max(height) > options.max_dimension;
+    if !too_big && !options.strip_metadata {
+        return Ok(None);
+    }
+
+    fs::create_dir_all(dest_dir)?;
+    let dest = dest_dir.join(path.file_name().context("the image has no file name")?);
+    // a JPEG which keeps its size only loses its metadata, so it isn't re-encoded
+    if format == ImageFormat::Jpeg && !too_big {
+        let stripped = strip_jpeg_metadata(&fs::read(path)?, read_orientation(path))?;
+        fs::write(&dest, &stripped)?;
+        return Ok(Some(ProcessedImage {
+            path: dest,
+            original_size,
+            size: stripped.len() as u64,
+        }));
+    }
+    // the orientation is part of the metadata that gets dropped, so it is applied to the pixels instead
+    let mut image = apply_orientation(image::open(path)?, read_orientation(path));
+    if too_big {
+        image = image.resize(
+            options.max_dimension,
+            options.max_dimension,
+            FilterType::Lanczos3,
+        );
+    }
+
+    let mut writer = BufWriter::new(File::create(&dest)?);
+    match format {
+        ImageFormat::Jpeg => {
+            let rgb = image.to_rgb8();
+            JpegEncoder::new_with_quality(&mut writer, options.quality).encode(
+                &rgb,
+                rgb.width(),
+                rgb.height(),
+                image::ColorType::Rgb8,
+            )?;
+        }
+        _ => image.write_to(&mut writer, format)?,
+    }
+    writer.flush()?;
+    drop(writer);
+
+    let size = fs::metadata(&dest)?.len();
+    // when only resizing, a bigger file isn't worth it
+    if !options.strip_metadata && size >= original_size {
+        return Ok(None);
+    }
+    Ok(Some(ProcessedImage {
+        path: dest,
+        original_size,
+        size,
+    }))
+}
+
+// the segments which are dropped: exif and xmp, the other application segments except the colour
+// profile and the adobe colour transform, and comments. the pixels are copied as they are
+fn is_metadata_segment(marker: u8) -> bool {
+    matches!(marker, 0xE1 | 0xE3..=0xED | 0xEF | 0xFE)
+}
+
+/// removes the metadata of a JPEG without re-encoding it. the orientation is kept in an exif
+/// segment of its own, since it's needed to show the image the right way up
+fn strip_jpeg_metadata(data: &[u8], orientation: u32) -> anyhow::Result<Vec<u8>> {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        anyhow::bail!("not a JPEG");
+    }
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..2]);
+    let mut orientation_written = orientation == 1;
+    let mut pos = 2;
+    loop {
+        // markers can be padded with any number of 0xFF bytes
+        while data.get(pos + 1) == Some(&0xFF) {
+            pos += 1;
+        }
+        let marker = match data.get(pos..pos + 2) {
+            Some([0xFF, marker]) => *marker,
+            _ => anyhow::bail!("invalid JPEG segment at {pos}"),
+        };
+        // the exif segment goes after the JFIF header, which has to come first
+        if !orientation_written && marker != 0xE0 {
+            out.extend_from_slice(&orientation_segment(orientation as u16));
+            orientation_written = true;
+        }
+        // the compressed image data runs until the end of the file
+        if marker == 0xDA {
+            out.extend_from_slice(&data[pos..]);
+            return Ok(out);
+        }
+        if marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            out.extend_from_slice(&data[pos..pos + 2]);
+            pos += 2;
+            continue;
+        }
+        let len = match data.get(pos + 2..pos + 4) {
+            Some(len) => u16::from_be_bytes([len[0], len[1]]) as usize,
+            None => anyhow::bail!("truncated JPEG"),
+        };
+        let end = pos + 2 + len;
+        if len < 2 || end > data.len() {
+            anyhow::bail!("truncated JPEG");
+        }
+        if !is_metadata_segment(marker) {
+            out.extend_from_slice(&data[pos..end]);
+        }
+        pos = end;
+    }
+}
+
+// an exif segment with only the orientation in it
+fn orientation_segment(orientation: u16) -> Vec<u8> {
+    let mut exif = b"Exif\0\0".to_vec();
+    // big endian tiff header, with the first directory right after it
+    exif.extend_from_slice(&[b'M', b'M', 0, 42, 0, 0, 0, 8]);
+    // one entry: the orientation, a single short
+    exif.extend_from_slice(&[0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1]);
+    exif.extend_from_slice(&orientation.to_be_bytes());
+    exif.extend_from_slice(&[0, 0]);
+    // no further directories
+    exif.extend_from_slice(&[0, 0, 0, 0]);
+
+    let mut segment = vec![0xFF, 0xE1];
+    segment.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+    segment.extend_from_slice(&exif);
+    segment
+}
+
+fn read_orientation(path: &Path) -> u32 {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return 1,
+    };
+    exif::Reader::new()
+        .read_from_container(&mut BufReader::new(file))
+        .ok()
+        .and_then(|exif| {
+            exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+                .and_then(|field| field.value.get_uint(0))
+        })
+        .unwrap_or(1)
+}
+
+// https://www.impulseadventure.com/photo/exif-orientation.html
+fn apply_orientation(image: DynamicImage, orientation: u32) -> DynamicImage {
+    match orientation {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.rotate90().fliph(),
+        6 => image.rotate90(),
+        7 => image.rotate270().fliph(),
+        8 => image.rotate270(),
+        _ => image,
+    }
+}
+
+fn process_path(path: PathBuf, options: &ImageProcessing) -> PathBuf {
+    match process_image(&path, options) {
+        Ok(Some(processed)) => processed.path,
+        Ok(None) => path,
+        Err(e) => {
+            log::warn!("failed to process image {}: {e}", path.display());
+            path
+        }
+    }
+}
+
+/// swaps the local images in a message for processed copies. images which can't be processed are sent as they are
+pub fn process_attachments(attachments: Vec<Location>, options: &ImageProcessing) -> Vec<Location> {
+    attachments
+        .into_iter()
+        .map(|location| match location {
+            Location::Disk { path } => Location::Disk {
+                path: process_path(path, options),
+            },
+            other => other,
+        })
+        .collect()
+}
+
+/// same as `process_attachments`, for files uploaded to storage
+pub fn process_files(paths: Vec<PathBuf>, options: &ImageProcessing) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .map(|path| process_path(path, options))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use image::RgbImage;
+
+    #[test]
+    fn orientation_is_applied() {
+        let image = DynamicImage::ImageRgb8(RgbImage::new(4, 2));
+        assert_eq!(apply_orientation(image.clone(), 1).width(), 4);
+        assert_eq!(apply_orientation(image.clone(), 3).width(), 4);
+        assert_eq!(apply_orientation(image.clone(), 6).width(), 2);
+        assert_eq!(apply_orientation(image, 8).width(), 2);
+    }
+
+    #[test]
+    fn big_images_are_downscaled() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("photo.jpg");
+        DynamicImage::ImageRgb8(RgbImage::from_fn(400, 200, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 128])
+        }))
+        .save(&src)
+        .unwrap();
+        let original_size = fs::metadata(&src).unwrap().len();
+        let options = ImageProcessing {
+            strip_metadata: false,
+            resize: true,
+            max_dimension: 100,
+            quality: 80,
+        };
+
+        let processed = process(
+            &src,
+            ImageFormat::Jpeg,
+            original_size,
+            &options,
+            &dir.path().join("out"),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(processed.path.file_name(), src.file_name());
+        assert!(processed.size < original_size);
+        assert_eq!(image::image_dimensions(&processed.path).unwrap(), (100, 50));
+
+        // nothing to do for small images unless the metadata should be stripped
+        let options = ImageProcessing {
+            max_dimension: 1000,
+            ..options
+        };
+        let dest = dir.path().join("out2");
+        assert!(
+            process(&src, ImageFormat::Jpeg, original_size, &options, &dest)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn jpeg_metadata_is_stripped_without_re_encoding() {
+        let mut original = vec![];
+        DynamicImage::ImageRgb8(RgbImage::new(8, 4))
+            .write_to(&mut std::io::Cursor::new(&mut original), ImageFormat::Jpeg)
+            .unwrap();
+        // a comment and an exif segment holding something private, right after the start marker
+        let mut with_metadata = original[..2].to_vec();
+        with_metadata.extend_from_slice(&[0xFF, 0xFE, 0, 9]);
+        with_metadata.extend_from_slice(b"private");
+        let mut segment = b"Exif\0\0secret location".to_vec();
+        segment.splice(0..0, ((segment.len() + 2) as u16).to_be_bytes());
+        with_metadata.extend_from_slice(&[0xFF, 0xE1]);
+        with_metadata.extend_from_slice(&segment);
+        with_metadata.extend_from_slice(&original[2..]);
+
+        let stripped = strip_jpeg_metadata(&with_metadata, 1).unwrap();
+        assert_eq!(stripped, original);
+
+        let rotated = strip_jpeg_metadata(&with_metadata, 6).unwrap();
+        assert!(!rotated.windows(7).any(|w| w == b"private"));
+        assert!(!rotated.windows(6).any(|w| w == b"secret"));
+        let exif = exif::Reader::new()
+            .read_from_container(&mut std::io::Cursor::new(&rotated))
+            .unwrap();
+        let orientation = exif
+            .get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+            .and_then(|field| field.value.get_uint(0));
+        assert_eq!(orientation, Some(6));
+        assert_eq!(
+            image::load_from_memory(&rotated).unwrap().width(),
+            image::load_from_memory(&original).unwrap().width()
+        );
+    }
+
+    #[test]
+    fn the_oldest_processed_image_is_evicted() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ProcessedImages::new(2);
+        let mut keys = vec![];
+        for name in ["a", "b", "c"] {
+            let copy_dir = dir.path().join(name);
+            fs::create_dir_all(&copy_dir).unwrap();
+            let path = copy_dir.join("photo.jpg");
+            fs::write(&path, b"jpeg").unwrap();
+            let key = (PathBuf::from(name), None, ImageProcessing::default());
+            let image = ProcessedImage {
+                path,
+                original_size: 8,
+                size: 4,
+            };
+            cache.insert(key.clone(), Some(image));
+            keys.push(key);
+        }
+        assert!(cache.get(&keys[0]).is_none());
+        assert!(!dir.path().join("a").exists());
+        assert!(cache.get(&keys[1]).is_some());
+        assert!(cache.get(&keys[2]).is_some());
+        assert!(dir.path().join("c/photo.jpg").exists());
+    }
+}
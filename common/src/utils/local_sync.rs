@@ -0,0 +1,451 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::UNIX_EPOCH,
+};
+
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::log;
+use walkdir::WalkDir;
+
+/// downloads are written next to their file with this suffix, and renamed once they finish
+pub const PARTIAL_SUFFIX: &str = ".uplink-sync";
+
+/// files deleted from the local folder because they were deleted in storage are moved in here,
+/// so they can still be recovered. it isn't synced itself
+pub const SYNC_TRASH_DIR: &str = ".uplink-sync-trash";
+
+// a pass which would delete more than this share of the synced files is refused, so that a folder
+// which went missing or was emptied by mistake doesn't empty the other side too. a few deletes are
+// always fine
+const MAX_DELETED_SHARE: usize = 10;
+const ALWAYS_ALLOWED_DELETES: usize = 5;
+
+static SYNC_STATUS: Lazy<Mutex<HashMap<String, SyncStatus>>> = Lazy::new(Default::default);
+// changes whenever the status of an item changed, so that the storage page knows to reload
+static SYNC_REVISION: AtomicUsize = AtomicUsize::new(0);
+
+/// what is known about a file on one side, to tell whether it changed since the last sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileState {
+    // unix seconds
+    pub modified: i64,
+    pub size: usize,
+}
+
+/// files by their path relative to the synced folder, with forward slashes
+pub type Listing = BTreeMap<String, FileState>;
+
+/// both sides of a file as they were when it was last synced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedFile {
+    pub local: FileState,
+    pub remote: FileState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncAction {
+    Upload(String),
+    Download(String),
+    DeleteLocal(String),
+    DeleteRemote(String),
+    // both sides changed. the local file is renamed to `copy` and uploaded, then the remote one is downloaded
+    Conflict { path: String, copy: String },
+}
+
+impl SyncAction {
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Upload(path)
+            | Self::Download(path)
+            | Self::DeleteLocal(path)
+            | Self::DeleteRemote(path)
+            | Self::Conflict { path, .. } => path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncStatus {
+    Synced,
+    Syncing,
+    // a renamed copy made because both sides changed
+    Conflict,
+    Failed(String),
+}
+
+/// the local and storage folder of the last sync, and the files which were in sync then
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SyncSnapshot {
+    pub local_path: PathBuf,
+    pub remote_path: String,
+    pub files: BTreeMap<String, SyncedFile>,
+    // conflicted copies keep their status until they are deleted
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl SyncSnapshot {
+    /// a snapshot of other folders is discarded, so nothing gets deleted when the folders are changed
+    pub fn load(path: PathBuf, local_path: &Path, remote_path: &str) -> Self {
+        let snapshot: Option<Self> = std::fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok());
+        let mut snapshot = match snapshot {
+            Some(s) if s.local_path == local_path && s.remote_path == remote_path => s,
+            _ => Self {
+                local_path: local_path.to_path_buf(),
+                remote_path: remote_path.to_string(),
+                ..Default::default()
+            },
+        };
+        snapshot.path = path;
+        snapshot
+    }
+
+    pub fn save(&self) {
+        let contents = match serde_json::to_vec(&self) {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("failed to serialize sync snapshot: {e}");
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(&self.path, contents) {
+            log::error!("failed to save sync snapshot: {e}");
+        }
+    }
+}
+
+/// the files of the local folder. downloads which didn't finish are left out
+pub fn list_local(root: &Path) -> Listing {
+    let mut listing = Listing::new();
+    let walk = WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() != 1 || entry.file_name() != SYNC_TRASH_DIR);
+    for entry in walk {
+        let entry = match entry {
+            Ok(entry) if entry.file_type().is_file() => entry,
+            Ok(_) => continue,
+            Err(e) => {
+                log::warn!("skipping an entry of {}: {e}", root.display());
+                continue;
+            }
+        };
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .ends_with(PARTIAL_SUFFIX)
+        {
+            continue;
+        }
+        let relative = match entry.path().strip_prefix(root) {
+            Ok(relative) => relative,
+            Err(_) => continue,
+        };
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        let path = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        listing.insert(
+            path,
+            FileState {
+                modified,
+                size: metadata.len() as usize,
+            },
+        );
+    }
+    listing
+}
+
+/// what has to happen so that both sides have the same files. a file which is missing on one side
+/// was deleted there if it was synced before, otherwise it is new
+pub fn plan(
+    local: &Listing,
+    remote: &Listing,
+    synced: &BTreeMap<String, SyncedFile>,
+    today: NaiveDate,
+) -> Vec<SyncAction> {
+    let mut paths: Vec<&String> = local.keys().chain(remote.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    let mut actions = vec![];
+    for path in paths {
+        let previous = synced.get(path);
+        let action = match (local.get(path), remote.get(path), previous) {
+            (Some(_), None, Some(_)) => SyncAction::DeleteLocal(path.clone()),
+            (Some(_), None, None) => SyncAction::Upload(path.clone()),
+            (None, Some(_), Some(_)) => SyncAction::DeleteRemote(path.clone()),
+            (None, Some(_), None) => SyncAction::Download(path.clone()),
+            (Some(l), Some(r), Some(previous)) => {
+                match (*l != previous.local, *r != previous.remote) {
+                    (false, false) => continue,
+                    (true, false) => SyncAction::Upload(path.clone()),
+                    (false, true) => SyncAction::Download(path.clone()),
+                    (true, true) => SyncAction::Conflict {
+                        path: path.clone(),
+                        copy: conflict_name(path, today, |name| {
+                            local.contains_key(name) || remote.contains_key(name)
+                        }),
+                    },
+                }
+            }
+            // the same file was added on both sides before the first sync
+            (Some(l), Some(r), None) if l.size == r.size => continue,
+            (Some(_), Some(_), None) => SyncAction::Conflict {
+                path: path.clone(),
+                copy: conflict_name(path, today, |name| {
+                    local.contains_key(name) || remote.contains_key(name)
+                }),
+            },
+            (None, None, _) => continue,
+        };
+        actions.push(action);
+    }
+    actions
+}
+
+/// the number of files the actions delete, if that is more than a pass may delete.
+/// `synced` is the number of files which were in sync before
+pub fn too_many_deletes(actions: &[SyncAction], synced: usize) -> Option<usize> {
+    let deletes = actions
+        .iter()
+        .filter(|action| {
+            matches!(
+                action,
+                SyncAction::DeleteLocal(_) | SyncAction::DeleteRemote(_)
+            )
+        })
+        .count();
+    let allowed = ALWAYS_ALLOWED_DELETES.max(synced * MAX_DELETED_SHARE / 100);
+    (deletes > allowed).then_some(deletes)
+}
+
+/// moves a file of the local folder into its sync trash, keeping its path. a file deleted before
+/// with the same path is kept as well
+pub fn trash_local(root: &Path, path: &str) -> std::io::Result<()> {
+    let trash = root.join(SYNC_TRASH_DIR);
+    let mut target = trash.join(path);
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut n = 2;
+    while target.exists() {
+        target = trash.join(format!("{path} ({n})"));
+        n += 1;
+    }
+    std::fs::rename(root.join(path), target)
+}
+
+/// `notes (conflicted copy 2023-05-01).txt`, with a number added if that is taken as well
+pub fn conflict_name(path: &str, today: NaiveDate, taken: impl Fn(&str) -> bool) -> String {
+    let (parent, name) = match path.rsplit_once('/') {
+        Some((parent, name)) => (format!("{parent}/"), name),
+        None => (String::new(), path),
+    };
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{extension}")),
+        _ => (name, String::new()),
+    };
+    let date = today.format("%Y-%m-%d");
+    let mut copy = format!("{parent}{stem} (conflicted copy {date}){extension}");
+    let mut n = 2;
+    while taken(&copy) {
+        copy = format!("{parent}{stem} (conflicted copy {date} {n}){extension}");
+        n += 1;
+    }
+    copy
+}
+
+/// replaces the status of every synced item. `root` is the storage folder, and the paths are relative to it
+pub fn set_statuses(root: &str, statuses: HashMap<String, SyncStatus>) {
+    let root = root.trim_matches('/');
+    let statuses: HashMap<_, _> = statuses
+        .into_iter()
+        .map(|(path, status)| (storage_key(root, &path), status))
+        .collect();
+    let mut all = SYNC_STATUS.lock();
+    if *all != statuses {
+        *all = statuses;
+        SYNC_REVISION.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+pub fn set_status(root: &str, path: &str, status: SyncStatus) {
+    let key = storage_key(root.trim_matches('/'), path);
+    SYNC_STATUS.lock().insert(key, status);
+    SYNC_REVISION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// the status of a synced file, or of the files inside a synced folder. `path` is from the storage root
+pub fn sync_status(path: &str) -> Option<SyncStatus> {
+    let path = path.trim_matches('/');
+    let all = SYNC_STATUS.lock();
+    if let Some(status) = all.get(path) {
+        return Some(status.clone());
+    }
+    let prefix = format!("{path}/");
+    let mut inner = all
+        .iter()
+        .filter(|(key, _)| path.is_empty() || key.starts_with(&prefix))
+        .map(|(_, status)| status)
+        .peekable();
+    inner.peek()?;
+    // the most pressing status of the files inside is shown
+    let rank = |status: &SyncStatus| match status {
+        SyncStatus::Failed(_) => 3,
+        SyncStatus::Syncing => 2,
+        SyncStatus::Conflict => 1,
+        SyncStatus::Synced => 0,
+    };
+    inner.max_by_key(|status| rank(status)).cloned()
+}
+
+pub fn sync_revision() -> usize {
+    SYNC_REVISION.load(Ordering::Relaxed)
+}
+
+fn storage_key(root: &str, path: &str) -> String {
+    if root.is_empty() {
+        path.to_string()
+    } else {
+        format!("{root}/{path}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn state(modified: i64, size: usize) -> FileState {
+        FileState { modified, size }
+    }
+
+    fn listing(files: &[(&str, FileState)]) -> Listing {
+        files.iter().map(|(p, s)| (p.to_string(), *s)).collect()
+    }
+
+    #[test]
+    fn changes_are_planned_from_the_last_sync() {
+        let today = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+        let old = state(1, 10);
+        let new = state(2, 12);
+        let synced: BTreeMap<String, SyncedFile> = [
+            "same.txt",
+            "edited-here.txt",
+            "edited-there.txt",
+            "edited-both.txt",
+            "deleted-here.txt",
+            "deleted-there.txt",
+        ]
+        .iter()
+        .map(|p| {
+            (
+                p.to_string(),
+                SyncedFile {
+                    local: old,
+                    remote: old,
+                },
+            )
+        })
+        .collect();
+        let local = listing(&[
+            ("same.txt", old),
+            ("edited-here.txt", new),
+            ("edited-there.txt", old),
+            ("edited-both.txt", new),
+            ("deleted-there.txt", old),
+            ("new-here/a.txt", new),
+        ]);
+        let remote = listing(&[
+            ("same.txt", old),
+            ("edited-here.txt", old),
+            ("edited-there.txt", new),
+            ("edited-both.txt", new),
+            ("deleted-here.txt", old),
+            ("new-there.txt", new),
+        ]);
+
+        assert_eq!(
+            plan(&local, &remote, &synced, today),
+            vec![
+                SyncAction::DeleteRemote("deleted-here.txt".into()),
+                SyncAction::DeleteLocal("deleted-there.txt".into()),
+                SyncAction::Conflict {
+                    path: "edited-both.txt".into(),
+                    copy: "edited-both (conflicted copy 2023-05-01).txt".into(),
+                },
+                SyncAction::Upload("edited-here.txt".into()),
+                SyncAction::Download("edited-there.txt".into()),
+                SyncAction::Upload("new-here/a.txt".into()),
+                SyncAction::Download("new-there.txt".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicted_copies_get_unique_names() {
+        let today = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+        assert_eq!(
+            conflict_name("docs/notes.txt", today, |_| false),
+            "docs/notes (conflicted copy 2023-05-01).txt"
+        );
+        assert_eq!(
+            conflict_name("Makefile", today, |name| name
+                == "Makefile (conflicted copy 2023-05-01)"),
+            "Makefile (conflicted copy 2023-05-01 2)"
+        );
+    }
+
+    #[test]
+    fn mass_deletes_are_refused() {
+        let deletes = |count: usize| {
+            (0..count)
+                .map(|i| SyncAction::DeleteRemote(format!("{i}.txt")))
+                .chain([SyncAction::Upload("new.txt".into())])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(too_many_deletes(&deletes(5), 5), None);
+        assert_eq!(too_many_deletes(&deletes(6), 10), Some(6));
+        assert_eq!(too_many_deletes(&deletes(10), 100), None);
+        assert_eq!(too_many_deletes(&deletes(11), 100), Some(11));
+    }
+
+    #[test]
+    fn deleted_files_are_kept_in_the_sync_trash() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/a.txt"), b"first").unwrap();
+        trash_local(root, "docs/a.txt").unwrap();
+        std::fs::write(root.join("docs/a.txt"), b"second").unwrap();
+        trash_local(root, "docs/a.txt").unwrap();
+
+        let trash = root.join(SYNC_TRASH_DIR);
+        assert_eq!(std::fs::read(trash.join("docs/a.txt")).unwrap(), b"first");
+        assert_eq!(
+            std::fs::read(trash.join("docs/a.txt (2)")).unwrap(),
+            b"second"
+        );
+        assert!(list_local(root).is_empty());
+    }
+}
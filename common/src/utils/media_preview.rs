@@ -0,0 +1,196 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::bail;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tracing::log;
+
+use crate::is_video;
+
+// wide enough for the chat embeds and the storage grid
+const THUMBNAIL_MAX_WIDTH: u32 = 640;
+// the thumbnails are kept in memory, so only the last few previews are cached
+const MAX_CACHED_PREVIEWS: usize = 64;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MediaPreview {
+    // a data url with the first frame, only for videos
+    pub thumbnail: Option<String>,
+    pub duration: Option<Duration>,
+}
+
+type CacheKey = (PathBuf, Option<SystemTime>);
+static MEDIA_PREVIEWS: Lazy<Mutex<MediaPreviews>> =
+    Lazy::new(|| Mutex::new(MediaPreviews::new(MAX_CACHED_PREVIEWS)));
+
+// the oldest preview is dropped when the cache is full
+struct MediaPreviews {
+    previews: HashMap<CacheKey, MediaPreview>,
+    order: VecDeque<CacheKey>,
+    capacity: usize,
+}
+
+impl MediaPreviews {
+    fn new(capacity: usize) -> Self {
+        Self {
+            previews: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<&MediaPreview> {
+        self.previews.get(key)
+    }
+
+    fn insert(&mut self, key: CacheKey, preview: MediaPreview) {
+        if self.previews.insert(key.clone(), preview).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(key) => self.previews.remove(&key),
+                None => break,
+            };
+        }
+    }
+}
+
+/// returns the first frame of a video as a JPEG, using ffmpeg
+pub fn video_thumbnail(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let output = Command::new("ffmpeg")
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args([
+            "-frames:v",
+            "1",
+            "-vf",
+            &format!("scale='min({THUMBNAIL_MAX_WIDTH},iw)':-2"),
+            "-q:v",
+            "3",
+            "-f",
+            "image2pipe",
+            "-c:v",
+            "mjpeg",
+            "pipe:1",
+        ])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() || output.stdout.is_empty() {
+        bail!("no frame could be decoded from {}", path.display());
+    }
+    Ok(output.stdout)
+}
+
+/// returns the length of an audio or video file, using ffprobe
+pub fn media_duration(path: &Path) -> anyhow::Result<Duration> {
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "default=noprint_wrappers=1:nokey=1",
+        ])
+        .arg(path)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    match parse_duration(&String::from_utf8_lossy(&output.stdout)) {
+        Some(duration) => Ok(duration),
+        None => bail!("no duration found for {}", path.display()),
+    }
+}
+
+fn parse_duration(output: &str) -> Option<Duration> {
+    output
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// the thumbnail and duration of a local audio or video file. anything that can't be decoded is left as None.
+/// this runs ffmpeg, so it shouldn't be called from the ui thread
+pub fn get_media_preview(path: &Path) -> MediaPreview {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+    let key = (path.to_path_buf(), modified);
+    if let Some(preview) = MEDIA_PREVIEWS.lock().get(&key) {
+        return preview.clone();
+    }
+
+    let duration = media_duration(path)
+        .map_err(|e| log::warn!("failed to get media duration: {e}"))
+        .ok();
+    let thumbnail = if is_video(&path.to_string_lossy()) {
+        video_thumbnail(path)
+            .map_err(|e| log::warn!("failed to get video thumbnail: {e}"))
+            .ok()
+            .map(|jpeg| format!("data:image/jpeg;base64,{}", base64::encode(jpeg)))
+    } else {
+        None
+    };
+    let preview = MediaPreview {
+        thumbnail,
+        duration,
+    };
+    MEDIA_PREVIEWS.lock().insert(key, preview.clone());
+    preview
+}
+
+/// formats a duration as m:ss, or h:mm:ss for long videos
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, mins, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{mins:02}:{secs:02}")
+    } else {
+        format!("{mins}:{secs:02}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn durations_are_formatted() {
+        assert_eq!(format_duration(Duration::ZERO), "0:00");
+        assert_eq!(format_duration(Duration::from_millis(65_900)), "1:05");
+        assert_eq!(format_duration(Duration::from_secs(3600 + 62)), "1:01:02");
+    }
+
+    #[test]
+    fn ffprobe_output_is_parsed() {
+        assert_eq!(
+            parse_duration("12.500000\n"),
+            Some(Duration::from_millis(12500))
+        );
+        assert_eq!(parse_duration("N/A\n"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn the_oldest_preview_is_evicted() {
+        let mut cache = MediaPreviews::new(2);
+        let keys: Vec<CacheKey> = ["a", "b", "c"]
+            .into_iter()
+            .map(|name| (PathBuf::from(name), None))
+            .collect();
+        for key in &keys {
+            cache.insert(key.clone(), MediaPreview::default());
+        }
+        assert!(cache.get(&keys[0]).is_none());
+        assert!(cache.get(&keys[1]).is_some());
+        assert!(cache.get(&keys[2]).is_some());
+    }
+}
@@ -1,4 +1,17 @@
 pub mod clear_temp_files_dir;
+pub mod contact_list;
+pub mod content_hash;
+pub mod file_preview;
+pub mod folder_upload;
+pub mod image_processing;
 pub mod img_dimensions_preview;
 pub mod lifecycle;
 pub mod local_file_path;
+pub mod local_sync;
+pub mod media_preview;
+pub mod qr_code;
+pub mod safety_number;
+pub mod shared_content;
+pub mod storage_search;
+pub mod storage_usage;
+pub mod trash;
@@ -0,0 +1,81 @@
+use std::{io::Cursor, path::Path};
+
+use anyhow::{bail, Context};
+use image::{DynamicImage, GrayImage, ImageOutputFormat, Luma, RgbaImage};
+use qrcode::{Color, QrCode};
+use tracing::log;
+
+// the size of a module in pixels, and of the white border around the code in modules
+const MODULE_SIZE: u32 = 8;
+const QUIET_ZONE: u32 = 4;
+
+/// renders text as a black and white QR code
+pub fn qr_image(text: &str) -> anyhow::Result<GrayImage> {
+    let code = QrCode::new(text.as_bytes())?;
+    let width = code.width() as u32;
+    let colors = code.to_colors();
+    let size = (width + QUIET_ZONE * 2) * MODULE_SIZE;
+    Ok(GrayImage::from_fn(size, size, |x, y| {
+        let (x, y) = (x / MODULE_SIZE, y / MODULE_SIZE);
+        let inside = (QUIET_ZONE..width + QUIET_ZONE).contains(&x)
+            && (QUIET_ZONE..width + QUIET_ZONE).contains(&y);
+        let dark =
+            inside && colors[((y - QUIET_ZONE) * width + x - QUIET_ZONE) as usize] == Color::Dark;
+        Luma([if dark { 0 } else { 255 }])
+    }))
+}
+
+pub fn qr_png(text: &str) -> anyhow::Result<Vec<u8>> {
+    let mut png = Cursor::new(vec![]);
+    DynamicImage::ImageLuma8(qr_image(text)?).write_to(&mut png, ImageOutputFormat::Png)?;
+    Ok(png.into_inner())
+}
+
+/// a data url, so that the code can be shown in an img
+pub fn qr_data_url(text: &str) -> anyhow::Result<String> {
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::encode(qr_png(text)?)
+    ))
+}
+
+/// the text of the first QR code which can be read in the image. this is done locally
+pub fn decode_qr(image: &DynamicImage) -> anyhow::Result<String> {
+    let luma = image.to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(
+        luma.width() as usize,
+        luma.height() as usize,
+        |x, y| luma.get_pixel(x as u32, y as u32).0[0],
+    );
+    for grid in prepared.detect_grids() {
+        match grid.decode() {
+            Ok((_, text)) => return Ok(text),
+            Err(e) => log::warn!("failed to decode QR code: {e}"),
+        }
+    }
+    bail!("no QR code was found in the image")
+}
+
+pub fn decode_qr_file(path: &Path) -> anyhow::Result<String> {
+    let image = image::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    decode_qr(&image)
+}
+
+/// from raw RGBA pixels, like the ones of an image in the clipboard
+pub fn decode_qr_rgba(width: u32, height: u32, pixels: Vec<u8>) -> anyhow::Result<String> {
+    let image = RgbaImage::from_raw(width, height, pixels).context("invalid image")?;
+    decode_qr(&DynamicImage::ImageRgba8(image))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn qr_code_round_trip() {
+        let did = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+        let image = DynamicImage::ImageLuma8(qr_image(did).expect("valid qr code"));
+        assert_eq!(decode_qr(&image).expect("readable qr code"), did);
+        assert!(decode_qr(&DynamicImage::new_luma8(64, 64)).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use sha2::{Digest, Sha256};
+use warp::crypto::DID;
+
+// keeps the hashes from being useful for anything but comparing identities
+const DOMAIN: &[u8] = b"uplink-safety-number";
+const WORD_COUNT: usize = 8;
+
+/// the number both people see when comparing identities. it is the same on both sides,
+/// and changes if either key changes
+pub fn safety_number(own: &DID, other: &DID) -> String {
+    let (first, second) = ordered(own, other);
+    fingerprint(first)
+        .into_iter()
+        .chain(fingerprint(second))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// the same comparison as words, which are easier to read out loud
+pub fn safety_words(own: &DID, other: &DID) -> Vec<&'static str> {
+    let (first, second) = ordered(own, other);
+    let hash = Sha256::new()
+        .chain_update(DOMAIN)
+        .chain_update(first.to_string())
+        .chain_update(second.to_string())
+        .finalize();
+    hash.iter()
+        .take(WORD_COUNT)
+        .map(|byte| WORDS[*byte as usize])
+        .collect()
+}
+
+fn ordered<'a>(own: &'a DID, other: &'a DID) -> (&'a DID, &'a DID) {
+    if own.to_string() <= other.to_string() {
+        (own, other)
+    } else {
+        (other, own)
+    }
+}
+
+// six groups of five digits for one identity
+fn fingerprint(did: &DID) -> Vec<String> {
+    let hash = Sha256::new()
+        .chain_update(DOMAIN)
+        .chain_update(did.to_string())
+        .finalize();
+    hash.chunks_exact(5)
+        .map(|chunk| {
+            let value = chunk.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+            format!("{:05}", value % 100_000)
+        })
+        .collect()
+}
+
+const WORDS: [&str; 256] = [
+    "acid", "acorn", "actor", "adult", "agent", "alarm", "album", "alert", "alley", "alpha",
+    "amber", "angle", "ankle", "apple", "april", "apron", "arena", "armor", "arrow", "ashes",
+    "aspen", "atlas", "attic", "audio", "award", "bacon", "badge", "bagel", "baker", "bamboo",
+    "banjo", "barn", "basil", "basin", "beach", "beard", "beast", "bench", "berry", "bison",
+    "blade", "blaze", "block", "bloom", "board", "boat", "bonus", "boost", "booth", "bread",
+    "brick", "bride", "broom", "brush", "bubble", "bucket", "buddy", "bugle", "cabin", "cable",
+    "cactus", "camel", "candy", "canoe", "canyon", "cargo", "carpet", "castle", "cedar", "chain",
+    "chalk", "charm", "cheese", "cherry", "chess", "chief", "cider", "cigar", "circle", "clay",
+    "cliff", "clock", "cloud", "clover", "coach", "cobra", "cocoa", "comet", "coral", "cotton",
+    "couch", "crane", "crater", "crown", "cube", "daisy", "dance", "delta", "denim", "desert",
+    "diary", "dingo", "disco", "dock", "dolphin", "donkey", "dragon", "drum", "duck", "dune",
+    "eagle", "earth", "echo", "elbow", "elder", "ember", "engine", "falcon", "fancy", "feast",
+    "fern", "ferry", "fiber", "field", "flame", "flute", "focus", "forest", "fossil", "fox",
+    "frost", "fudge", "galaxy", "garden", "garlic", "gecko", "ghost", "giant", "ginger", "glacier",
+    "globe", "goose", "grape", "gravel", "guitar", "hammer", "harbor", "hazel", "helmet", "heron",
+    "hippo", "honey", "hotel", "husky", "igloo", "index", "iris", "island", "ivory", "jacket",
+    "jaguar", "jelly", "jewel", "jungle", "kayak", "kettle", "kiwi", "koala", "ladder", "lagoon",
+    "lemon", "lily", "linen", "lion", "lizard", "lobster", "locket", "lotus", "magnet", "mango",
+    "maple", "marble", "meadow", "melon", "metal", "mint", "mirror", "monkey", "moose", "mosaic",
+    "motor", "muffin", "napkin", "nectar", "needle", "nickel", "noodle", "oasis", "ocean", "olive",
+    "onion", "opera", "orbit", "otter", "owl", "oyster", "paddle", "panda", "panther", "parrot",
+    "peach", "pearl", "pebble", "pepper", "piano", "pickle", "pilot", "pine", "planet", "plum",
+    "polar", "pony", "poppy", "potato", "puzzle", "quartz", "quill", "rabbit", "radar", "radio",
+    "raven", "reef", "rhino", "ribbon", "river", "robin", "rocket", "ruby", "saddle", "salmon",
+    "sandal", "saturn", "scarf", "shark", "shell", "silver", "sketch", "sloth", "snail", "solar",
+    "spider", "spruce", "squid", "stone", "sugar", "summit",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{collections::HashSet, str::FromStr};
+
+    #[test]
+    fn safety_number_is_the_same_on_both_sides() {
+        let alice = DID::default();
+        let bob = DID::from_str("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+            .expect("valid did");
+
+        let number = safety_number(&alice, &bob);
+        assert_eq!(number, safety_number(&bob, &alice));
+        assert_eq!(number.split(' ').count(), 12);
+        assert_eq!(safety_words(&alice, &bob), safety_words(&bob, &alice));
+        assert_ne!(number, safety_number(&alice, &alice));
+        assert_eq!(WORDS.iter().collect::<HashSet<_>>().len(), WORDS.len());
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use linkify::{LinkFinder, LinkKind};
+use uuid::Uuid;
+use warp::{crypto::DID, raygun};
+
+use crate::{is_image, is_video, warp_runner::ui_adapter::MessageAttachment};
+
+/// a link sent in a conversation, with the message it came with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedLink {
+    pub message_id: Uuid,
+    pub sender: DID,
+    pub date: DateTime<Utc>,
+    pub url: String,
+}
+
+/// everything exchanged in a conversation, newest first. media are images and videos, files are the other attachments
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SharedContent {
+    pub media: Vec<MessageAttachment>,
+    pub files: Vec<MessageAttachment>,
+    pub links: Vec<SharedLink>,
+}
+
+impl SharedContent {
+    /// indexes a page of messages. the pages can be added in any order, and pages which overlap
+    /// don't list anything twice
+    pub fn add<'a>(&mut self, messages: impl IntoIterator<Item = &'a raygun::Message>) {
+        let mut attachments: HashSet<(Uuid, Uuid)> = self
+            .media
+            .iter()
+            .chain(self.files.iter())
+            .map(|a| (a.message_id, a.file.id()))
+            .collect();
+        let mut links: HashSet<(Uuid, String)> = self
+            .links
+            .iter()
+            .map(|l| (l.message_id, l.url.clone()))
+            .collect();
+        for message in messages {
+            for file in message.attachments() {
+                if !attachments.insert((message.id(), file.id())) {
+                    continue;
+                }
+                let name = file.name();
+                let attachment = MessageAttachment {
+                    conversation_id: message.conversation_id(),
+                    message_id: message.id(),
+                    sender: message.sender(),
+                    date: message.date(),
+                    file,
+                };
+                if is_image(&name) || is_video(&name) {
+                    self.media.push(attachment);
+                } else {
+                    self.files.push(attachment);
+                }
+            }
+            for url in find_links(&message.lines().join("\n")) {
+                if !links.insert((message.id(), url.clone())) {
+                    continue;
+                }
+                self.links.push(SharedLink {
+                    message_id: message.id(),
+                    sender: message.sender(),
+                    date: message.date(),
+                    url,
+                });
+            }
+        }
+        self.media.sort_by(|a, b| b.date.cmp(&a.date));
+        self.files.sort_by(|a, b| b.date.cmp(&a.date));
+        self.links.sort_by(|a, b| b.date.cmp(&a.date));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.media.is_empty() && self.files.is_empty() && self.links.is_empty()
+    }
+}
+
+/// the web links in the text of a message, in order
+pub fn find_links(text: &str) -> Vec<String> {
+    let mut finder = LinkFinder::new();
+    finder.kinds(&[LinkKind::Url]);
+    finder
+        .links(text)
+        .map(|link| link.as_str().to_string())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_web_links_are_found() {
+        assert_eq!(
+            find_links("see https://example.com/a?b=c, and http://test.org.\nnot ftp://files.net or mail@me.com"),
+            vec!["https://example.com/a?b=c", "http://test.org"]
+        );
+        assert!(find_links("no links here").is_empty());
+    }
+
+    #[test]
+    fn overlapping_pages_are_indexed_once() {
+        let mut first = raygun::Message::default();
+        first.set_id(Uuid::new_v4());
+        first.set_lines(vec!["https://example.com and https://example.com".into()]);
+        let mut second = raygun::Message::default();
+        second.set_id(Uuid::new_v4());
+        second.set_lines(vec!["https://example.com".into()]);
+
+        let mut content = SharedContent::default();
+        content.add([&first]);
+        content.add([&first, &second]);
+
+        // the same link twice in one message is listed once, but it is listed for every message
+        assert_eq!(content.links.len(), 2);
+    }
+}
@@ -0,0 +1,94 @@
+use std::{cmp::Ordering, path::Path};
+
+use chrono::{DateTime, Utc};
+use warp::constellation::{directory::Directory, file::File, item::Item};
+
+use crate::{
+    state::storage::{SortKey, StorageFilter, StorageSort},
+    TRASH_DIR_NAME,
+};
+
+/// a file found in storage, with the names of the directories leading to it from the root
+#[derive(Clone)]
+pub struct SearchResult {
+    pub directories: Vec<String>,
+    pub file: File,
+}
+
+impl SearchResult {
+    pub fn path(&self) -> String {
+        self.directories.join("/")
+    }
+}
+
+/// walks every directory below `root` looking for files which match the filter
+pub fn search_files(
+    root: &Directory,
+    filter: &StorageFilter,
+    now: DateTime<Utc>,
+) -> Vec<SearchResult> {
+    let mut results = vec![];
+    let mut to_visit = vec![(root.clone(), vec![])];
+    while let Some((directory, path)) = to_visit.pop() {
+        for item in directory.get_items() {
+            if let Ok(inner) = item.get_directory() {
+                if path.is_empty() && inner.name() == TRASH_DIR_NAME {
+                    continue;
+                }
+                let mut inner_path = path.clone();
+                inner_path.push(inner.name());
+                to_visit.push((inner, inner_path));
+            } else if let Ok(file) = item.get_file() {
+                if filter.matches(&file, now) {
+                    results.push(SearchResult {
+                        directories: path.clone(),
+                        file,
+                    });
+                }
+            }
+        }
+    }
+    results
+}
+
+fn extension(name: &str) -> String {
+    Path::new(name)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn compare(sort: StorageSort, a: &Item, b: &Item) -> Ordering {
+    let by_name = || a.name().to_lowercase().cmp(&b.name().to_lowercase());
+    let ordering = match sort.key {
+        SortKey::Name => by_name(),
+        SortKey::Size => a.size().cmp(&b.size()).then_with(by_name),
+        SortKey::Date => a.creation().cmp(&b.creation()).then_with(by_name),
+        SortKey::Type => extension(&a.name())
+            .cmp(&extension(&b.name()))
+            .then_with(by_name),
+    };
+    if sort.descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+pub fn sort_directories(directories: &mut [Directory], sort: StorageSort) {
+    directories.sort_by(|a, b| compare(sort, &Item::from(a.clone()), &Item::from(b.clone())));
+}
+
+pub fn sort_files(files: &mut [File], sort: StorageSort) {
+    files.sort_by(|a, b| compare(sort, &Item::from(a.clone()), &Item::from(b.clone())));
+}
+
+pub fn sort_search_results(results: &mut [SearchResult], sort: StorageSort) {
+    results.sort_by(|a, b| {
+        compare(
+            sort,
+            &Item::from(a.file.clone()),
+            &Item::from(b.file.clone()),
+        )
+    });
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::state::storage::FileKind;
+
+use super::content_hash::StoredFile;
+
+/// how many of the largest files are listed
+pub const LARGEST_FILES: usize = 10;
+
+/// the space taken by the files of one kind. `kind` is none for the files which aren't images, videos, audio or documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindUsage {
+    pub kind: Option<FileKind>,
+    pub size: usize,
+    pub count: usize,
+}
+
+/// the space taken by a folder of the root and everything inside it. `name` is none for the files in the root itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderUsage {
+    pub name: Option<String>,
+    pub size: usize,
+    pub count: usize,
+}
+
+/// what the space in storage is used for, the biggest first, so it is clear what to clean up to stay under the quota
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageUsage {
+    pub total: usize,
+    pub by_kind: Vec<KindUsage>,
+    pub by_folder: Vec<FolderUsage>,
+    pub largest: Vec<StoredFile>,
+}
+
+impl StorageUsage {
+    pub fn new(files: &[StoredFile]) -> Self {
+        let mut by_kind: HashMap<Option<FileKind>, KindUsage> = HashMap::new();
+        let mut by_folder: HashMap<Option<String>, FolderUsage> = HashMap::new();
+        for file in files {
+            let kind = FileKind::of(&file.path);
+            let usage = by_kind.entry(kind).or_insert(KindUsage {
+                kind,
+                size: 0,
+                count: 0,
+            });
+            usage.size += file.size;
+            usage.count += 1;
+
+            let name = top_level_folder(&file.path);
+            let usage = by_folder
+                .entry(name.clone())
+                .or_insert_with(|| FolderUsage {
+                    name,
+                    size: 0,
+                    count: 0,
+                });
+            usage.size += file.size;
+            usage.count += 1;
+        }
+
+        let mut by_kind: Vec<KindUsage> = by_kind.into_values().collect();
+        by_kind.sort_by(|a, b| b.size.cmp(&a.size).then(b.count.cmp(&a.count)));
+        let mut by_folder: Vec<FolderUsage> = by_folder.into_values().collect();
+        by_folder.sort_by(|a, b| b.size.cmp(&a.size).then(a.name.cmp(&b.name)));
+        let mut largest = files.to_vec();
+        largest.sort_by(|a, b| b.size.cmp(&a.size).then(a.path.cmp(&b.path)));
+        largest.truncate(LARGEST_FILES);
+
+        Self {
+            total: files.iter().map(|file| file.size).sum(),
+            by_kind,
+            by_folder,
+            largest,
+        }
+    }
+
+    /// the share of the used space, from 0 to 100
+    pub fn percentage(&self, size: usize) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        size as f64 * 100.0 / self.total as f64
+    }
+}
+
+fn top_level_folder(path: &str) -> Option<String> {
+    let mut names = path.split('/').filter(|name| !name.is_empty());
+    let first = names.next()?;
+    names.next().map(|_| first.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn stored(path: &str, size: usize) -> StoredFile {
+        StoredFile {
+            id: Uuid::new_v4(),
+            path: path.into(),
+            size,
+        }
+    }
+
+    #[test]
+    fn usage_is_grouped_by_kind_and_folder() {
+        let files = vec![
+            stored("/photos/a.png", 30),
+            stored("/photos/2023/b.jpg", 20),
+            stored("/videos/c.mp4", 100),
+            stored("/notes.txt", 5),
+            stored("/backup.zip", 45),
+        ];
+        let usage = StorageUsage::new(&files);
+
+        assert_eq!(usage.total, 200);
+        assert_eq!(usage.percentage(50), 25.0);
+        assert_eq!(
+            usage
+                .by_kind
+                .iter()
+                .map(|k| (k.kind, k.size, k.count))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(FileKind::Video), 100, 1),
+                (Some(FileKind::Image), 50, 2),
+                (None, 45, 1),
+                (Some(FileKind::Document), 5, 1),
+            ]
+        );
+        assert_eq!(
+            usage
+                .by_folder
+                .iter()
+                .map(|f| (f.name.as_deref(), f.size))
+                .collect::<Vec<_>>(),
+            vec![(Some("videos"), 100), (None, 50), (Some("photos"), 50)]
+        );
+        assert_eq!(usage.largest[0], files[2]);
+        assert_eq!(usage.largest.len(), 5);
+    }
+}
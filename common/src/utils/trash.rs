@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::log;
+use uuid::Uuid;
+
+/// an item which was deleted from storage and can still be restored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub id: Uuid,
+    // the name it had before it was deleted
+    pub name: String,
+    // the name it has in the trash directory, which differs if the trash already had an item called `name`
+    pub trash_name: String,
+    // the directory it was deleted from, from the root and with forward slashes
+    pub original_path: String,
+    pub deleted_at: DateTime<Utc>,
+    pub size: usize,
+    pub is_directory: bool,
+}
+
+impl TrashEntry {
+    /// whole days left until the entry is purged. None if the trash is never purged
+    pub fn days_left(&self, retention_days: u32, now: DateTime<Utc>) -> Option<i64> {
+        if retention_days == 0 {
+            return None;
+        }
+        let purge_at = self.deleted_at + Duration::days(retention_days.into());
+        Some((purge_at - now).num_days().max(0))
+    }
+
+    pub fn is_expired(&self, retention_days: u32, now: DateTime<Utc>) -> bool {
+        retention_days != 0 && now - self.deleted_at >= Duration::days(retention_days.into())
+    }
+}
+
+/// what is known about the items in the trash. warp only keeps the items, so this is saved locally
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TrashIndex {
+    pub entries: Vec<TrashEntry>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl TrashIndex {
+    pub fn load(path: PathBuf) -> Self {
+        let mut index: Self = std::fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        index.path = path;
+        index
+    }
+
+    pub fn save(&self) {
+        let contents = match serde_json::to_vec(&self) {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("failed to serialize trash index: {e}");
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(&self.path, contents) {
+            log::error!("failed to save trash index: {e}");
+        }
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&TrashEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn remove(&mut self, id: Uuid) -> Option<TrashEntry> {
+        let idx = self.entries.iter().position(|entry| entry.id == id)?;
+        Some(self.entries.remove(idx))
+    }
+
+    /// the entries which have been in the trash for longer than `retention_days`
+    pub fn expired(&self, retention_days: u32, now: DateTime<Utc>) -> Vec<Uuid> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_expired(retention_days, now))
+            .map(|entry| entry.id)
+            .collect()
+    }
+
+    /// the entries, most recently deleted first
+    pub fn sorted(&self) -> Vec<TrashEntry> {
+        let mut entries = self.entries.clone();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.deleted_at));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(name: &str, days_ago: i64, now: DateTime<Utc>) -> TrashEntry {
+        TrashEntry {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            trash_name: name.into(),
+            original_path: "/".into(),
+            deleted_at: now - Duration::days(days_ago),
+            size: 0,
+            is_directory: false,
+        }
+    }
+
+    #[test]
+    fn old_entries_expire() {
+        let now = Utc::now();
+        let old = entry("old.txt", 31, now);
+        let recent = entry("recent.txt", 2, now);
+        let index = TrashIndex {
+            entries: vec![old.clone(), recent.clone()],
+            path: PathBuf::new(),
+        };
+        assert_eq!(index.expired(30, now), vec![old.id]);
+        assert!(index.expired(0, now).is_empty());
+        assert_eq!(recent.days_left(30, now), Some(28));
+        assert_eq!(old.days_left(30, now), Some(0));
+        assert_eq!(recent.days_left(0, now), None);
+    }
+
+    #[test]
+    fn index_is_saved_and_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trash.json");
+        let now = Utc::now();
+        let mut index = TrashIndex::load(path.clone());
+        assert!(index.entries.is_empty());
+        index.entries.push(entry("a.txt", 1, now));
+        index.save();
+
+        let mut loaded = TrashIndex::load(path);
+        assert_eq!(loaded.entries, index.entries);
+        let id = index.entries[0].id;
+        assert_eq!(loaded.remove(id).map(|e| e.name), Some("a.txt".into()));
+        assert!(loaded.get(id).is_none());
+    }
+}
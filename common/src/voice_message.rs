@@ -0,0 +1,454 @@
+use std::{
+    fs::{self, File},
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context};
+use ogg::{PacketReader, PacketWriteEndInfo, PacketWriter};
+use opus::{Application, Bitrate, Channels, Decoder, Encoder};
+use parking_lot::Mutex;
+use rodio::cpal::{
+    self,
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    InputCallbackInfo, Sample, SampleFormat,
+};
+use tracing::log;
+
+use crate::STATIC_ARGS;
+
+const FILE_NAME_PREFIX: &str = "voice-message-";
+// written to the comment header of recorded clips, which is how the receiving side knows to show a player.
+// file names can be anything, so they aren't used for this
+const VOICE_MESSAGE_COMMENT: &str = "UPLINK_VOICE_MESSAGE=1";
+pub const MAX_VOICE_MESSAGE_DURATION: Duration = Duration::from_secs(5 * 60);
+const MIN_VOICE_MESSAGE_DURATION: Duration = Duration::from_millis(500);
+
+// opus always decodes at 48kHz. 20ms frames are the usual choice for voice
+const OPUS_SAMPLE_RATE: u32 = 48000;
+const FRAME_SIZE: usize = 960;
+// the largest frame opus can produce is 120ms
+const MAX_FRAME_SIZE: usize = 5760;
+const MAX_PACKET_SIZE: usize = 4000;
+const BITRATE: i32 = 24000;
+
+/// returns true if `path` is an ogg opus clip recorded as a voice message
+pub fn is_voice_message(path: &Path) -> bool {
+    let read_tags = || -> anyhow::Result<bool> {
+        let mut reader = PacketReader::new(BufReader::new(File::open(path)?));
+        let head = reader.read_packet()?.context("the file is empty")?;
+        if !head.data.starts_with(b"OpusHead") {
+            return Ok(false);
+        }
+        let tags = reader
+            .read_packet()?
+            .context("the comment header is missing")?;
+        Ok(is_tagged_as_voice_message(&tags.data))
+    };
+    read_tags().unwrap_or_default()
+}
+
+// https://www.rfc-editor.org/rfc/rfc7845#section-5.2
+fn is_tagged_as_voice_message(tags: &[u8]) -> bool {
+    let read_u32 = |pos: usize| -> Option<usize> {
+        let bytes = tags.get(pos..pos + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?) as usize)
+    };
+    if !tags.starts_with(b"OpusTags") {
+        return false;
+    }
+    let vendor_len = match read_u32(8) {
+        Some(len) => len,
+        None => return false,
+    };
+    let mut pos = 12 + vendor_len;
+    let count = match read_u32(pos) {
+        Some(count) => count,
+        None => return false,
+    };
+    pos += 4;
+    for _ in 0..count {
+        let len = match read_u32(pos) {
+            Some(len) => len,
+            None => return false,
+        };
+        pos += 4;
+        match tags.get(pos..pos + len) {
+            Some(comment) if comment == VOICE_MESSAGE_COMMENT.as_bytes() => return true,
+            Some(_) => pos += len,
+            None => return false,
+        }
+    }
+    false
+}
+
+#[derive(Default)]
+struct Recording {
+    // mono
+    samples: Vec<f32>,
+    sample_rate: u32,
+}
+
+/// records mono audio from an input device until `finish` or `cancel` is called.
+/// opening the device can take a while, so `start` and `finish` shouldn't be called from the UI thread.
+/// cpal streams can't be moved across threads on every platform, so the stream lives on its own thread.
+pub struct VoiceRecorder {
+    stop_tx: mpsc::Sender<()>,
+    handle: thread::JoinHandle<Recording>,
+    started: Instant,
+}
+
+impl VoiceRecorder {
+    /// `input_device` is the name of the device from the audio settings. the default input device is used if it is None
+    pub fn start(input_device: Option<&str>) -> anyhow::Result<Self> {
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let device_name = input_device.map(str::to_string);
+        let handle = thread::spawn(move || {
+            let samples = Arc::new(Mutex::new(Vec::new()));
+            let (stream, sample_rate) = match open_stream(device_name.as_deref(), samples.clone()) {
+                Ok(res) => {
+                    let _ = ready_tx.send(Ok(()));
+                    res
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return Recording::default();
+                }
+            };
+            // also returns if the recorder is dropped
+            let _ = stop_rx.recv();
+            drop(stream);
+            let samples = std::mem::take(&mut *samples.lock());
+            Recording {
+                samples,
+                sample_rate,
+            }
+        });
+        ready_rx
+            .recv()
+            .map_err(|_| anyhow!("the recording thread stopped unexpectedly"))??;
+        Ok(Self {
+            stop_tx,
+            handle,
+            started: Instant::now(),
+        })
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// stops the recording and encodes it as opus in an ogg container. returns the path of the file, which is in the temp files directory
+    pub fn finish(self) -> anyhow::Result<PathBuf> {
+        let _ = self.stop_tx.send(());
+        let recording = self
+            .handle
+            .join()
+            .map_err(|_| anyhow!("the recording thread panicked"))?;
+        let min_samples =
+            MIN_VOICE_MESSAGE_DURATION.as_millis() as usize * recording.sample_rate as usize / 1000;
+        if recording.samples.is_empty() || recording.samples.len() < min_samples {
+            bail!("the recording is too short");
+        }
+        let samples = resample(&recording.samples, recording.sample_rate, OPUS_SAMPLE_RATE);
+
+        fs::create_dir_all(&STATIC_ARGS.temp_files)?;
+        let path = STATIC_ARGS.temp_files.join(format!(
+            "{FILE_NAME_PREFIX}{}.ogg",
+            chrono::Local::now().format("%Y%m%d-%H%M%S%3f")
+        ));
+        let res = File::create(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| encode(&samples, file));
+        if let Err(e) = res {
+            let _ = fs::remove_file(&path);
+            return Err(e);
+        }
+        Ok(path)
+    }
+
+    pub fn cancel(self) {
+        let _ = self.stop_tx.send(());
+    }
+}
+
+fn open_stream(
+    device_name: Option<&str>,
+    samples: Arc<Mutex<Vec<f32>>>,
+) -> anyhow::Result<(cpal::Stream, u32)> {
+    let host = cpal::default_host();
+    let device = match device_name.filter(|name| *name != "default") {
+        Some(name) => host
+            .input_devices()?
+            .find(|device| device.name().map(|n| n == name).unwrap_or_default())
+            .with_context(|| format!("input device {name} not found"))?,
+        None => host
+            .default_input_device()
+            .context("no input device available")?,
+    };
+    let supported_config = device.default_input_config()?;
+    let sample_format = supported_config.sample_format();
+    let config = supported_config.config();
+    let channels = config.channels as usize;
+    let max_samples = MAX_VOICE_MESSAGE_DURATION.as_secs() as usize * config.sample_rate.0 as usize;
+    let on_error = |e: cpal::StreamError| log::error!("voice message input stream error: {e}");
+    let stream = match sample_format {
+        SampleFormat::F32 => device.build_input_stream(
+            &config,
+            push_samples::<f32>(channels, max_samples, samples),
+            on_error,
+        )?,
+        SampleFormat::I16 => device.build_input_stream(
+            &config,
+            push_samples::<i16>(channels, max_samples, samples),
+            on_error,
+        )?,
+        SampleFormat::U16 => device.build_input_stream(
+            &config,
+            push_samples::<u16>(channels, max_samples, samples),
+            on_error,
+        )?,
+    };
+    stream.play()?;
+    Ok((stream, config.sample_rate.0))
+}
+
+// downmixes to mono and stops collecting once the maximum duration is reached
+fn push_samples<T: Sample>(
+    channels: usize,
+    max_samples: usize,
+    samples: Arc<Mutex<Vec<f32>>>,
+) -> impl FnMut(&[T], &InputCallbackInfo) + Send + 'static {
+    move |data, _| {
+        let mut samples = samples.lock();
+        for frame in data.chunks(channels.max(1)) {
+            if samples.len() >= max_samples {
+                break;
+            }
+            let sum: f32 = frame.iter().map(|s| s.to_f32()).sum();
+            samples.push(sum / frame.len() as f32);
+        }
+    }
+}
+
+// linear interpolation is plenty for speech
+fn resample(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || samples.is_empty() || from == 0 {
+        return samples.to_vec();
+    }
+    let ratio = from as f64 / to as f64;
+    let len = (samples.len() as f64 / ratio).round() as usize;
+    (0..len)
+        .map(|i| {
+            let pos = i as f64 * ratio;
+            let idx = pos.floor() as usize;
+            let frac = (pos - idx as f64) as f32;
+            let a = samples[idx.min(samples.len() - 1)];
+            let b = samples[(idx + 1).min(samples.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+// https://www.rfc-editor.org/rfc/rfc7845#section-5.1
+fn opus_head(pre_skip: u16, input_sample_rate: u32) -> Vec<u8> {
+    let mut head = b"OpusHead".to_vec();
+    head.push(1); // version
+    head.push(1); // channel count
+    head.extend_from_slice(&pre_skip.to_le_bytes());
+    head.extend_from_slice(&input_sample_rate.to_le_bytes());
+    head.extend_from_slice(&0i16.to_le_bytes()); // output gain
+    head.push(0); // channel mapping family
+    head
+}
+
+// https://www.rfc-editor.org/rfc/rfc7845#section-5.2
+fn opus_tags() -> Vec<u8> {
+    let vendor = concat!("uplink ", env!("CARGO_PKG_VERSION"));
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor.as_bytes());
+    tags.extend_from_slice(&1u32.to_le_bytes()); // user comment count
+    tags.extend_from_slice(&(VOICE_MESSAGE_COMMENT.len() as u32).to_le_bytes());
+    tags.extend_from_slice(VOICE_MESSAGE_COMMENT.as_bytes());
+    tags
+}
+
+/// encodes 48kHz mono samples as an ogg opus stream
+fn encode<W: Write>(samples: &[f32], writer: W) -> anyhow::Result<()> {
+    let mut encoder = Encoder::new(OPUS_SAMPLE_RATE, Channels::Mono, Application::Voip)?;
+    encoder.set_bitrate(Bitrate::Bits(BITRATE))?;
+    let pre_skip = encoder.get_lookahead()? as usize;
+    let serial: u32 = rand::random();
+
+    let mut writer = PacketWriter::new(writer);
+    writer.write_packet(
+        opus_head(pre_skip as u16, OPUS_SAMPLE_RATE).into_boxed_slice(),
+        serial,
+        PacketWriteEndInfo::EndPage,
+        0,
+    )?;
+    writer.write_packet(
+        opus_tags().into_boxed_slice(),
+        serial,
+        PacketWriteEndInfo::EndPage,
+        0,
+    )?;
+
+    // the encoder delays its output by pre_skip samples, so the input is padded to flush it.
+    // the granule position of the last page tells the decoder where the audio really ends
+    let total = samples.len() + pre_skip;
+    let frames = (total + FRAME_SIZE - 1) / FRAME_SIZE;
+    let mut frame = [0f32; FRAME_SIZE];
+    let mut output = [0u8; MAX_PACKET_SIZE];
+    for i in 0..frames {
+        let start = (i * FRAME_SIZE).min(samples.len());
+        let end = ((i + 1) * FRAME_SIZE).min(samples.len());
+        frame.fill(0.0);
+        frame[..end - start].copy_from_slice(&samples[start..end]);
+        let len = encoder.encode_float(&frame, &mut output)?;
+        let is_last = i + 1 == frames;
+        let (granule, end_info) = if is_last {
+            (total, PacketWriteEndInfo::EndStream)
+        } else {
+            ((i + 1) * FRAME_SIZE, PacketWriteEndInfo::NormalPacket)
+        };
+        writer.write_packet(
+            output[..len].to_vec().into_boxed_slice(),
+            serial,
+            end_info,
+            granule as u64,
+        )?;
+    }
+    writer.into_inner().flush()?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waveform {
+    pub duration: Duration,
+    // between 0 and 1, scaled so that the loudest bar is 1
+    pub peaks: Vec<f32>,
+}
+
+/// decodes an ogg opus file and returns its duration and `bars` peaks to draw a waveform with
+pub fn get_waveform(path: &Path, bars: usize) -> anyhow::Result<Waveform> {
+    let mut reader = PacketReader::new(BufReader::new(File::open(path)?));
+    let head = reader.read_packet()?.context("the file is empty")?;
+    if head.data.len() < 19 || !head.data.starts_with(b"OpusHead") {
+        bail!("not an opus file");
+    }
+    let channels = match head.data[9] {
+        1 => Channels::Mono,
+        2 => Channels::Stereo,
+        n => bail!("unsupported channel count: {n}"),
+    };
+    let pre_skip = u16::from_le_bytes([head.data[10], head.data[11]]) as usize;
+    // the comment header
+    reader.read_packet()?;
+
+    let mut decoder = Decoder::new(OPUS_SAMPLE_RATE, channels)?;
+    let mut buffer = vec![0f32; MAX_FRAME_SIZE * channels as usize];
+    let mut frame_peaks = Vec::new();
+    let mut decoded = 0;
+    while let Some(packet) = reader.read_packet()? {
+        let len = decoder.decode_float(&packet.data, &mut buffer, false)?;
+        let peak = buffer[..len * channels as usize]
+            .iter()
+            .fold(0f32, |max, s| max.max(s.abs()));
+        frame_peaks.push(peak);
+        decoded += len;
+    }
+    let samples = decoded.saturating_sub(pre_skip) as u64;
+    Ok(Waveform {
+        duration: Duration::from_millis(samples * 1000 / OPUS_SAMPLE_RATE as u64),
+        peaks: bin_peaks(&frame_peaks, bars),
+    })
+}
+
+fn bin_peaks(peaks: &[f32], bars: usize) -> Vec<f32> {
+    if peaks.is_empty() || bars == 0 {
+        return vec![0.0; bars];
+    }
+    let binned: Vec<f32> = (0..bars)
+        .map(|i| {
+            let start = (i * peaks.len() / bars).min(peaks.len() - 1);
+            let end = ((i + 1) * peaks.len() / bars).clamp(start + 1, peaks.len());
+            peaks[start..end].iter().fold(0f32, |max, p| max.max(*p))
+        })
+        .collect();
+    let loudest = binned.iter().fold(0f32, |max, p| max.max(*p));
+    if loudest <= f32::EPSILON {
+        return binned;
+    }
+    binned.iter().map(|p| p / loudest).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn resample_keeps_duration() {
+        let samples: Vec<f32> = (0..44100).map(|i| (i as f32 / 100.0).sin()).collect();
+        assert_eq!(resample(&samples, 44100, 48000).len(), 48000);
+        assert_eq!(resample(&samples, 48000, 48000), samples);
+    }
+
+    #[test]
+    fn peaks_are_binned_and_scaled() {
+        let peaks = [0.1, 0.2, 0.5, 0.25];
+        assert_eq!(bin_peaks(&peaks, 2), vec![0.4, 1.0]);
+        // fewer peaks than bars repeats them
+        assert_eq!(bin_peaks(&[0.5], 3), vec![1.0, 1.0, 1.0]);
+        assert_eq!(bin_peaks(&[], 2), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn encoded_clips_can_be_decoded() {
+        // two seconds of a 440Hz tone getting louder
+        let samples: Vec<f32> = (0..2 * OPUS_SAMPLE_RATE as usize)
+            .map(|i| {
+                let t = i as f32 / OPUS_SAMPLE_RATE as f32;
+                (t * 440.0 * std::f32::consts::TAU).sin() * t / 2.0
+            })
+            .collect();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clip.ogg");
+        encode(&samples, File::create(&path).unwrap()).unwrap();
+
+        let waveform = get_waveform(&path, 10).unwrap();
+        let millis = waveform.duration.as_millis() as i64;
+        assert!((millis - 2000).abs() <= 20, "{millis}");
+        assert_eq!(waveform.peaks.len(), 10);
+        assert!(waveform.peaks[0] < waveform.peaks[9]);
+        assert!(is_voice_message(&path));
+    }
+
+    #[test]
+    fn only_tagged_clips_are_voice_messages() {
+        assert!(is_tagged_as_voice_message(&opus_tags()));
+
+        let mut untagged = b"OpusTags".to_vec();
+        untagged.extend_from_slice(&3u32.to_le_bytes());
+        untagged.extend_from_slice(b"abc");
+        untagged.extend_from_slice(&1u32.to_le_bytes());
+        untagged.extend_from_slice(&7u32.to_le_bytes());
+        untagged.extend_from_slice(b"TITLE=a");
+        assert!(!is_tagged_as_voice_message(&untagged));
+        // a comment count larger than the packet
+        untagged[15..19].copy_from_slice(&2u32.to_le_bytes());
+        assert!(!is_tagged_as_voice_message(&untagged));
+        assert!(!is_tagged_as_voice_message(b"OpusTags"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("voice-message-1.ogg");
+        fs::write(&path, b"not an ogg file").unwrap();
+        assert!(!is_voice_message(&path));
+        assert!(!is_voice_message(&dir.path().join("missing.ogg")));
+    }
+}
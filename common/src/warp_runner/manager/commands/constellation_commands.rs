@@ -1,42 +1,73 @@
 use std::{
+    collections::{HashMap, HashSet},
     ffi::OsStr,
-    io::{Read, Write},
+    io::Write,
     path::{Path, PathBuf},
     process::{Command, Stdio},
-    sync::mpsc,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
 };
 
+use chrono::Utc;
 use derive_more::Display;
 
-use futures::{channel::oneshot, stream, StreamExt};
+use futures::{
+    channel::oneshot,
+    future::{self, BoxFuture},
+    stream, FutureExt, StreamExt,
+};
 use humansize::{format_size, DECIMAL};
 use once_cell::sync::Lazy;
 use tempfile::TempDir;
+use tokio::task::JoinHandle;
 
 use crate::{
-    language::get_local_text,
-    state::storage::Storage as uplink_storage,
+    language::{get_local_text, get_local_text_with_args},
+    state::storage::{ConflictResolution, Storage as uplink_storage},
+    transfers::{Transfer, TransferControl, TransferKind, TransferStatus, TRANSFERS},
     upload_file_channel::{UploadFileAction, CANCEL_FILE_UPLOADLISTENER, UPLOAD_FILE_LISTENER},
-    ROOT_DIR_NAME, VIDEO_FILE_EXTENSIONS,
+    utils::{
+        content_hash::{hash_file, DuplicateGroup, HashIndex, StoredFile},
+        folder_upload::{remote_path, FolderUpload},
+        local_sync::{
+            self, FileState, Listing, SyncAction, SyncSnapshot, SyncStatus, SyncedFile,
+            PARTIAL_SUFFIX,
+        },
+        media_preview::video_thumbnail,
+        storage_usage::StorageUsage,
+        trash::{TrashEntry, TrashIndex},
+    },
+    ROOT_DIR_NAME, STATIC_ARGS, TRASH_DIR_NAME, VIDEO_FILE_EXTENSIONS,
 };
 use crate::{warp_runner::Storage as warp_storage, DOC_EXTENSIONS};
 
+use super::other_commands::zip_options;
+
 use warp::{
     constellation::{
         directory::Directory,
         file::File,
-        item::{FormatType, Item, ItemType},
+        item::{FormatType, Item},
         ConstellationProgressStream, Progression,
     },
     error::Error,
 };
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use tracing::log;
+use uuid::Uuid;
 
 static DIRECTORIES_AVAILABLE_TO_BROWSE: Lazy<RwLock<Vec<Directory>>> =
     Lazy::new(|| RwLock::new(Vec::new()));
 
+static TRASH_INDEX: Lazy<Mutex<TrashIndex>> =
+    Lazy::new(|| Mutex::new(TrashIndex::load(STATIC_ARGS.trash_index.clone())));
+
+static HASH_INDEX: Lazy<Mutex<HashIndex>> =
+    Lazy::new(|| Mutex::new(HashIndex::load(STATIC_ARGS.hash_index.clone())));
+
 #[derive(Display)]
 pub enum ConstellationCmd {
     #[display(fmt = "GetItemsFromCurrentDirectory")]
@@ -60,6 +91,8 @@ pub enum ConstellationCmd {
     },
     #[display(fmt = "UploadFiles {{ files_path: {files_path:?} }} ")]
     UploadFiles { files_path: Vec<PathBuf> },
+    #[display(fmt = "UploadFolder {{ path: {:?} }} ", "folder.path")]
+    UploadFolder { folder: FolderUpload },
     #[display(fmt = "RenameItems {{ old_name: {old_name}, new_name: {new_name} }} ")]
     RenameItem {
         old_name: String,
@@ -72,13 +105,93 @@ pub enum ConstellationCmd {
     DownloadFile {
         file_name: String,
         local_path_to_save_file: PathBuf,
+        transfer: Uuid,
+        rsp: oneshot::Sender<Result<ConstellationProgressStream, warp::error::Error>>,
+    },
+    // the files of the items, and of the directories inside them, are packed into a zip as they are downloaded
+    #[display(
+        fmt = "DownloadItemsAsZip {{ items: {}, local_path: {local_path:?} }} ",
+        "items.len()"
+    )]
+    DownloadItemsAsZip {
+        items: Vec<Item>,
+        local_path: PathBuf,
         rsp: oneshot::Sender<Result<ConstellationProgressStream, warp::error::Error>>,
     },
+    // downloads return the rest of the file, uploads are handled like `UploadFiles`
+    #[display(fmt = "ResumeTransfer {{ id: {id} }} ")]
+    ResumeTransfer {
+        id: Uuid,
+        rsp: oneshot::Sender<Result<Option<ConstellationProgressStream>, warp::error::Error>>,
+    },
+    // the path of a storage file with the same content as a local file, by the hash of that file.
+    // hashing a large file takes a while, so it's done before the command is sent
+    #[display(fmt = "FindInStorage {{ hash: {hash} }} ")]
+    FindInStorage {
+        hash: String,
+        rsp: oneshot::Sender<Result<Option<String>, warp::error::Error>>,
+    },
     #[display(fmt = "DeleteItems {{ item: {item:?} }} ")]
     DeleteItems {
         item: Item,
         rsp: oneshot::Sender<Result<uplink_storage, warp::error::Error>>,
     },
+    // runs one pass of the local sync
+    #[display(fmt = "SyncFolder {{ local_path: {local_path:?}, remote_path: {remote_path} }} ")]
+    SyncFolder {
+        local_path: PathBuf,
+        remote_path: String,
+        rsp: oneshot::Sender<Result<(), warp::error::Error>>,
+    },
+    // moves a file from anywhere in storage to the trash
+    #[display(fmt = "MoveFileToTrash {{ path: {path} }} ")]
+    MoveFileToTrash {
+        path: String,
+        rsp: oneshot::Sender<Result<uplink_storage, warp::error::Error>>,
+    },
+    #[display(fmt = "RestoreFromTrash {{ ids: {ids:?} }} ")]
+    RestoreFromTrash {
+        ids: Vec<Uuid>,
+        rsp: oneshot::Sender<Result<uplink_storage, warp::error::Error>>,
+    },
+    #[display(fmt = "DeleteFromTrash {{ ids: {ids:?} }} ")]
+    DeleteFromTrash {
+        ids: Vec<Uuid>,
+        rsp: oneshot::Sender<Result<uplink_storage, warp::error::Error>>,
+    },
+    #[display(fmt = "PurgeTrash {{ retention_days: {retention_days} }} ")]
+    PurgeTrash {
+        retention_days: u32,
+        rsp: oneshot::Sender<Result<uplink_storage, warp::error::Error>>,
+    },
+    // the items in the trash and the space they take
+    #[display(fmt = "GetTrash")]
+    GetTrash {
+        rsp: oneshot::Sender<Result<(Vec<TrashEntry>, usize), warp::error::Error>>,
+    },
+    // these walk the whole storage, so they are only computed when their view is opened
+    #[display(fmt = "GetDuplicates")]
+    GetDuplicates {
+        rsp: oneshot::Sender<Result<Vec<DuplicateGroup>, warp::error::Error>>,
+    },
+    #[display(fmt = "GetStorageUsage")]
+    GetStorageUsage {
+        rsp: oneshot::Sender<Result<StorageUsage, warp::error::Error>>,
+    },
+    #[display(fmt = "MoveItems {{ destination: {} }} ", "destination.name()")]
+    MoveItems {
+        items: Vec<Item>,
+        destination: Directory,
+        resolution: ConflictResolution,
+        rsp: oneshot::Sender<Result<(uplink_storage, Vec<(String, String)>), warp::error::Error>>,
+    },
+    #[display(fmt = "CopyItems {{ destination: {} }} ", "destination.name()")]
+    CopyItems {
+        items: Vec<Item>,
+        destination: Directory,
+        resolution: ConflictResolution,
+        rsp: oneshot::Sender<Result<(uplink_storage, Vec<(String, String)>), warp::error::Error>>,
+    },
 }
 
 pub async fn handle_constellation_cmd(cmd: ConstellationCmd, warp_storage: &mut warp_storage) {
@@ -108,12 +221,56 @@ pub async fn handle_constellation_cmd(cmd: ConstellationCmd, warp_storage: &mut
         ConstellationCmd::UploadFiles { files_path } => {
             upload_files(warp_storage, files_path).await;
         }
+        ConstellationCmd::UploadFolder { folder } => {
+            upload_folder(warp_storage, folder).await;
+        }
         ConstellationCmd::DownloadFile {
             file_name,
             local_path_to_save_file,
+            transfer,
+            rsp,
+        } => {
+            let r = download_file(
+                warp_storage,
+                file_name,
+                local_path_to_save_file,
+                transfer,
+                false,
+            )
+            .await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::DownloadItemsAsZip {
+            items,
+            local_path,
             rsp,
         } => {
-            let r = download_file(warp_storage, file_name, local_path_to_save_file).await;
+            let r = download_items_as_zip(warp_storage, items, local_path).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::ResumeTransfer { id, rsp } => {
+            let r = resume_transfer(warp_storage, id).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::FindInStorage { hash, rsp } => {
+            let _ = rsp.send(Ok(find_in_storage(warp_storage, &hash)));
+        }
+        ConstellationCmd::SyncFolder {
+            local_path,
+            remote_path,
+            rsp,
+        } => match sync_folder(warp_storage, local_path, remote_path).await {
+            Ok(pass) => {
+                tokio::spawn(async move {
+                    let _ = rsp.send(pass.await);
+                });
+            }
+            Err(e) => {
+                let _ = rsp.send(Err(e));
+            }
+        },
+        ConstellationCmd::MoveFileToTrash { path, rsp } => {
+            let r = move_file_to_trash(warp_storage, path).await;
             let _ = rsp.send(r);
         }
         ConstellationCmd::RenameItem {
@@ -125,119 +282,639 @@ pub async fn handle_constellation_cmd(cmd: ConstellationCmd, warp_storage: &mut
             let _ = rsp.send(r);
         }
         ConstellationCmd::DeleteItems { item, rsp } => {
-            let r = delete_items(warp_storage, item).await;
+            let r = move_to_trash(warp_storage, item).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::RestoreFromTrash { ids, rsp } => {
+            let r = restore_from_trash(warp_storage, ids).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::DeleteFromTrash { ids, rsp } => {
+            let r = delete_from_trash(warp_storage, ids).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::PurgeTrash {
+            retention_days,
+            rsp,
+        } => {
+            let ids = TRASH_INDEX.lock().expired(retention_days, Utc::now());
+            let r = delete_from_trash(warp_storage, ids).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::GetTrash { rsp } => {
+            let _ = rsp.send(Ok(trash_entries(warp_storage)));
+        }
+        ConstellationCmd::GetDuplicates { rsp } => {
+            let stored_files = storage_files(warp_storage);
+            let _ = rsp.send(Ok(HASH_INDEX.lock().duplicates(&stored_files)));
+        }
+        ConstellationCmd::GetStorageUsage { rsp } => {
+            let _ = rsp.send(Ok(StorageUsage::new(&storage_files(warp_storage))));
+        }
+        ConstellationCmd::MoveItems {
+            items,
+            destination,
+            resolution,
+            rsp,
+        } => {
+            let r = move_items(warp_storage, items, destination, resolution).await;
+            let _ = rsp.send(r);
+        }
+        ConstellationCmd::CopyItems {
+            items,
+            destination,
+            resolution,
+            rsp,
+        } => {
+            let r = copy_items(warp_storage, items, destination, resolution).await;
             let _ = rsp.send(r);
         }
     }
 }
 
-async fn delete_items(
+// the trash is kept in a directory, so nothing else can take its name
+fn check_reserved_name(name: &str) -> Result<(), Error> {
+    if name.trim() == TRASH_DIR_NAME {
+        return Err(anyhow::anyhow!(get_local_text_with_args(
+            "files.reserved-name",
+            vec![("name", TRASH_DIR_NAME)],
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+// like `check_reserved_name`, for a name which is only reserved at the root
+fn check_reserved_name_at(
+    warp_storage: &warp_storage,
+    directory: &Directory,
+    name: &str,
+) -> Result<(), Error> {
+    if directory.id() != warp_storage.root_directory().id() {
+        return Ok(());
+    }
+    check_reserved_name(name)
+}
+
+fn trash_path() -> PathBuf {
+    join_storage_path(Path::new("/"), TRASH_DIR_NAME)
+}
+
+// the trash is created the first time something is deleted
+async fn trash_directory(warp_storage: &mut warp_storage) -> Result<Directory, Error> {
+    let root = warp_storage.root_directory();
+    if !root.has_item(TRASH_DIR_NAME) {
+        let base_path = warp_storage.get_path().to_path_buf();
+        warp_storage.set_path("/".into());
+        let res = warp_storage.create_directory(TRASH_DIR_NAME, true).await;
+        warp_storage.set_path(base_path);
+        res?;
+    }
+    root.get_item(TRASH_DIR_NAME)?.get_directory()
+}
+
+// the items in the trash with where they came from, and the space they take
+fn trash_entries(warp_storage: &warp_storage) -> (Vec<TrashEntry>, usize) {
+    let trash = match warp_storage.root_directory().get_item(TRASH_DIR_NAME) {
+        Ok(trash) => trash,
+        Err(_) => return (vec![], 0),
+    };
+    let items = match trash.get_directory() {
+        Ok(directory) => directory.get_items(),
+        Err(_) => return (vec![], 0),
+    };
+    let mut index = TRASH_INDEX.lock();
+    let known = index.entries.len();
+    index
+        .entries
+        .retain(|entry| items.iter().any(|item| item.name() == entry.trash_name));
+    let mut changed = index.entries.len() != known;
+    // the index is local, so it can miss items. those are restored to the root
+    for item in items {
+        if index
+            .entries
+            .iter()
+            .any(|entry| entry.trash_name == item.name())
+        {
+            continue;
+        }
+        index.entries.push(TrashEntry {
+            id: item.id(),
+            name: item.name(),
+            trash_name: item.name(),
+            original_path: "/".into(),
+            deleted_at: Utc::now(),
+            size: item.size(),
+            is_directory: item.is_directory(),
+        });
+        changed = true;
+    }
+    if changed {
+        index.save();
+    }
+    (index.sorted(), trash.size())
+}
+
+async fn move_to_trash(
     warp_storage: &mut warp_storage,
     item: Item,
 ) -> Result<uplink_storage, Error> {
-    // If is file, just a small function solve it
-    if item.is_file() {
-        let file_name = item.name();
-        match warp_storage.remove(&file_name, false).await {
-            Ok(_) => log::info!("File deleted: {:?}", file_name),
-            Err(error) => log::error!("Error to delete file {:?}, {:?}", file_name, error),
+    trash_item(warp_storage, item).await?;
+    get_items_from_current_directory(warp_storage)
+}
+
+// the item keeps its content, it is only moved from the current directory into the trash
+async fn trash_item(warp_storage: &mut warp_storage, item: Item) -> Result<(), Error> {
+    let current_directory = warp_storage.current_directory()?;
+    let trash = trash_directory(warp_storage).await?;
+    let name = item.name();
+    let trash_name = rename_if_duplicate(trash.clone(), name.clone(), PathBuf::from(&name));
+    if trash_name != name {
+        if current_directory.has_item(&trash_name) {
+            return Err(anyhow::anyhow!(get_local_text("files.file-already-with-name")).into());
+        }
+        warp_storage.rename(&name, &trash_name).await?;
+    }
+    let deleted = current_directory.remove_item(&trash_name)?;
+    if let Err(e) = trash.add_item(deleted.clone()) {
+        current_directory.add_item(deleted)?;
+        return Err(e);
+    }
+    log::info!("{name} moved to the trash");
+
+    let mut index = TRASH_INDEX.lock();
+    index.entries.push(TrashEntry {
+        id: item.id(),
+        name,
+        trash_name,
+        original_path: storage_path(warp_storage.get_path()),
+        deleted_at: Utc::now(),
+        size: item.size(),
+        is_directory: item.is_directory(),
+    });
+    index.save();
+    Ok(())
+}
+
+async fn move_file_to_trash(
+    warp_storage: &mut warp_storage,
+    path: String,
+) -> Result<uplink_storage, Error> {
+    let path = PathBuf::from(path);
+    let (parent, name) = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => (parent.to_path_buf(), name.to_string_lossy().to_string()),
+        _ => return Err(Error::InvalidItem),
+    };
+    let base_path = warp_storage.get_path().to_path_buf();
+    warp_storage.set_path(parent);
+    let res = match warp_storage
+        .current_directory()
+        .and_then(|directory| directory.get_item(&name))
+    {
+        Ok(item) => trash_item(warp_storage, item).await,
+        Err(e) => Err(e),
+    };
+    warp_storage.set_path(base_path);
+    res?;
+    get_items_from_current_directory(warp_storage)
+}
+
+// the directory an item was deleted from. it is created again if it was deleted as well
+async fn original_directory(
+    warp_storage: &mut warp_storage,
+    original_path: &str,
+) -> Result<Directory, Error> {
+    let mut directory = warp_storage.root_directory();
+    let mut path = PathBuf::from("/");
+    for name in original_path.split('/').filter(|name| !name.is_empty()) {
+        if !directory.has_item(name) {
+            warp_storage.set_path(path.clone());
+            warp_storage.create_directory(name, true).await?;
+        }
+        directory = directory.get_item(name)?.get_directory()?;
+        path = join_storage_path(&path, name);
+    }
+    Ok(directory)
+}
+
+async fn restore_item(
+    warp_storage: &mut warp_storage,
+    trash: &Directory,
+    entry: &TrashEntry,
+) -> Result<(), Error> {
+    if !trash.has_item(&entry.trash_name) {
+        return Err(Error::InvalidItem);
+    }
+    let destination = original_directory(warp_storage, &entry.original_path).await?;
+    // keeps both if something with the same name was added since
+    let name = rename_if_duplicate(
+        destination.clone(),
+        entry.name.clone(),
+        PathBuf::from(&entry.name),
+    );
+    if name != entry.trash_name {
+        if trash.has_item(&name) {
+            return Err(anyhow::anyhow!(get_local_text("files.file-already-with-name")).into());
+        }
+        warp_storage.set_path(trash_path());
+        warp_storage.rename(&entry.trash_name, &name).await?;
+    }
+    let restored = trash.remove_item(&name)?;
+    if let Err(e) = destination.add_item(restored.clone()) {
+        trash.add_item(restored)?;
+        return Err(e);
+    }
+    Ok(())
+}
+
+async fn restore_from_trash(
+    warp_storage: &mut warp_storage,
+    ids: Vec<Uuid>,
+) -> Result<uplink_storage, Error> {
+    let base_path = warp_storage.get_path().to_path_buf();
+    let trash = trash_directory(warp_storage).await?;
+    for id in ids {
+        let entry = match TRASH_INDEX.lock().get(id).cloned() {
+            Some(entry) => entry,
+            None => continue,
         };
+        match restore_item(warp_storage, &trash, &entry).await {
+            Ok(_) => {
+                log::info!("{} restored to {}", entry.name, entry.original_path);
+                TRASH_INDEX.lock().remove(id);
+            }
+            Err(e) => log::error!("failed to restore {}: {e}", entry.name),
+        }
+        warp_storage.set_path(base_path.clone());
+    }
+    TRASH_INDEX.lock().save();
+    get_items_from_current_directory(warp_storage)
+}
+
+async fn delete_from_trash(
+    warp_storage: &mut warp_storage,
+    ids: Vec<Uuid>,
+) -> Result<uplink_storage, Error> {
+    if ids.is_empty() {
         return get_items_from_current_directory(warp_storage);
-    };
-    // Code keeps here just if item is a directory
-    let first_dir = warp_storage.current_directory()?;
-    let mut current_dirs_opened = get_directories_opened();
-    current_dirs_opened.push(first_dir.clone());
+    }
+    let base_path = warp_storage.get_path().to_path_buf();
+    let trash = trash_directory(warp_storage).await?;
+    for id in ids {
+        let entry = match TRASH_INDEX.lock().get(id).cloned() {
+            Some(entry) => entry,
+            None => continue,
+        };
+        // the entry is kept when the item couldn't be deleted, so it can be tried again. an item
+        // which isn't in the trash anymore has nothing left to delete
+        let res = match trash.get_item(&entry.trash_name) {
+            Ok(item) => remove_item_at(warp_storage, &trash_path(), item).await,
+            Err(e) => {
+                log::warn!("{} is not in the trash anymore: {e}", entry.name);
+                Ok(())
+            }
+        };
+        warp_storage.set_path(base_path.clone());
+        match res {
+            Ok(_) => {
+                log::info!("{} deleted permanently", entry.name);
+                TRASH_INDEX.lock().remove(id);
+            }
+            Err(e) => log::error!("failed to delete {} from the trash: {e}", entry.name),
+        }
+    }
+    TRASH_INDEX.lock().save();
+    prune_hashes(warp_storage);
+    get_items_from_current_directory(warp_storage)
+}
+
+// the path of a directory, searched from the root. uses forward slashes like `open_new_directory`
+fn find_directory_path(directory: &Directory, id: Uuid, path: PathBuf) -> Option<PathBuf> {
+    if directory.id() == id {
+        return Some(path);
+    }
+    directory
+        .get_items()
+        .iter()
+        .filter_map(|item| item.get_directory().ok())
+        .find_map(|dir| find_directory_path(&dir, id, join_storage_path(&path, &dir.name())))
+}
 
-    let mut dirs: Vec<Directory> = current_dirs_opened;
+fn join_storage_path(path: &Path, name: &str) -> PathBuf {
+    PathBuf::from(path.join(name).to_string_lossy().replace('\\', "/"))
+}
 
-    match warp_storage.select(&item.name()) {
-        Ok(_) => log::debug!("Selected new dir: {:?}.", item.name()),
-        Err(error) => {
-            log::error!("Error to select new dir: {:?}.", error);
-            return Err(error);
+fn is_same_or_inside(directory: &Directory, id: Uuid) -> bool {
+    find_directory_path(directory, id, PathBuf::new()).is_some()
+}
+
+// every item inside a directory, with the path of the directory holding it. parents come first
+fn walk_items(directory: &Directory, path: &Path) -> Vec<(PathBuf, Item)> {
+    let mut items = vec![];
+    for item in directory.get_items() {
+        let inner = item.get_directory().ok();
+        items.push((path.to_path_buf(), item));
+        if let Some(inner) = inner {
+            items.extend(walk_items(&inner, &join_storage_path(path, &inner.name())));
+        }
+    }
+    items
+}
+
+// removes an item from the directory at `path`, including everything inside it
+async fn remove_item_at(
+    warp_storage: &mut warp_storage,
+    path: &Path,
+    item: Item,
+) -> Result<(), Error> {
+    let mut items = vec![(path.to_path_buf(), item.clone())];
+    if let Ok(directory) = item.get_directory() {
+        items.extend(walk_items(
+            &directory,
+            &join_storage_path(path, &item.name()),
+        ));
+    }
+    // children go before the directories holding them
+    for (path, item) in items.into_iter().rev() {
+        warp_storage.set_path(path);
+        if item.is_directory() {
+            warp_storage
+                .current_directory()?
+                .remove_item(&item.name())?;
+        } else {
+            warp_storage.remove(&item.name(), false).await?;
+        }
+    }
+    Ok(())
+}
+
+// the name an item gets in the destination, or None if it should be skipped
+fn conflict_name(
+    destination: &Directory,
+    name: String,
+    resolution: ConflictResolution,
+) -> Option<String> {
+    if !destination.has_item(&name) {
+        return Some(name);
+    }
+    match resolution {
+        ConflictResolution::Skip => None,
+        ConflictResolution::Rename => {
+            let file = PathBuf::from(&name);
+            Some(rename_if_duplicate(destination.clone(), name, file))
         }
+        ConflictResolution::Replace => Some(name),
+    }
+}
+
+// like `conflict_name`, with the item being replaced. the new item gets a temporary name, and the
+// replaced item is only moved to the trash once the new one is in place
+fn resolve_name_conflict(
+    warp_storage: &warp_storage,
+    destination: &Directory,
+    item: &Item,
+    resolution: ConflictResolution,
+) -> Result<Option<(String, Option<Item>)>, Error> {
+    let name = item.name();
+    let existing = match destination.get_item(&name) {
+        Ok(existing) if resolution == ConflictResolution::Replace => Some(existing),
+        _ => None,
+    };
+    let new_name = match existing {
+        Some(_) => name.clone(),
+        None => match conflict_name(destination, name.clone(), resolution) {
+            Some(new_name) => new_name,
+            None => return Ok(None),
+        },
+    };
+    check_reserved_name_at(warp_storage, destination, &new_name)?;
+    let existing = match existing {
+        Some(existing) => existing,
+        None => return Ok(Some((new_name, None))),
+    };
+    let replaces_itself = match existing.get_directory() {
+        Ok(directory) => is_same_or_inside(&directory, item.id()),
+        Err(_) => existing.id() == item.id(),
     };
-    dirs.push(warp_storage.current_directory()?);
+    if replaces_itself {
+        return Err(anyhow::anyhow!(get_local_text("files.replace-itself")).into());
+    }
+    let temporary_name =
+        rename_if_duplicate(destination.clone(), name.clone(), PathBuf::from(&name));
+    Ok(Some((temporary_name, Some(existing))))
+}
 
-    while let Some(last_dir) = dirs.clone().last() {
-        if last_dir.id() == first_dir.id() {
-            set_new_directory_opened(dirs.as_mut(), last_dir.clone());
-            break;
-        };
+// moves the replaced item to the trash and gives its name to the item which replaces it. if that
+// fails, both are kept
+async fn replace_item(
+    warp_storage: &mut warp_storage,
+    destination_path: &Path,
+    replaced: Item,
+    temporary_name: &str,
+) -> Result<(), Error> {
+    let base_path = warp_storage.get_path().to_path_buf();
+    let name = replaced.name();
+    warp_storage.set_path(destination_path.to_path_buf());
+    let res = match trash_item(warp_storage, replaced).await {
+        Ok(_) => warp_storage.rename(temporary_name, &name).await,
+        Err(e) => Err(e),
+    };
+    warp_storage.set_path(base_path);
+    res
+}
 
-        let dir_items = last_dir.get_items();
+// the items that couldn't be moved or copied are returned with the reason, the others are still
+// moved or copied
+async fn move_items(
+    warp_storage: &mut warp_storage,
+    items: Vec<Item>,
+    destination: Directory,
+    resolution: ConflictResolution,
+) -> Result<(uplink_storage, Vec<(String, String)>), Error> {
+    let current_directory = warp_storage.current_directory()?;
+    if current_directory.id() == destination.id() {
+        return Ok((get_items_from_current_directory(warp_storage)?, vec![]));
+    }
+    let destination_path =
+        find_directory_path(&warp_storage.root_directory(), destination.id(), "/".into())
+            .ok_or(Error::DirectoryNotFound)?;
+
+    let mut failures = vec![];
+    for item in items {
+        let name = item.name();
+        if let Err(e) = move_item(
+            warp_storage,
+            &current_directory,
+            &destination,
+            &destination_path,
+            &item,
+            resolution,
+        )
+        .await
+        {
+            log::error!("failed to move {name}: {e}");
+            failures.push((name, e.to_string()));
+        }
+    }
+    Ok((get_items_from_current_directory(warp_storage)?, failures))
+}
 
-        let is_there_file_yet = last_dir
-            .get_items()
-            .iter()
-            .any(|f| f.item_type() == ItemType::FileItem);
+async fn move_item(
+    warp_storage: &mut warp_storage,
+    current_directory: &Directory,
+    destination: &Directory,
+    destination_path: &Path,
+    item: &Item,
+    resolution: ConflictResolution,
+) -> Result<(), Error> {
+    let name = item.name();
+    if let Ok(directory) = item.get_directory() {
+        if is_same_or_inside(&directory, destination.id()) {
+            return Err(anyhow::anyhow!(get_local_text("files.move-inside-itself")).into());
+        }
+    }
+    let (new_name, replaced) =
+        match resolve_name_conflict(warp_storage, destination, item, resolution)? {
+            Some(resolved) => resolved,
+            None => return Ok(()),
+        };
+    // the item keeps its content, only the directory holding it changes
+    if new_name != name {
+        if current_directory.has_item(&new_name) {
+            return Err(anyhow::anyhow!(get_local_text_with_args(
+                "files.name-taken",
+                vec![("name", new_name)],
+            ))
+            .into());
+        }
+        warp_storage.rename(&name, &new_name).await?;
+    }
+    let moved = current_directory.remove_item(&new_name)?;
+    if let Err(e) = destination.add_item(moved.clone()) {
+        current_directory.add_item(moved)?;
+        return Err(e);
+    }
+    match replaced {
+        Some(replaced) => replace_item(warp_storage, destination_path, replaced, &new_name).await,
+        None => Ok(()),
+    }
+}
 
-        let is_there_directory_yet = last_dir
-            .get_items()
-            .iter()
-            .any(|f| f.item_type() == ItemType::DirectoryItem);
-
-        // If there is a sub directory yet
-        // Select it and keep loop
-        if is_there_directory_yet {
-            for item in dir_items {
-                if item.is_directory() {
-                    match warp_storage.select(&item.name()) {
-                        Ok(_) => log::debug!("Selected new dir: {:?}.", item.name()),
-                        Err(error) => {
-                            log::error!("Error to select new dir: {:?}.", error);
-                            return Err(error);
+async fn copy_items(
+    warp_storage: &mut warp_storage,
+    items: Vec<Item>,
+    destination: Directory,
+    resolution: ConflictResolution,
+) -> Result<(uplink_storage, Vec<(String, String)>), Error> {
+    let base_path = warp_storage.get_path().to_path_buf();
+    let destination_path =
+        find_directory_path(&warp_storage.root_directory(), destination.id(), "/".into())
+            .ok_or(Error::DirectoryNotFound)?;
+    let temp_dir = TempDir::new()?;
+
+    let mut failures = vec![];
+    for item in items {
+        let name = item.name();
+        let res = match item.get_directory() {
+            Ok(directory) if is_same_or_inside(&directory, destination.id()) => {
+                Err(anyhow::anyhow!(get_local_text("files.copy-inside-itself")).into())
+            }
+            _ => match resolve_name_conflict(warp_storage, &destination, &item, resolution) {
+                Ok(Some((new_name, replaced))) => {
+                    let res = copy_item(
+                        warp_storage,
+                        &item,
+                        &base_path,
+                        &destination_path,
+                        &new_name,
+                        temp_dir.path(),
+                    )
+                    .await;
+                    match (res, replaced) {
+                        (Ok(_), Some(replaced)) => {
+                            replace_item(warp_storage, &destination_path, replaced, &new_name).await
                         }
-                    };
-                    dirs.push(warp_storage.current_directory()?);
-                    break;
+                        (res, _) => res,
+                    }
                 }
-            }
-            continue;
+                Ok(None) => Ok(()),
+                Err(e) => Err(e),
+            },
         };
+        if let Err(e) = res {
+            log::error!("failed to copy {name}: {e}");
+            failures.push((name, e.to_string()));
+        }
+        warp_storage.set_path(base_path.clone());
+    }
+    Ok((get_items_from_current_directory(warp_storage)?, failures))
+}
 
-        // No more files, it pop current dir on dirs variable
-        // And remove current dir.
-        //
-        // After it, back to previous dir and keep loop verifying other files and sub dirs.
-        if !is_there_file_yet {
-            dirs.pop();
-            if let Some(previous_dir) = dirs.last() {
-                previous_dir.remove_item(&last_dir.name())?;
-                log::info!("Directory {:?} was removed.", &last_dir.name());
-            };
+// directories are recreated and files are downloaded and uploaded again,
+// so the copy doesn't share anything with the original
+async fn copy_item(
+    warp_storage: &mut warp_storage,
+    item: &Item,
+    source_path: &Path,
+    destination_path: &Path,
+    name: &str,
+    temp_dir: &Path,
+) -> Result<(), Error> {
+    let mut items = vec![(
+        source_path.to_path_buf(),
+        destination_path.to_path_buf(),
+        name.to_string(),
+        item.clone(),
+    )];
+    if let Ok(directory) = item.get_directory() {
+        let source_root = join_storage_path(source_path, &item.name());
+        let destination_root = join_storage_path(destination_path, name);
+        for (path, inner) in walk_items(&directory, &source_root) {
+            let relative = path.strip_prefix(&source_root).unwrap_or(Path::new(""));
+            let destination = join_storage_path(&destination_root, &relative.to_string_lossy());
+            items.push((path, destination, inner.name(), inner));
+        }
+    }
 
-            match warp_storage.go_back() {
-                Ok(_) => {
-                    log::debug!(
-                        "Selected new dir: {:?}",
-                        warp_storage.current_directory()?.name()
-                    );
-                }
-                Err(error) => {
-                    log::error!("Error on go back a directory: {error}");
-                    return Err(error);
-                }
-            };
+    for (source, destination, name, item) in items {
+        warp_storage.set_path(destination.clone());
+        if item.is_directory() {
+            warp_storage.create_directory(&name, true).await?;
             continue;
         }
+        let file = item.get_file()?;
+        if warp_storage.current_size() + file.size() > warp_storage.max_size() {
+            return Err(anyhow::anyhow!(get_local_text("files.not-enough-space")).into());
+        }
 
-        // If code arrives here, just run into files inside that dir and delete all of them.
-        for file in last_dir.get_items().iter().filter(|f| f.is_file()) {
-            match warp_storage.remove(&file.name(), false).await {
-                Ok(_) => log::info!(
-                    "File deleted: {:?}, on directory: {:?}.",
-                    file.name(),
-                    warp_storage.current_directory()?.name()
-                ),
-                Err(error) => {
-                    log::error!("Error to delete this file: {:?}, {:?}", file.name(), error)
-                }
-            };
+        warp_storage.set_path(source);
+        let local_path = temp_dir.join(file.id().to_string());
+        let mut local_file = std::fs::File::create(&local_path)?;
+        let mut stream = warp_storage.get_stream(&file.name()).await?;
+        while let Some(data) = stream.next().await {
+            local_file.write_all(&data?)?;
+        }
+
+        warp_storage.set_path(destination);
+        let mut upload_progress = warp_storage
+            .put(&name, &local_path.to_string_lossy())
+            .await?;
+        while let Some(progress) = upload_progress.next().await {
+            if let Progression::ProgressFailed { error, .. } = progress {
+                return Err(anyhow::anyhow!(error.unwrap_or_default()).into());
+            }
+        }
+        let _ = std::fs::remove_file(&local_path);
+
+        let copy = warp_storage.current_directory()?.get_item(&name)?;
+        HASH_INDEX.lock().copy(file.id(), copy.id());
+        let thumbnail = file.thumbnail();
+        if !thumbnail.is_empty() {
+            copy.set_thumbnail(&thumbnail);
+            copy.set_thumbnail_format(file.thumbnail_format());
         }
     }
-    get_items_from_current_directory(warp_storage)
+    Ok(())
 }
 
 async fn rename_item(
@@ -245,6 +922,7 @@ async fn rename_item(
     new_name: String,
     warp_storage: &mut warp_storage,
 ) -> Result<uplink_storage, Error> {
+    check_reserved_name(&new_name)?;
     if let Err(error) = warp_storage.rename(&old_name, &new_name).await {
         log::error!("Failed to rename item: {error}");
     }
@@ -256,6 +934,7 @@ async fn create_new_directory(
     folder_name: &str,
     warp_storage: &mut warp_storage,
 ) -> Result<(), Error> {
+    check_reserved_name(folder_name)?;
     warp_storage.create_directory(folder_name, true).await?;
     log::debug!("New directory created: {:?}", folder_name);
     Ok(())
@@ -268,7 +947,13 @@ fn get_items_from_current_directory(
     let mut current_dirs = get_directories_opened();
     set_new_directory_opened(current_dirs.as_mut(), current_dir.clone());
 
-    let items = current_dir.get_items();
+    // the trash has its own view
+    let is_root = current_dir.id() == warp_storage.root_directory().id();
+    let items: Vec<Item> = current_dir
+        .get_items()
+        .into_iter()
+        .filter(|item| !(is_root && item.name() == TRASH_DIR_NAME))
+        .collect();
 
     let mut directories = items
         .iter()
@@ -372,6 +1057,7 @@ async fn upload_files(warp_storage: &mut warp_storage, files_path: Vec<PathBuf>)
     };
 
     let max_size_ipfs = warp_storage.max_size();
+    let stored_files = storage_files(warp_storage);
     let (tx, rx) = mpsc::channel();
 
     for file_path in files_path.clone() {
@@ -421,24 +1107,45 @@ async fn upload_files(warp_storage: &mut warp_storage, files_path: Vec<PathBuf>)
             filename.clone(),
         )));
         filename = rename_if_duplicate(current_directory.clone(), filename.clone(), file);
+        if let Err(e) = check_reserved_name_at(warp_storage, &current_directory, &filename) {
+            log::error!("Not possible to upload {filename}: {e}");
+            let _ = tx_upload_file.send(UploadFileAction::Error);
+            continue;
+        }
+        let hash = hash_upload(file_path.clone(), stored_files.clone(), original);
+        let transfer = Transfer::upload(
+            filename.clone(),
+            file_path.clone(),
+            storage_path(warp_storage.get_path()),
+            file_size,
+        );
+        let transfer_id = transfer.id;
+        let control = TRANSFERS.start(transfer);
 
         match warp_storage.put(&filename, &local_path).await {
             Ok(upload_progress) => {
+                record_transfer_item(&current_directory, &filename, transfer_id);
                 // Handle each upload on another thread
-                let mut warp_storage = warp_storage.clone();
+                let directory = current_directory.clone();
                 let res = tx.clone();
                 tokio::spawn(async move {
                     handle_upload_progress(
-                        &mut warp_storage,
+                        directory,
                         upload_progress,
                         filename,
                         file_path.clone(),
+                        transfer_id,
+                        control,
+                        hash,
                     )
                     .await;
                     let _ = res.send(file_path);
                 });
             }
-            Err(error) => log::error!("Error when upload file: {:?}", error),
+            Err(error) => {
+                log::error!("Error when upload file: {:?}", error);
+                TRANSFERS.finish(transfer_id, TransferStatus::Failed(error.to_string()));
+            }
         }
     }
     let mut warp_storage = warp_storage.clone();
@@ -451,6 +1158,7 @@ async fn upload_files(warp_storage: &mut warp_storage, files_path: Vec<PathBuf>)
                 break;
             }
         }
+        HASH_INDEX.lock().save();
         let ret = match get_items_from_current_directory(&mut warp_storage) {
             Ok(r) => UploadFileAction::Finished(r),
             Err(_) => UploadFileAction::Error,
@@ -460,36 +1168,338 @@ async fn upload_files(warp_storage: &mut warp_storage, files_path: Vec<PathBuf>)
     });
 }
 
-async fn handle_upload_progress(
-    warp_storage: &mut warp_storage,
-    mut upload_progress: ConstellationProgressStream,
-    filename: String,
-    file_path: PathBuf,
-) {
-    let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
-    let mut previous_percentage: usize = 0;
-    let mut upload_process_started = false;
+// shared by the tasks uploading the files of a folder, so the progress bar shows the whole folder
+struct FolderUploadProgress {
+    path: PathBuf,
+    name: String,
+    total_size: usize,
+    file_count: usize,
+    uploaded: Mutex<HashMap<usize, usize>>,
+    completed: AtomicUsize,
+    // the upload itself holds one, so the folder isn't finished before every file was started
+    remaining: AtomicUsize,
+    cancelled: AtomicBool,
+    failures: Mutex<Vec<(String, String)>>,
+}
 
-    while let Some(upload_progress) = upload_progress.next().await {
-        match upload_progress {
-            Progression::CurrentProgress {
-                name,
-                current,
-                total,
-            } => {
-                log::trace!("starting upload file action listener");
-                if let Ok(received_tx) = CANCEL_FILE_UPLOADLISTENER
-                    .rx
-                    .clone()
-                    .lock()
-                    .await
-                    .try_recv()
-                {
+impl FolderUploadProgress {
+    // returns the percentage of the whole folder
+    fn set_uploaded(&self, idx: usize, bytes: usize) -> usize {
+        let mut uploaded = self.uploaded.lock();
+        uploaded.insert(idx, bytes);
+        if self.total_size == 0 {
+            return 100;
+        }
+        (uploaded.values().sum::<usize>() * 100 / self.total_size).min(100)
+    }
+
+    fn description(&self) -> String {
+        get_local_text_with_args(
+            "files.uploading-folder",
+            vec![
+                ("done", self.completed.load(Ordering::Relaxed).to_string()),
+                ("total", self.file_count.to_string()),
+            ],
+        )
+    }
+
+    fn fail(&self, file: String, error: String) {
+        log::error!("failed to upload {file}: {error}");
+        self.failures.lock().push((file, error));
+    }
+
+    // called once per file and once by the upload itself
+    fn done(&self) {
+        if self.remaining.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return;
+        }
+        let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
+        if self.cancelled.load(Ordering::Relaxed) {
+            let _ = tx_upload_file.send(UploadFileAction::Cancelling);
+            return;
+        }
+        let failures = std::mem::take(&mut *self.failures.lock());
+        if !failures.is_empty() {
+            let _ = tx_upload_file.send(UploadFileAction::UploadFailures(failures));
+        }
+        let _ = tx_upload_file.send(UploadFileAction::Finishing(self.path.clone(), true));
+    }
+}
+
+async fn upload_folder(warp_storage: &mut warp_storage, folder: FolderUpload) {
+    let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
+
+    let current_directory = match warp_storage.current_directory() {
+        Ok(d) => d,
+        Err(_) => {
+            let _ = tx_upload_file.send(UploadFileAction::Error);
+            return;
+        }
+    };
+    let base_path = warp_storage.get_path().to_path_buf();
+    let max_size_ipfs = warp_storage.max_size();
+    let stored_files = storage_files(warp_storage);
+
+    let folder_name = folder.name();
+    let total_size: usize = folder
+        .files
+        .iter()
+        .map(|(path, _)| {
+            std::fs::metadata(path)
+                .map(|m| m.len() as usize)
+                .unwrap_or_default()
+        })
+        .sum();
+    // the whole folder has to fit, otherwise it would only be uploaded in part
+    if warp_storage.current_size() + total_size > max_size_ipfs {
+        log::error!(
+            "Not available space for the folder {}",
+            folder.path.display()
+        );
+        let _ = tx_upload_file.send(UploadFileAction::SizeNotAvailable(folder_name));
+        return;
+    }
+    let _ = tx_upload_file.send(UploadFileAction::Starting(folder_name.clone()));
+    let root = rename_if_duplicate(
+        current_directory,
+        folder_name.clone(),
+        PathBuf::from(&folder_name),
+    );
+    let progress = Arc::new(FolderUploadProgress {
+        path: folder.path.clone(),
+        name: root.clone(),
+        total_size,
+        file_count: folder.files.len(),
+        uploaded: Default::default(),
+        completed: AtomicUsize::new(0),
+        remaining: AtomicUsize::new(1),
+        cancelled: AtomicBool::new(false),
+        failures: Default::default(),
+    });
+
+    let files = match create_new_directory(&root, warp_storage).await {
+        Ok(_) => {
+            for directory in &folder.directories {
+                let remote = remote_path(&root, directory);
+                if let Err(e) = create_new_directory(&remote, warp_storage).await {
+                    progress.fail(remote, e.to_string());
+                }
+            }
+            folder.files
+        }
+        Err(e) => {
+            progress.fail(root.clone(), e.to_string());
+            vec![]
+        }
+    };
+
+    let (tx, rx) = mpsc::channel();
+    for (idx, (file_path, directory)) in files.into_iter().enumerate() {
+        let filename = match file_path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let remote_dir = remote_path(&root, &directory);
+        let remote_file = format!("{remote_dir}/{filename}");
+
+        let file_size = std::fs::metadata(&file_path)
+            .map(|m| m.len() as usize)
+            .unwrap_or_default();
+        if warp_storage.current_size() + file_size > max_size_ipfs {
+            progress.fail(remote_file, get_local_text("files.not-enough-space"));
+            continue;
+        }
+
+        let hash = hash_upload(file_path.clone(), stored_files.clone(), remote_file.clone());
+
+        // files are put into the current directory, so it is switched to the right sub folder for a moment
+        warp_storage.set_path(join_storage_path(&base_path, &remote_dir));
+        let transfer = Transfer::upload(
+            filename.clone(),
+            file_path.clone(),
+            storage_path(warp_storage.get_path()),
+            file_size,
+        );
+        let transfer_id = transfer.id;
+        let control = TRANSFERS.start(transfer);
+        let put = match warp_storage.current_directory() {
+            Ok(directory) => warp_storage
+                .put(&filename, &file_path.to_string_lossy())
+                .await
+                .map(|stream| (directory, stream)),
+            Err(e) => Err(e),
+        };
+        warp_storage.set_path(base_path.clone());
+
+        match put {
+            Ok((directory, upload_progress)) => {
+                record_transfer_item(&directory, &filename, transfer_id);
+                progress.remaining.fetch_add(1, Ordering::AcqRel);
+                let progress = progress.clone();
+                let res = tx.clone();
+                tokio::spawn(async move {
+                    handle_folder_file_upload(
+                        directory,
+                        upload_progress,
+                        file_path.clone(),
+                        idx,
+                        remote_file,
+                        &progress,
+                        (transfer_id, control, hash),
+                    )
+                    .await;
+                    progress.done();
+                    let _ = res.send(file_path);
+                });
+            }
+            Err(e) => {
+                TRANSFERS.finish(transfer_id, TransferStatus::Failed(e.to_string()));
+                progress.fail(remote_file, e.to_string());
+            }
+        }
+    }
+    progress.done();
+
+    let mut warp_storage = warp_storage.clone();
+    tokio::spawn(async move {
+        while rx.recv().is_ok() {}
+        HASH_INDEX.lock().save();
+        let ret = match get_items_from_current_directory(&mut warp_storage) {
+            Ok(r) => UploadFileAction::Finished(r),
+            Err(_) => UploadFileAction::Error,
+        };
+        let _ = tx_upload_file.send(ret);
+    });
+}
+
+async fn handle_folder_file_upload(
+    directory: Directory,
+    mut upload_progress: ConstellationProgressStream,
+    file_path: PathBuf,
+    idx: usize,
+    remote_file: String,
+    progress: &FolderUploadProgress,
+    (transfer, control, hash): (Uuid, Arc<TransferControl>, JoinHandle<Option<String>>),
+) {
+    let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
+    let mut previous_percentage = None;
+    loop {
+        if !control.proceed().await {
+            TRANSFERS.finish(transfer, TransferStatus::Cancelled);
+            return;
+        }
+        let upload_progress = match upload_progress.next().await {
+            Some(upload_progress) => upload_progress,
+            None => break,
+        };
+        let uploaded = match upload_progress {
+            Progression::CurrentProgress { current, .. } => current,
+            Progression::ProgressComplete { total, .. } => total.unwrap_or_default(),
+            Progression::ProgressFailed { error, .. } => {
+                let error = error.unwrap_or_default();
+                TRANSFERS.finish(transfer, TransferStatus::Failed(error.clone()));
+                progress.fail(remote_file, error);
+                return;
+            }
+        };
+        TRANSFERS.progress(transfer, uploaded);
+        if let Ok(true) = CANCEL_FILE_UPLOADLISTENER
+            .rx
+            .clone()
+            .lock()
+            .await
+            .try_recv()
+        {
+            progress.cancelled.store(true, Ordering::Relaxed);
+        }
+        if progress.cancelled.load(Ordering::Relaxed) {
+            TRANSFERS.cancel(transfer);
+            TRANSFERS.finish(transfer, TransferStatus::Cancelled);
+            return;
+        }
+        let percentage = progress.set_uploaded(idx, uploaded);
+        if previous_percentage != Some(percentage) {
+            previous_percentage = Some(percentage);
+            let _ = tx_upload_file.send(UploadFileAction::Uploading((
+                format!("{percentage}%"),
+                progress.description(),
+                progress.name.clone(),
+            )));
+        }
+    }
+    progress.completed.fetch_add(1, Ordering::Relaxed);
+    TRANSFERS.finish(transfer, TransferStatus::Completed);
+
+    let filename = file_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    record_hash(&directory, &filename, hash.await.ok().flatten());
+    let file_extension = Path::new(&filename)
+        .extension()
+        .and_then(OsStr::to_str)
+        .map(|s| format!(".{s}"))
+        .unwrap_or_default();
+    let res = if VIDEO_FILE_EXTENSIONS.contains(&file_extension.as_str()) {
+        set_thumbnail_if_file_is_video(directory, filename, file_path).await
+    } else if DOC_EXTENSIONS.contains(&file_extension.as_str()) {
+        set_thumbnail_if_file_is_document(directory, filename, file_path).await
+    } else {
+        Ok(())
+    };
+    if let Err(e) = res {
+        log::error!("Not possible to update thumbnail for {remote_file}: {e}");
+    }
+}
+
+// the upload only advances while its progress is polled, which is how it gets paused
+// the name was free when the upload started, so an item with it is the one being uploaded. it is recorded as
+// soon as the upload starts, so that an upload interrupted by quitting still knows the file it left
+fn record_transfer_item(directory: &Directory, filename: &str, transfer: Uuid) {
+    if let Ok(item) = directory.get_item(filename) {
+        TRANSFERS.set_item(transfer, item.id());
+    }
+}
+
+async fn handle_upload_progress(
+    directory: Directory,
+    mut upload_progress: ConstellationProgressStream,
+    filename: String,
+    file_path: PathBuf,
+    transfer: Uuid,
+    control: Arc<TransferControl>,
+    hash: JoinHandle<Option<String>>,
+) {
+    let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
+    let mut previous_percentage: usize = 0;
+    let mut upload_process_started = false;
+    let mut failure = None;
+
+    while control.proceed().await {
+        let progress = match upload_progress.next().await {
+            Some(progress) => progress,
+            None => break,
+        };
+        match progress {
+            Progression::CurrentProgress {
+                name,
+                current,
+                total,
+            } => {
+                log::trace!("starting upload file action listener");
+                if let Ok(received_tx) = CANCEL_FILE_UPLOADLISTENER
+                    .rx
+                    .clone()
+                    .lock()
+                    .await
+                    .try_recv()
+                {
                     if received_tx {
-                        let _ = tx_upload_file.send(UploadFileAction::Cancelling);
+                        TRANSFERS.cancel(transfer);
                         break;
                     }
                 }
+                TRANSFERS.progress(transfer, current);
                 if !upload_process_started {
                     upload_process_started = true;
                     log::info!("Starting upload for {name}");
@@ -541,10 +1551,26 @@ async fn handle_upload_progress(
                     error.unwrap_or_default()
                 );
                 let _ = tx_upload_file.send(UploadFileAction::Error);
+                failure = Some(error.unwrap_or_default());
                 break;
             }
         }
     }
+    if control.is_cancelled() {
+        let _ = tx_upload_file.send(UploadFileAction::Cancelling);
+    }
+    let status = match failure {
+        _ if control.is_cancelled() => TransferStatus::Cancelled,
+        Some(error) => {
+            record_transfer_item(&directory, &filename, transfer);
+            TransferStatus::Failed(error)
+        }
+        None => {
+            record_hash(&directory, &filename, hash.await.ok().flatten());
+            TransferStatus::Completed
+        }
+    };
+    TRANSFERS.finish(transfer, status);
 
     let _ = tx_upload_file.send(UploadFileAction::Uploading((
         "100%".into(),
@@ -562,7 +1588,7 @@ async fn handle_upload_progress(
         .unwrap_or_default();
 
     if video_formats.iter().any(|f| f == &file_extension) {
-        match set_thumbnail_if_file_is_video(warp_storage, filename.clone(), file_path.clone())
+        match set_thumbnail_if_file_is_video(directory.clone(), filename.clone(), file_path.clone())
             .await
         {
             Ok(_) => {
@@ -580,8 +1606,12 @@ async fn handle_upload_progress(
     }
 
     if doc_formats.iter().any(|f| f == &file_extension) {
-        match set_thumbnail_if_file_is_document(warp_storage, filename.clone(), file_path.clone())
-            .await
+        match set_thumbnail_if_file_is_document(
+            directory.clone(),
+            filename.clone(),
+            file_path.clone(),
+        )
+        .await
         {
             Ok(_) => {
                 log::info!("Document Thumbnail uploaded");
@@ -600,6 +1630,86 @@ async fn handle_upload_progress(
     log::info!("{:?} file uploaded!", filename);
 }
 
+// hashing a large file takes a while, so it is done on a blocking thread
+async fn hash_local_file(path: PathBuf) -> Option<String> {
+    match tokio::task::spawn_blocking(move || hash_file(&path)).await {
+        Ok(Ok(hash)) => Some(hash),
+        res => {
+            log::warn!("failed to hash file: {res:?}");
+            None
+        }
+    }
+}
+
+// hashes a file next to its upload instead of holding up the warp runner, and tells the user once
+// it turns out that the file is already in storage
+fn hash_upload(
+    path: PathBuf,
+    stored_files: Vec<StoredFile>,
+    name: String,
+) -> JoinHandle<Option<String>> {
+    tokio::spawn(async move {
+        let hash = hash_local_file(path).await?;
+        let existing = HASH_INDEX
+            .lock()
+            .find(&hash, &stored_files)
+            .map(|file| file.path.clone());
+        if let Some(existing) = existing {
+            let _ = UPLOAD_FILE_LISTENER
+                .tx
+                .send(UploadFileAction::Duplicate(name, existing));
+        }
+        Some(hash)
+    })
+}
+
+// remembers the content of an uploaded file, so that it is recognized when it is uploaded again
+fn record_hash(directory: &Directory, filename: &str, hash: Option<String>) {
+    if let (Some(hash), Ok(item)) = (hash, directory.get_item(filename)) {
+        HASH_INDEX.lock().insert(item.id(), hash);
+    }
+}
+
+// hashes are kept for files in the trash, so that they are still known when restored. files
+// which were deleted for good are forgotten
+fn prune_hashes(warp_storage: &warp_storage) {
+    let existing = walk_items(&warp_storage.root_directory(), Path::new("/"))
+        .into_iter()
+        .map(|(_, item)| item.id())
+        .collect::<HashSet<_>>();
+    let mut index = HASH_INDEX.lock();
+    index.retain(&existing);
+    index.save();
+}
+
+// every file in storage outside of the trash, oldest first
+fn storage_files(warp_storage: &warp_storage) -> Vec<StoredFile> {
+    let trash = trash_path();
+    let mut files: Vec<_> = walk_items(&warp_storage.root_directory(), Path::new("/"))
+        .into_iter()
+        .filter(|(path, item)| !item.is_directory() && !path.starts_with(&trash))
+        .collect();
+    files.sort_by_key(|(_, item)| item.creation());
+    files
+        .into_iter()
+        .map(|(path, item)| StoredFile {
+            id: item.id(),
+            path: join_storage_path(&path, &item.name())
+                .to_string_lossy()
+                .to_string(),
+            size: item.size(),
+        })
+        .collect()
+}
+
+fn find_in_storage(warp_storage: &warp_storage, hash: &str) -> Option<String> {
+    let files = storage_files(warp_storage);
+    HASH_INDEX
+        .lock()
+        .find(hash, &files)
+        .map(|file| file.path.clone())
+}
+
 fn rename_if_duplicate(
     current_directory: Directory,
     filename: String,
@@ -635,58 +1745,25 @@ fn rename_if_duplicate(
 }
 
 async fn set_thumbnail_if_file_is_video(
-    warp_storage: &warp_storage,
+    directory: Directory,
     filename_to_save: String,
     file_path: PathBuf,
 ) -> Result<(), Error> {
-    let warp_storage = warp_storage.clone();
     tokio::task::spawn_blocking(move || {
-        let item = warp_storage
-            .current_directory()?
-            .get_item(&filename_to_save)?;
-
-        let file_stem = file_path
-            .file_stem()
-            .and_then(OsStr::to_str)
-            .map(str::to_string)
-            .unwrap_or_default();
-
-        let temp_dir = TempDir::new()?;
-
-        let temp_path = temp_dir.path().join(file_stem);
-
-        let output = Command::new("ffmpeg")
-            .args([
-                "-i",
-                &file_path.to_string_lossy(),
-                "-vf",
-                "select=eq(pict_type\\,I)",
-                "-q:v",
-                "2",
-                "-f",
-                "image2",
-                "-update",
-                "1",
-                &temp_path.to_string_lossy(),
-            ])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::null())
-            .spawn()?;
-
-        if let Some(mut child) = output.stdout {
-            let mut contents = vec![];
-            child.read_to_end(&mut contents)?;
-
-            let image = std::fs::read(temp_path)?;
-
-            item.set_thumbnail(&image);
-            item.set_thumbnail_format(FormatType::Mime(
-                "image/jpeg".parse().expect("Correct mime type"),
-            ));
-            Ok(())
-        } else {
-            log::warn!("Failed to save thumbnail from a video file");
-            Err(Error::InvalidConversion)
+        let item = directory.get_item(&filename_to_save)?;
+
+        match video_thumbnail(&file_path) {
+            Ok(image) => {
+                item.set_thumbnail(&image);
+                item.set_thumbnail_format(FormatType::Mime(
+                    "image/jpeg".parse().expect("Correct mime type"),
+                ));
+                Ok(())
+            }
+            Err(e) => {
+                log::warn!("Failed to save thumbnail from a video file: {e}");
+                Err(Error::InvalidConversion)
+            }
         }
     })
     .await
@@ -694,15 +1771,12 @@ async fn set_thumbnail_if_file_is_video(
 }
 
 async fn set_thumbnail_if_file_is_document(
-    warp_storage: &warp_storage,
+    directory: Directory,
     filename_to_save: String,
     file_path: PathBuf,
 ) -> Result<(), Error> {
-    let warp_storage = warp_storage.clone();
     tokio::task::spawn_blocking(move || {
-        let item = warp_storage
-            .current_directory()?
-            .get_item(&filename_to_save)?;
+        let item = directory.get_item(&filename_to_save)?;
 
         let file_stem = file_path
             .file_stem()
@@ -755,22 +1829,38 @@ async fn download_file(
     warp_storage: &warp_storage,
     file_name: String,
     local_path_to_save_file: PathBuf,
+    transfer: Uuid,
+    resume: bool,
 ) -> Result<ConstellationProgressStream, Error> {
     let size = warp_storage
         .current_directory()?
         .get_item_by_path(&file_name)
         .map(|d| d.size())
         .unwrap_or_default();
+    TRANSFERS.set_source(transfer, storage_path(warp_storage.get_path()), size);
     let stream = warp_storage.get_stream(&file_name).await?;
     let path = local_path_to_save_file.clone();
-    let mut file = std::fs::File::create(local_path_to_save_file)
-        .expect(&format!("Couldn't create file {:?}", path.as_os_str()));
+    // a resumed download keeps what was written before. the stream starts from the beginning again, so that part is skipped
+    let mut offset = match resume {
+        true => std::fs::metadata(&path)
+            .map(|m| m.len() as usize)
+            .unwrap_or_default(),
+        false => 0,
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resume)
+        .truncate(!resume)
+        .open(local_path_to_save_file)?;
     let name = file_name.clone();
     let name2 = file_name.clone();
     let stream = stream
         .map(move |v| match v {
             Ok(data) => {
-                let _ = file.write(&data);
+                let skip = offset.min(data.len());
+                offset -= skip;
+                let _ = file.write_all(&data[skip..]);
                 Progression::CurrentProgress {
                     name: file_name.clone(),
                     current: file
@@ -796,6 +1886,514 @@ async fn download_file(
     Ok(stream.boxed())
 }
 
+// the items of the current directory and everything inside them, with the directory holding each one
+// and its path inside the zip. items selected with the same name get a numbered name, and the
+// entries inside a renamed folder follow it
+fn zip_entries(base_path: &Path, items: &[Item]) -> Vec<(PathBuf, String, Item)> {
+    let mut entries = vec![];
+    let mut used = HashSet::new();
+    for item in items {
+        let zip_name = unique_zip_name(&mut used, &item.name(), item.is_directory());
+        entries.push((base_path.to_path_buf(), zip_name.clone(), item.clone()));
+        if let Ok(directory) = item.get_directory() {
+            let path = join_storage_path(base_path, &item.name());
+            for (parent, inner) in walk_items(&directory, &path) {
+                let inside = parent.strip_prefix(&path).unwrap_or(&parent);
+                let zip_path = join_storage_path(&Path::new(&zip_name).join(inside), &inner.name());
+                entries.push((parent, storage_path(&zip_path), inner));
+            }
+        }
+    }
+    entries
+}
+
+// `name`, or `name (n).ext` when the name was already used
+fn unique_zip_name(used: &mut HashSet<String>, name: &str, is_directory: bool) -> String {
+    let path = Path::new(name);
+    let (stem, extension) = match (is_directory, path.file_stem(), path.extension()) {
+        (false, Some(stem), Some(extension)) => (
+            stem.to_string_lossy().to_string(),
+            format!(".{}", extension.to_string_lossy()),
+        ),
+        _ => (name.to_string(), String::new()),
+    };
+    let mut candidate = name.to_string();
+    let mut count = 1;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{stem} ({count}){extension}");
+        count += 1;
+    }
+    candidate
+}
+
+// like `download_file`, the bytes are written while the stream is read. the progress is the size of
+// everything downloaded so far
+async fn download_items_as_zip(
+    warp_storage: &mut warp_storage,
+    items: Vec<Item>,
+    local_path: PathBuf,
+) -> Result<ConstellationProgressStream, Error> {
+    let base_path = warp_storage.get_path().to_path_buf();
+    let mut entries = vec![];
+    for (directory, zip_path, item) in zip_entries(&base_path, &items) {
+        if item.is_directory() {
+            entries.push((zip_path, None));
+            continue;
+        }
+        warp_storage.set_path(directory);
+        match warp_storage.get_stream(&item.name()).await {
+            Ok(data) => entries.push((zip_path, Some(data))),
+            Err(e) => {
+                warp_storage.set_path(base_path);
+                return Err(e);
+            }
+        }
+    }
+    warp_storage.set_path(base_path);
+
+    let name = local_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name2 = name.clone();
+    let zip = Arc::new(Mutex::new(zip::ZipWriter::new(std::fs::File::create(
+        &local_path,
+    )?)));
+    let zip2 = zip.clone();
+    let options = zip_options(zip::CompressionMethod::Deflated);
+    let written = Arc::new(AtomicUsize::new(0));
+    // once an entry fails the archive is incomplete, so the rest are skipped and the file removed
+    let any_failed = Arc::new(AtomicBool::new(false));
+    let any_failed2 = any_failed.clone();
+    let failed = move |name: &str, error: String| Progression::ProgressFailed {
+        name: name.to_string(),
+        last_size: None,
+        error: Some(error),
+    };
+
+    let stream = stream::iter(entries)
+        .flat_map(move |(zip_path, data)| {
+            if any_failed.load(Ordering::Relaxed) {
+                return stream::empty().boxed();
+            }
+            let started = match data {
+                Some(_) => zip.lock().start_file(zip_path.as_str(), options),
+                None => zip.lock().add_directory(zip_path.as_str(), options),
+            };
+            let (data, name) = match (started, data) {
+                (Err(e), _) => {
+                    any_failed.store(true, Ordering::Relaxed);
+                    return stream::iter([failed(&name, e.to_string())]).boxed();
+                }
+                (Ok(()), None) => return stream::empty().boxed(),
+                (Ok(()), Some(data)) => (data, name.clone()),
+            };
+            let zip = zip.clone();
+            let written = written.clone();
+            let any_failed = any_failed.clone();
+            let stop = any_failed.clone();
+            data.take_while(move |_| futures::future::ready(!stop.load(Ordering::Relaxed)))
+                .map(move |chunk| {
+                    let chunk = chunk.and_then(|chunk| {
+                        zip.lock().write_all(&chunk)?;
+                        Ok(chunk.len())
+                    });
+                    match chunk {
+                        Ok(len) => Progression::CurrentProgress {
+                            name: name.clone(),
+                            current: written.fetch_add(len, Ordering::Relaxed) + len,
+                            total: None,
+                        },
+                        Err(e) => {
+                            any_failed.store(true, Ordering::Relaxed);
+                            failed(&name, e.to_string())
+                        }
+                    }
+                })
+                .boxed()
+        })
+        .chain(stream::once(async move {
+            // finishing closes the file even when it is removed afterwards
+            let finished = zip2.lock().finish();
+            match finished {
+                Ok(file) if !any_failed2.load(Ordering::Relaxed) => Progression::ProgressComplete {
+                    name: name2.clone(),
+                    total: file.metadata().map(|m| m.len() as usize).ok(),
+                },
+                Ok(file) => {
+                    drop(file);
+                    remove_partial_zip(&local_path);
+                    failed(&name2, get_local_text("files.zip-incomplete"))
+                }
+                Err(e) => {
+                    remove_partial_zip(&local_path);
+                    failed(&name2, e.to_string())
+                }
+            }
+        }));
+    Ok(stream.boxed())
+}
+
+fn remove_partial_zip(path: &Path) {
+    if let Err(e) = std::fs::remove_file(path) {
+        log::error!("failed to remove the incomplete zip at {path:?}: {e}");
+    }
+}
+
+// the directory of the storage path, with forward slashes
+fn storage_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+async fn resume_transfer(
+    warp_storage: &mut warp_storage,
+    id: Uuid,
+) -> Result<Option<ConstellationProgressStream>, Error> {
+    let transfer = match TRANSFERS.get(id).filter(|t| t.is_resumable()) {
+        Some(transfer) => transfer,
+        None => return Err(Error::InvalidItem),
+    };
+    let base_path = warp_storage.get_path().to_path_buf();
+    warp_storage.set_path(PathBuf::from(
+        transfer.remote_dir.clone().unwrap_or_default(),
+    ));
+    let res = match transfer.kind {
+        TransferKind::Download => {
+            TRANSFERS.restart(id);
+            let partial_path = transfer
+                .partial_path
+                .clone()
+                .unwrap_or_else(|| transfer.local_path.clone());
+            download_file(warp_storage, transfer.name, partial_path, id, true)
+                .await
+                .map(Some)
+        }
+        TransferKind::Upload => resume_upload(warp_storage, transfer).await.map(|_| None),
+    };
+    warp_storage.set_path(base_path);
+    if let Err(e) = &res {
+        TRANSFERS.finish(id, TransferStatus::Failed(e.to_string()));
+    }
+    res
+}
+
+// warp can't continue an upload, so the file is uploaded again
+async fn resume_upload(
+    warp_storage: &mut warp_storage,
+    mut transfer: Transfer,
+) -> Result<(), Error> {
+    let directory = warp_storage.current_directory()?;
+    if let Ok(item) = directory.get_item(&transfer.name) {
+        // an upload which stopped half way may have left the file behind. anything else with the
+        // name was added since, so it's kept and the file is uploaded under another name
+        if transfer.item == Some(item.id()) {
+            if item.size() == transfer.size {
+                TRANSFERS.finish(transfer.id, TransferStatus::Completed);
+                return Ok(());
+            }
+            warp_storage.remove(&transfer.name, false).await?;
+            prune_hashes(warp_storage);
+        } else {
+            let name = rename_if_duplicate(
+                directory.clone(),
+                transfer.name.clone(),
+                PathBuf::from(&transfer.name),
+            );
+            TRANSFERS.rename(transfer.id, name.clone());
+            transfer.name = name;
+        }
+    }
+    let control = match TRANSFERS.restart(transfer.id) {
+        Some(control) => control,
+        None => return Err(Error::InvalidItem),
+    };
+    let upload_progress = warp_storage
+        .put(&transfer.name, &transfer.local_path.to_string_lossy())
+        .await?;
+    record_transfer_item(&directory, &transfer.name, transfer.id);
+
+    let hash = hash_upload(
+        transfer.local_path.clone(),
+        storage_files(warp_storage),
+        transfer.name.clone(),
+    );
+
+    let tx_upload_file = UPLOAD_FILE_LISTENER.tx.clone();
+    let _ = tx_upload_file.send(UploadFileAction::Starting(transfer.name.clone()));
+    let mut warp_storage = warp_storage.clone();
+    tokio::spawn(async move {
+        handle_upload_progress(
+            directory,
+            upload_progress,
+            transfer.name,
+            transfer.local_path,
+            transfer.id,
+            control,
+            hash,
+        )
+        .await;
+        HASH_INDEX.lock().save();
+        let ret = match get_items_from_current_directory(&mut warp_storage) {
+            Ok(r) => UploadFileAction::Finished(r),
+            Err(_) => UploadFileAction::Error,
+        };
+        let _ = tx_upload_file.send(ret);
+    });
+    Ok(())
+}
+
+// the files inside a storage folder, by their path relative to it
+fn list_remote(directory: &Directory) -> Listing {
+    walk_items(directory, Path::new(""))
+        .into_iter()
+        .filter(|(_, item)| !item.is_directory())
+        .map(|(path, item)| {
+            let path = join_storage_path(&path, &item.name())
+                .to_string_lossy()
+                .to_string();
+            let state = FileState {
+                modified: item.modified().timestamp(),
+                size: item.size(),
+            };
+            (path, state)
+        })
+        .collect()
+}
+
+// the storage directory holding a synced file, from the root, and the name of the file
+fn split_sync_path(remote_root: &str, path: &str) -> (String, String) {
+    let full = format!("/{}/{path}", remote_root.trim_matches('/')).replace("//", "/");
+    match full.rsplit_once('/') {
+        Some(("", name)) => ("/".into(), name.to_string()),
+        Some((parent, name)) => (parent.to_string(), name.to_string()),
+        None => ("/".into(), full),
+    }
+}
+
+// the part of a sync action which moves the bytes. it runs in a spawned task like the uploads of
+// `upload_files`, so the warp runner isn't held up while files are transferred
+type SyncTransfer = BoxFuture<'static, Result<(), Error>>;
+
+fn sync_done() -> SyncTransfer {
+    future::ready(Ok(())).boxed()
+}
+
+async fn sync_upload(
+    warp_storage: &mut warp_storage,
+    local_root: &Path,
+    remote_root: &str,
+    path: &str,
+) -> Result<SyncTransfer, Error> {
+    let (parent, name) = split_sync_path(remote_root, path);
+    let directory = original_directory(warp_storage, &parent).await?;
+    warp_storage.set_path(PathBuf::from(&parent));
+    if directory.has_item(&name) {
+        warp_storage.remove(&name, false).await?;
+    }
+    let local_path = local_root.join(path);
+    let mut upload_progress = warp_storage
+        .put(&name, &local_path.to_string_lossy())
+        .await?;
+    Ok(async move {
+        while let Some(progress) = upload_progress.next().await {
+            if let Progression::ProgressFailed { error, .. } = progress {
+                return Err(anyhow::anyhow!(error.unwrap_or_default()).into());
+            }
+        }
+        record_hash(&directory, &name, hash_local_file(local_path).await);
+        Ok(())
+    }
+    .boxed())
+}
+
+// the file is downloaded next to where it goes, so an unfinished download never replaces it
+async fn sync_download(
+    warp_storage: &mut warp_storage,
+    local_root: &Path,
+    remote_root: &str,
+    path: &str,
+) -> Result<SyncTransfer, Error> {
+    let (parent, name) = split_sync_path(remote_root, path);
+    warp_storage.set_path(PathBuf::from(&parent));
+    let local_path = local_root.join(path);
+    if let Some(dir) = local_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial_path = PathBuf::from(format!("{}{PARTIAL_SUFFIX}", local_path.to_string_lossy()));
+    let mut stream = warp_storage.get_stream(&name).await?;
+    let mut file = std::fs::File::create(&partial_path)?;
+    Ok(async move {
+        while let Some(data) = stream.next().await {
+            let written = match data {
+                Ok(data) => file.write_all(&data).map_err(Error::from),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                drop(file);
+                let _ = std::fs::remove_file(&partial_path);
+                return Err(e);
+            }
+        }
+        std::fs::rename(&partial_path, &local_path)?;
+        Ok(())
+    }
+    .boxed())
+}
+
+// starts a sync action. deletes are done right away
+async fn start_sync_action(
+    warp_storage: &mut warp_storage,
+    local_root: &Path,
+    remote_root: &str,
+    action: &SyncAction,
+) -> Result<SyncTransfer, Error> {
+    match action {
+        SyncAction::Upload(path) => sync_upload(warp_storage, local_root, remote_root, path).await,
+        SyncAction::Download(path) => {
+            sync_download(warp_storage, local_root, remote_root, path).await
+        }
+        SyncAction::DeleteLocal(path) => {
+            local_sync::trash_local(local_root, path)?;
+            Ok(sync_done())
+        }
+        SyncAction::DeleteRemote(path) => {
+            let (parent, name) = split_sync_path(remote_root, path);
+            let path = join_storage_path(Path::new(&parent), &name);
+            move_file_to_trash(warp_storage, path.to_string_lossy().to_string()).await?;
+            Ok(sync_done())
+        }
+        SyncAction::Conflict { path, copy } => {
+            std::fs::rename(local_root.join(path), local_root.join(copy))?;
+            let upload = sync_upload(warp_storage, local_root, remote_root, copy).await?;
+            let download = sync_download(warp_storage, local_root, remote_root, path).await?;
+            Ok(async move {
+                upload.await?;
+                download.await
+            }
+            .boxed())
+        }
+    }
+}
+
+// one pass of the two way sync between a local folder and a storage folder. the pass is planned and
+// its transfers are started here, the rest of it is returned to be run in a spawned task
+async fn sync_folder(
+    warp_storage: &mut warp_storage,
+    local_root: PathBuf,
+    remote_root: String,
+) -> Result<SyncTransfer, Error> {
+    // syncing the whole storage would sync the trash as well
+    if remote_root.trim_matches('/').is_empty() {
+        return Err(anyhow::anyhow!(get_local_text("settings-files.sync-root-not-allowed")).into());
+    }
+    // nor can it sync into the trash, or create a folder with its name
+    if let Some(name) = remote_root.trim_matches('/').split('/').next() {
+        check_reserved_name(name)?;
+    }
+    std::fs::create_dir_all(&local_root)?;
+    let base_path = warp_storage.get_path().to_path_buf();
+    let remote_dir = original_directory(warp_storage, &remote_root).await?;
+    warp_storage.set_path(base_path.clone());
+
+    let mut snapshot =
+        SyncSnapshot::load(STATIC_ARGS.sync_snapshot.clone(), &local_root, &remote_root);
+    let actions = local_sync::plan(
+        &local_sync::list_local(&local_root),
+        &list_remote(&remote_dir),
+        &snapshot.files,
+        chrono::Local::now().date_naive(),
+    );
+    if let Some(count) = local_sync::too_many_deletes(&actions, snapshot.files.len()) {
+        log::warn!("refusing to delete {count} synced files");
+        return Err(anyhow::anyhow!(get_local_text_with_args(
+            "files.sync-too-many-deletes",
+            vec![("count", count)],
+        ))
+        .into());
+    }
+
+    let mut started = vec![];
+    for action in actions {
+        local_sync::set_status(&remote_root, action.path(), SyncStatus::Syncing);
+        let res = start_sync_action(warp_storage, &local_root, &remote_root, &action).await;
+        warp_storage.set_path(base_path.clone());
+        started.push((action, res));
+    }
+
+    let warp_storage = warp_storage.clone();
+    Ok(async move {
+        let count = started.len();
+        let mut failures = HashMap::new();
+        for (action, res) in started {
+            let res = match res {
+                Ok(transfer) => transfer.await,
+                Err(e) => Err(e),
+            };
+            match res {
+                Ok(()) => {
+                    if let SyncAction::Conflict { copy, .. } = action {
+                        snapshot.conflicts.push(copy);
+                    }
+                }
+                Err(e) => {
+                    log::error!("failed to sync {}: {e}", action.path());
+                    failures.insert(action.path().to_string(), e.to_string());
+                }
+            }
+        }
+
+        // only files which are the same on both sides now count as synced
+        let local = local_sync::list_local(&local_root);
+        let remote = list_remote(&remote_dir);
+        let previous = std::mem::take(&mut snapshot.files);
+        let mut statuses = HashMap::new();
+        for (path, local_state) in &local {
+            let remote_state = match remote.get(path) {
+                Some(state) => *state,
+                None => continue,
+            };
+            let status = match failures.remove(path) {
+                Some(error) => {
+                    if let Some(synced) = previous.get(path) {
+                        snapshot.files.insert(path.clone(), *synced);
+                    }
+                    SyncStatus::Failed(error)
+                }
+                None => {
+                    snapshot.files.insert(
+                        path.clone(),
+                        SyncedFile {
+                            local: *local_state,
+                            remote: remote_state,
+                        },
+                    );
+                    if snapshot.conflicts.contains(path) {
+                        SyncStatus::Conflict
+                    } else {
+                        SyncStatus::Synced
+                    }
+                }
+            };
+            statuses.insert(path.clone(), status);
+        }
+        for (path, error) in failures {
+            statuses.insert(path, SyncStatus::Failed(error));
+        }
+        snapshot
+            .conflicts
+            .retain(|path| local.contains_key(path) || remote.contains_key(path));
+        snapshot.save();
+        local_sync::set_statuses(&remote_root, statuses);
+
+        if count > 0 {
+            prune_hashes(&warp_storage);
+            log::info!("synced {} changes with {}", count, local_root.display());
+        }
+        Ok(())
+    }
+    .boxed())
+}
+
 pub fn thumbnail_to_base64(file: &File) -> String {
     let thumbnail = file.thumbnail();
 
@@ -814,3 +2412,123 @@ pub fn thumbnail_to_base64(file: &File) -> String {
 
     prefix + &base64_image
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn folder(name: &str, items: Vec<Item>) -> Item {
+        let directory = Directory::new(name);
+        for item in items {
+            directory.add_item(item).unwrap();
+        }
+        Item::from(directory)
+    }
+
+    fn file(name: &str) -> Item {
+        Item::from(File::new(name))
+    }
+
+    fn zip_paths(base_path: &str, items: &[Item]) -> Vec<(String, String)> {
+        zip_entries(Path::new(base_path), items)
+            .into_iter()
+            .map(|(directory, zip_path, _)| (storage_path(&directory), zip_path))
+            .collect()
+    }
+
+    fn directory_with(items: Vec<Item>) -> Directory {
+        let directory = Directory::new("destination");
+        for item in items {
+            directory.add_item(item).unwrap();
+        }
+        directory
+    }
+
+    #[test]
+    fn free_names_are_kept() {
+        let destination = directory_with(vec![file("a.txt")]);
+        for resolution in [
+            ConflictResolution::Rename,
+            ConflictResolution::Replace,
+            ConflictResolution::Skip,
+        ] {
+            assert_eq!(
+                conflict_name(&destination, "b.txt".into(), resolution),
+                Some("b.txt".into())
+            );
+        }
+    }
+
+    #[test]
+    fn conflicts_are_resolved() {
+        let destination = directory_with(vec![
+            file("a.txt"),
+            file("a (1).txt"),
+            folder("photos", vec![]),
+        ]);
+        assert_eq!(
+            conflict_name(&destination, "a.txt".into(), ConflictResolution::Rename),
+            Some("a (2).txt".into())
+        );
+        assert_eq!(
+            conflict_name(&destination, "photos".into(), ConflictResolution::Rename),
+            Some("photos (1)".into())
+        );
+        assert_eq!(
+            conflict_name(&destination, "a.txt".into(), ConflictResolution::Replace),
+            Some("a.txt".into())
+        );
+        assert_eq!(
+            conflict_name(&destination, "a.txt".into(), ConflictResolution::Skip),
+            None
+        );
+    }
+
+    #[test]
+    fn zip_paths_are_relative_to_the_current_directory() {
+        let items = vec![
+            folder(
+                "photos",
+                vec![file("a.png"), folder("2023", vec![file("b.png")])],
+            ),
+            file("notes.txt"),
+        ];
+        assert_eq!(
+            zip_paths("/docs", &items),
+            vec![
+                ("/docs".into(), "photos".into()),
+                ("/docs/photos".into(), "photos/a.png".into()),
+                ("/docs/photos".into(), "photos/2023".into()),
+                ("/docs/photos/2023".into(), "photos/2023/b.png".into()),
+                ("/docs".into(), "notes.txt".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn zip_paths_are_unique() {
+        let items = vec![
+            file("notes.txt"),
+            file("notes.txt"),
+            file("notes.txt"),
+            folder("photos", vec![file("a.png")]),
+            folder("photos", vec![file("a.png")]),
+        ];
+        let paths: Vec<String> = zip_paths("/", &items)
+            .into_iter()
+            .map(|(_, zip_path)| zip_path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "notes.txt",
+                "notes (1).txt",
+                "notes (2).txt",
+                "photos",
+                "photos/a.png",
+                "photos (1)",
+                "photos (1)/a.png",
+            ]
+        );
+    }
+}
@@ -8,7 +8,10 @@ mod tesseract_commands;
 // this shortens the path required to use the functions and structs
 pub use blink_commands::{handle_blink_cmd, BlinkCmd};
 pub use constellation_commands::{handle_constellation_cmd, thumbnail_to_base64, ConstellationCmd};
-pub use multipass_commands::{handle_multipass_cmd, identity_image_to_base64, MultiPassCmd};
+pub use multipass_commands::{
+    forget_pending_request_note, handle_multipass_cmd, identity_image_to_base64,
+    send_pending_request_note, MultiPassCmd,
+};
 pub use other_commands::*;
 pub use raygun_commands::{handle_raygun_cmd, RayGunCmd};
 pub use tesseract_commands::{handle_tesseract_cmd, TesseractCmd};
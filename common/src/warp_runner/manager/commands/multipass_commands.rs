@@ -3,6 +3,8 @@ use std::{collections::HashMap, str::FromStr};
 use derive_more::Display;
 
 use futures::channel::oneshot;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use warp::{
     constellation::file::FileType,
     crypto::DID,
@@ -17,9 +19,21 @@ use tracing::log;
 
 use crate::{
     state::{self, Identity},
-    warp_runner::{ui_adapter::dids_to_identity, Account},
+    warp_runner::{ui_adapter::dids_to_identity, Account, Messaging},
+    STATIC_ARGS,
 };
 
+// notes of sent requests by the DID they were sent to. they are kept until the request is accepted or closed,
+// and saved so that a request accepted after a restart still gets its note
+static PENDING_REQUEST_NOTES: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| {
+    Mutex::new(
+        std::fs::read(&STATIC_ARGS.request_notes)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default(),
+    )
+});
+
 #[derive(Display)]
 pub enum MultiPassCmd {
     #[display(fmt = "RecoverIdentity")]
@@ -45,6 +59,8 @@ pub enum MultiPassCmd {
     #[display(fmt = "RequestFriend")]
     RequestFriend {
         id: String,
+        // sent as a direct message once the request is sent
+        note: Option<String>,
         outgoing_requests: Vec<Identity>,
         rsp: oneshot::Sender<Result<(), warp::error::Error>>,
     },
@@ -162,6 +178,7 @@ pub async fn handle_multipass_cmd(cmd: MultiPassCmd, warp: &mut super::super::Wa
         }
         MultiPassCmd::RequestFriend {
             id,
+            note,
             outgoing_requests,
             rsp,
         } => {
@@ -211,6 +228,11 @@ pub async fn handle_multipass_cmd(cmd: MultiPassCmd, warp: &mut super::super::Wa
                 return;
             }
             let r = warp.multipass.send_request(&did).await;
+            if let (Ok(_), Some(note)) = (&r, note.filter(|n| !n.trim().is_empty())) {
+                let mut notes = PENDING_REQUEST_NOTES.lock();
+                notes.insert(did.to_string(), note.trim().to_string());
+                save_request_notes(&notes);
+            }
             let _ = rsp.send(r);
         }
         MultiPassCmd::GetOwnDid { rsp } => {
@@ -247,6 +269,9 @@ pub async fn handle_multipass_cmd(cmd: MultiPassCmd, warp: &mut super::super::Wa
         }
         MultiPassCmd::CancelRequest { did, rsp } => {
             let r = warp.multipass.close_request(&did).await;
+            if r.is_ok() {
+                forget_pending_request_note(&did);
+            }
             let _ = rsp.send(r);
         }
         MultiPassCmd::GetProfilePicture { did, rsp } => {
@@ -452,6 +477,54 @@ async fn update_identity(id: &mut Identity, warp: &mut crate::warp_runner::manag
     }
 }
 
+fn save_request_notes(notes: &HashMap<String, String>) {
+    let contents = match serde_json::to_vec(notes) {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("failed to serialize request notes: {e}");
+            return;
+        }
+    };
+    if let Err(e) = std::fs::write(&STATIC_ARGS.request_notes, contents) {
+        log::error!("failed to save request notes: {e}");
+    }
+}
+
+/// warp friend requests can't carry text, and a conversation can't be opened with someone who isn't a friend yet.
+/// the note of a request is sent as the first message once it is accepted then. the request is accepted either way,
+/// so failing to send the note is only logged
+pub async fn send_pending_request_note(did: &DID, messaging: &mut Messaging) {
+    let note = {
+        let mut notes = PENDING_REQUEST_NOTES.lock();
+        let note = notes.remove(&did.to_string());
+        if note.is_some() {
+            save_request_notes(&notes);
+        }
+        note
+    };
+    let note = match note {
+        Some(note) => note,
+        None => return,
+    };
+    let conv_id = match messaging.create_conversation(did).await {
+        Ok(conv) | Err(Error::ConversationExist { conversation: conv }) => conv.id(),
+        Err(e) => {
+            log::warn!("failed to send the request note: {e}");
+            return;
+        }
+    };
+    if let Err(e) = messaging.send(conv_id, vec![note]).await {
+        log::warn!("failed to send the request note: {e}");
+    }
+}
+
+pub fn forget_pending_request_note(did: &DID) {
+    let mut notes = PENDING_REQUEST_NOTES.lock();
+    if notes.remove(&did.to_string()).is_some() {
+        save_request_notes(&notes);
+    }
+}
+
 async fn multipass_refresh_friends(
     account: &mut Account,
 ) -> Result<HashMap<DID, state::Identity>, Error> {
@@ -62,6 +62,14 @@ async fn compress_folder(src: PathBuf, dest: PathBuf) -> Result<(), error::Error
     res.map_err(|e| warp::error::Error::OtherWithContext(e.to_string()))
 }
 
+// shared with the zip downloads of storage items
+pub(super) fn zip_options(method: zip::CompressionMethod) -> FileOptions {
+    FileOptions::default()
+        .compression_method(method)
+        .unix_permissions(0o755)
+        .large_file(true)
+}
+
 // taken from https://github.com/zip-rs/zip/blob/master/examples/write_dir.rs
 fn zip_dir<T>(
     it: &mut dyn Iterator<Item = walkdir::DirEntry>,
@@ -73,10 +81,7 @@ where
     T: Write + Seek,
 {
     let mut zip = zip::ZipWriter::new(writer);
-    let options = FileOptions::default()
-        .compression_method(method)
-        .unix_permissions(0o755)
-        .large_file(true);
+    let options = zip_options(method);
 
     for entry in it {
         let path = entry.path();
@@ -24,8 +24,9 @@ use crate::{
     warp_runner::{
         conv_stream,
         ui_adapter::{
-            self, conversation_to_chat, dids_to_identity, fetch_messages2, fetch_messages_between,
-            fetch_messages_from_chat, fetch_pinned_messages_from_chat, get_uninitialized_identity,
+            self, conversation_to_chat, dids_to_identity, fetch_attachments, fetch_messages2,
+            fetch_messages_between, fetch_messages_from_chat, fetch_pinned_messages_from_chat,
+            get_uninitialized_identity,
         },
         Account, FetchMessagesConfig, FetchMessagesResponse, Messaging,
     },
@@ -101,6 +102,16 @@ pub enum RayGunCmd {
         conv_id: Uuid,
         rsp: oneshot::Sender<Result<Vec<ui_adapter::Message>, warp::error::Error>>,
     },
+    // a page of attachments, with the date to fetch the next page before if there are more
+    #[display(fmt = "FetchAttachments {{ conv_id: {conv_id}, before: {before} }} ")]
+    FetchAttachments {
+        conv_id: Uuid,
+        before: DateTime<Utc>,
+        limit: usize,
+        rsp: oneshot::Sender<
+            Result<(Vec<ui_adapter::MessageAttachment>, Option<DateTime<Utc>>), warp::error::Error>,
+        >,
+    },
     #[display(fmt = "SendMessage")]
     SendMessage {
         conv_id: Uuid,
@@ -268,6 +279,15 @@ pub async fn handle_raygun_cmd(
             let r = fetch_pinned_messages_from_chat(conv_id, messaging).await;
             let _ = rsp.send(r);
         }
+        RayGunCmd::FetchAttachments {
+            conv_id,
+            before,
+            limit,
+            rsp,
+        } => {
+            let r = fetch_attachments(conv_id, messaging, before, limit).await;
+            let _ = rsp.send(r);
+        }
         RayGunCmd::SendMessage {
             conv_id,
             msg,
@@ -18,8 +18,8 @@ use crate::{
 
 use super::{
     commands::{
-        handle_constellation_cmd, handle_multipass_cmd, handle_other_cmd, handle_raygun_cmd,
-        handle_tesseract_cmd,
+        forget_pending_request_note, handle_constellation_cmd, handle_multipass_cmd,
+        handle_other_cmd, handle_raygun_cmd, handle_tesseract_cmd, send_pending_request_note,
     },
     MultiPassCmd,
 };
@@ -33,6 +33,15 @@ pub async fn handle_multipass_event(
         None => return Ok(()),
     };
     log::debug!("received multipass event: {:?}", &evt);
+    match &evt {
+        MultiPassEventKind::FriendAdded { did } => {
+            send_pending_request_note(did, &mut warp.raygun).await
+        }
+        MultiPassEventKind::OutgoingFriendRequestRejected { did } => {
+            forget_pending_request_note(did)
+        }
+        _ => {}
+    }
     let warp_event_tx = WARP_EVENT_CH.tx.clone();
     match ui_adapter::convert_multipass_event(evt, &mut warp.multipass, &mut warp.raygun).await {
         Ok(evt) => {
@@ -282,6 +282,57 @@ pub async fn fetch_pinned_messages_from_chat(
     Ok(messages)
 }
 
+/// a file sent in a conversation, with the message it came with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageAttachment {
+    pub conversation_id: Uuid,
+    pub message_id: Uuid,
+    pub sender: DID,
+    pub date: DateTime<Utc>,
+    pub file: File,
+}
+
+/// the attachments of up to `limit` messages sent before `before`, oldest first, and the date to fetch the
+/// next page before if there may be older messages
+pub async fn fetch_attachments(
+    conv_id: Uuid,
+    messaging: &mut super::Messaging,
+    before: DateTime<Utc>,
+    limit: usize,
+) -> Result<(Vec<MessageAttachment>, Option<DateTime<Utc>>), Error> {
+    let message_options = MessageOptions::default()
+        .set_date_range(DateTime::<Utc>::default()..before)
+        .set_reverse()
+        .set_limit(limit as _);
+    let messages: Vec<raygun::Message> = messaging
+        .get_messages(conv_id, message_options)
+        .await
+        .and_then(Vec::<_>::try_from)?;
+    let next = if messages.len() < limit {
+        None
+    } else {
+        messages.iter().map(|message| message.date()).min()
+    };
+
+    let mut attachments: Vec<_> = messages
+        .iter()
+        .flat_map(|message| {
+            message
+                .attachments()
+                .into_iter()
+                .map(|file| MessageAttachment {
+                    conversation_id: conv_id,
+                    message_id: message.id(),
+                    sender: message.sender(),
+                    date: message.date(),
+                    file,
+                })
+        })
+        .collect();
+    attachments.sort_by_key(|attachment| attachment.date);
+    Ok((attachments, next))
+}
+
 pub async fn fetch_messages2(
     conv_id: Uuid,
     messaging: &mut super::Messaging,
@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::is_video;
+use common::language::get_local_text;
+use common::return_correct_icon;
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
+use common::utils::media_preview::{format_duration, get_media_preview, MediaPreview};
+use dioxus::prelude::*;
+use tracing::log;
+
+use crate::elements::loader::Loader;
+
+// videos can be big, so this is more generous than for voice messages
+const TIME_TO_WAIT_FOR_DOWNLOAD: u64 = 300000;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // where the file is, or will be once it is downloaded
+    path: PathBuf,
+
+    // shown until the video is played. the first frame is used if there is none
+    thumbnail: Option<String>,
+
+    // called when play is pressed and the file isn't on disk yet
+    on_download: EventHandler<'a, PathBuf>,
+}
+
+// an inline player for audio and video attachments. the native controls are used for seeking and volume.
+// message_actions.js swaps the player for the file icon if the webview can't decode the file
+#[allow(non_snake_case)]
+pub fn MediaPlayer<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let preview: &UseState<Option<MediaPreview>> = use_state(cx, || None);
+    let requested = use_state(cx, || false);
+    let failed = use_state(cx, || false);
+    let downloaded = cx.props.path.exists();
+
+    use_future(
+        cx,
+        (&cx.props.path, requested.get()),
+        |(path, requested)| {
+            to_owned![preview, failed];
+            async move {
+                let mut waited = 0;
+                while !path.exists() {
+                    if !requested {
+                        return;
+                    }
+                    if waited > TIME_TO_WAIT_FOR_DOWNLOAD {
+                        log::warn!("{} wasn't downloaded in time", path.display());
+                        failed.set(true);
+                        return;
+                    }
+                    tokio::time::sleep(Duration::from_millis(250)).await;
+                    waited += 250;
+                }
+                match tokio::task::spawn_blocking(move || get_media_preview(&path)).await {
+                    Ok(res) => preview.set(Some(res)),
+                    Err(e) => log::error!("failed to read media file: {e}"),
+                }
+            }
+        },
+    );
+
+    let file_name = cx
+        .props
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let is_video = is_video(&file_name);
+    let thumbnail = cx
+        .props
+        .thumbnail
+        .clone()
+        .filter(|t| !t.is_empty())
+        .or_else(|| preview.get().as_ref().and_then(|p| p.thumbnail.clone()))
+        .unwrap_or_default();
+    let duration = preview
+        .get()
+        .as_ref()
+        .and_then(|p| p.duration)
+        .map(format_duration)
+        .unwrap_or_default();
+    let src = get_fixed_path_to_load_local_file(cx.props.path.clone());
+    let autoplay = *requested.get();
+
+    cx.render(rsx!(
+        div {
+            class: format_args!(
+                "media-player {} {}",
+                if is_video { "video" } else { "audio" },
+                if *failed.get() { "failed" } else { "" }
+            ),
+            aria_label: "media-player",
+            if downloaded {
+                if is_video {
+                    rsx!(video {
+                        aria_label: "media-player-video",
+                        controls: true,
+                        preload: "metadata",
+                        autoplay: autoplay,
+                        poster: "{thumbnail}",
+                        src: "{src}",
+                    })
+                } else {
+                    rsx!(audio {
+                        aria_label: "media-player-audio",
+                        controls: true,
+                        preload: "metadata",
+                        autoplay: autoplay,
+                        src: "{src}",
+                    })
+                }
+            } else {
+                rsx!(div {
+                    class: "media-player-placeholder",
+                    aria_label: "media-player-placeholder",
+                    title: get_local_text("files.play"),
+                    onclick: move |_| {
+                        if !*requested.get() {
+                            requested.set(true);
+                            cx.props.on_download.call(cx.props.path.clone());
+                        }
+                    },
+                    (!thumbnail.is_empty()).then(|| rsx!(img {
+                        class: "media-player-thumbnail",
+                        src: "{thumbnail}",
+                    })),
+                    div {
+                        class: "media-player-play",
+                        if *requested.get() {
+                            rsx!(Loader {
+                                spinning: true
+                            })
+                        } else {
+                            rsx!(IconElement {
+                                icon: Icon::Play
+                            })
+                        }
+                    }
+                })
+            },
+            (!duration.is_empty()).then(|| rsx!(span {
+                class: "media-player-duration",
+                aria_label: "media-player-duration",
+                "{duration}"
+            })),
+            div {
+                class: "media-player-fallback",
+                aria_label: "media-player-fallback",
+                IconElement {
+                    icon: return_correct_icon(&file_name)
+                }
+            }
+        }
+    ))
+}
@@ -1,5 +1,6 @@
 use std::ffi::OsStr;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::elements::button::Button;
 use crate::elements::Appearance;
@@ -19,8 +20,15 @@ use humansize::DECIMAL;
 use mime::IMAGE_JPEG;
 use mime::IMAGE_PNG;
 use mime::IMAGE_SVG;
+use tracing::log;
 use warp::constellation::Progression;
 
+use self::media_player::MediaPlayer;
+use self::voice_message::{VoiceMessage, TIME_TO_WAIT_FOR_DOWNLOAD};
+
+pub mod media_player;
+mod voice_message;
+
 #[derive(Props)]
 pub struct Props<'a> {
     // The filename of the file
@@ -167,6 +175,94 @@ pub fn FileEmbed<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let is_video_or_audio =
         is_video(&file_name_with_extension) || is_audio(&file_name_with_extension);
     let is_video = is_video(&file_name_with_extension);
+    let path = cx
+        .props
+        .filepath
+        .clone()
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| temp_dir.clone());
+
+    // recorded clips are tagged in their comment header, so a voice message is only recognised once it is on disk.
+    // until then it is shown like any other audio file, which downloads it
+    let may_be_voice_message = file_name_with_extension.to_lowercase().ends_with(".ogg")
+        && !is_from_attachments
+        && !is_pending;
+    let is_voice_message = use_state(cx, || false);
+    use_future(
+        cx,
+        (&path, &may_be_voice_message),
+        |(path, may_be_voice_message)| {
+            to_owned![is_voice_message];
+            async move {
+                if !may_be_voice_message {
+                    return;
+                }
+                let mut waited = 0;
+                while !path.exists() {
+                    if waited > TIME_TO_WAIT_FOR_DOWNLOAD {
+                        return;
+                    }
+                    tokio::time::sleep(Duration::from_millis(250)).await;
+                    waited += 250;
+                }
+                match tokio::task::spawn_blocking(move || {
+                    common::voice_message::is_voice_message(&path)
+                })
+                .await
+                {
+                    Ok(res) => is_voice_message.set(res),
+                    Err(e) => log::error!("failed to read audio file: {e}"),
+                }
+            }
+        },
+    );
+
+    if *is_voice_message.get() && may_be_voice_message {
+        return cx.render(rsx!(
+            div {
+                class: format_args!("file-embed voice {}", if remote { "remote" } else { "" }),
+                aria_label: format_args!("file-embed{}", if remote { "-remote" } else { "" }),
+                VoiceMessage {
+                    path: path,
+                    on_download: move |path| cx.props.on_press.call(Some(path)),
+                },
+                show_download_or_minus_button_if_enabled(cx, with_download_button, btn_icon),
+            }
+        ));
+    }
+
+    // only message attachments are played inline, not the previews in replies and pinned messages
+    if is_video_or_audio && with_download_button && !is_from_attachments && !is_pending {
+        return cx.render(rsx!(
+            div {
+                class: format_args!("file-embed media {}", if remote { "remote" } else { "" }),
+                aria_label: format_args!("file-embed{}", if remote { "-remote" } else { "" }),
+                MediaPlayer {
+                    path: path,
+                    thumbnail: thumbnail,
+                    on_download: move |path| cx.props.on_press.call(Some(path)),
+                },
+                div {
+                    class: "media-info",
+                    div {
+                        class: "file-info",
+                        aria_label: "file-info",
+                        p {
+                            class: "name",
+                            aria_label: "file-name",
+                            "{filename}"
+                        },
+                        p {
+                            class: "meta",
+                            aria_label: "file-meta",
+                            "{file_description}"
+                        }
+                    },
+                    show_download_or_minus_button_if_enabled(cx, with_download_button, btn_icon),
+                }
+            }
+        ));
+    }
 
     cx.render(rsx! (
         div {
@@ -249,7 +345,7 @@ pub fn FileEmbed<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                                     class: "document-container",
                                     height: "60px",
                                     onclick: move |mouse_event_data: Event<MouseData>| {
-                                        if mouse_event_data.modifiers() != Modifiers::CONTROL && is_video_or_audio && !is_from_attachments {
+                                        if mouse_event_data.modifiers() != Modifiers::CONTROL && !is_from_attachments {
                                             cx.props.on_press.call(Some(temp_dir.clone()));
                                         }
                                     },
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::get_local_text;
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
+use common::voice_message::{get_waveform, Waveform};
+use dioxus::prelude::*;
+use tracing::log;
+
+const WAVEFORM_BARS: usize = 40;
+// the player stays disabled if the clip didn't download by then
+pub(super) const TIME_TO_WAIT_FOR_DOWNLOAD: u64 = 60000;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    // where the clip is, or will be once it is downloaded
+    path: PathBuf,
+
+    // called once if the clip isn't on disk yet
+    on_download: EventHandler<'a, PathBuf>,
+}
+
+// playback is handled by message_actions.js
+#[allow(non_snake_case)]
+pub fn VoiceMessage<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let waveform: &UseState<Option<Waveform>> = use_state(cx, || None);
+    let should_download = use_state(cx, || true);
+
+    if *should_download.get() {
+        should_download.set(false);
+        if !cx.props.path.exists() {
+            cx.props.on_download.call(cx.props.path.clone());
+        }
+    }
+
+    use_future(cx, &cx.props.path, |path| {
+        to_owned![waveform];
+        async move {
+            let mut waited = 0;
+            while !path.exists() {
+                if waited > TIME_TO_WAIT_FOR_DOWNLOAD {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(250)).await;
+                waited += 250;
+            }
+            match tokio::task::spawn_blocking(move || get_waveform(&path, WAVEFORM_BARS)).await {
+                Ok(Ok(res)) => waveform.set(Some(res)),
+                Ok(Err(e)) => log::error!("failed to read voice message: {e}"),
+                Err(e) => log::error!("failed to read voice message: {e}"),
+            }
+        }
+    });
+
+    let loaded = waveform.get().is_some();
+    let (peaks, duration) = match waveform.get() {
+        Some(res) => (res.peaks.clone(), res.duration),
+        None => (vec![0.0; WAVEFORM_BARS], Duration::ZERO),
+    };
+    let secs = duration.as_secs();
+    let src = get_fixed_path_to_load_local_file(cx.props.path.clone());
+
+    cx.render(rsx!(
+        div {
+            class: format_args!("voice-message {}", if loaded { "" } else { "loading" }),
+            aria_label: "voice-message",
+            loaded.then(|| rsx!(audio {
+                preload: "metadata",
+                src: "{src}",
+            })),
+            button {
+                class: "voice-message-play",
+                aria_label: "voice-message-play",
+                title: get_local_text("messages.voice-message-play"),
+                disabled: !loaded,
+                span {
+                    class: "play-icon",
+                    IconElement {
+                        icon: Icon::Play
+                    }
+                },
+                span {
+                    class: "pause-icon",
+                    IconElement {
+                        icon: Icon::Pause
+                    }
+                }
+            },
+            div {
+                class: "voice-message-waveform",
+                aria_label: "voice-message-waveform",
+                peaks.iter().enumerate().map(|(i, peak)| rsx!(span {
+                    key: "{i}",
+                    class: "bar",
+                    height: format_args!("{}%", (peak * 100.0).max(8.0)),
+                }))
+            },
+            span {
+                class: "voice-message-time",
+                aria_label: "voice-message-time",
+                format!("{}:{:02}", secs / 60, secs % 60)
+            },
+            button {
+                class: "voice-message-speed",
+                aria_label: "voice-message-speed",
+                title: get_local_text("messages.voice-message-speed"),
+                disabled: !loaded,
+                "1x"
+            }
+        }
+    ))
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use chrono::{Duration, Utc};
+use common::STATIC_ARGS;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::log;
+
+use super::SiteMeta;
+
+// how long a fetched preview is considered fresh
+const CACHE_TTL_DAYS: i64 = 7;
+// upper bound for the sum of all cached entries, icons included
+const CACHE_MAX_SIZE: usize = 10 * 1024 * 1024;
+// previews fetched close together are saved at once, off the thread which fetched them
+const SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+static SAVE_SCHEDULED: AtomicBool = AtomicBool::new(false);
+
+static LINK_PREVIEW_CACHE: Lazy<Mutex<LinkPreviewCache>> = Lazy::new(|| {
+    Mutex::new(LinkPreviewCache::load(
+        STATIC_ARGS.link_preview_cache.clone(),
+    ))
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    // unix timestamp, in seconds
+    fetched_at: i64,
+    meta: SiteMeta,
+}
+
+impl CacheEntry {
+    fn size(&self) -> usize {
+        self.meta.title.len()
+            + self.meta.description.len()
+            + self.meta.icon.len()
+            + self.meta.url.len()
+    }
+
+    fn is_expired(&self, now: i64) -> bool {
+        now - self.fetched_at > Duration::days(CACHE_TTL_DAYS).num_seconds()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LinkPreviewCache {
+    entries: HashMap<String, CacheEntry>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl LinkPreviewCache {
+    fn load(path: PathBuf) -> Self {
+        let mut cache: Self = std::fs::read(&path)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        cache.path = path;
+        let now = Utc::now().timestamp();
+        cache.entries.retain(|_, entry| !entry.is_expired(now));
+        cache
+    }
+
+    fn serialize(&self) -> Option<(PathBuf, Vec<u8>)> {
+        match serde_json::to_vec(&self) {
+            Ok(contents) => Some((self.path.clone(), contents)),
+            Err(e) => {
+                log::error!("failed to serialize link preview cache: {e}");
+                None
+            }
+        }
+    }
+
+    fn get(&mut self, url: &str) -> Option<SiteMeta> {
+        let entry = self.entries.get(url)?;
+        if entry.is_expired(Utc::now().timestamp()) {
+            self.entries.remove(url);
+            return None;
+        }
+        Some(entry.meta.clone())
+    }
+
+    fn insert(&mut self, meta: SiteMeta) {
+        let entry = CacheEntry {
+            fetched_at: Utc::now().timestamp(),
+            meta,
+        };
+        if entry.size() > CACHE_MAX_SIZE {
+            return;
+        }
+        self.entries.insert(entry.meta.url.clone(), entry);
+
+        // evict the oldest entries until the cache fits again
+        let mut total: usize = self.entries.values().map(CacheEntry::size).sum();
+        if total > CACHE_MAX_SIZE {
+            let mut by_age: Vec<(String, i64, usize)> = self
+                .entries
+                .iter()
+                .map(|(k, v)| (k.clone(), v.fetched_at, v.size()))
+                .collect();
+            by_age.sort_by_key(|(_, fetched_at, _)| *fetched_at);
+            for (url, _, size) in by_age {
+                if total <= CACHE_MAX_SIZE {
+                    break;
+                }
+                self.entries.remove(&url);
+                total -= size;
+            }
+        }
+    }
+}
+
+// the file is written after `SAVE_DELAY`, with every preview cached until then
+fn schedule_save() {
+    if SAVE_SCHEDULED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    std::thread::spawn(|| {
+        std::thread::sleep(SAVE_DELAY);
+        SAVE_SCHEDULED.store(false, Ordering::Release);
+        let contents = match LINK_PREVIEW_CACHE.lock() {
+            Ok(cache) => cache.serialize(),
+            Err(e) => {
+                log::error!("link preview cache poisoned: {e}");
+                None
+            }
+        };
+        if let Some((path, contents)) = contents {
+            if let Err(e) = std::fs::write(path, contents) {
+                log::error!("failed to save link preview cache: {e}");
+            }
+        }
+    });
+}
+
+pub fn get_cached_meta(url: &str) -> Option<SiteMeta> {
+    match LINK_PREVIEW_CACHE.lock() {
+        Ok(mut cache) => cache.get(url),
+        Err(e) => {
+            log::error!("link preview cache poisoned: {e}");
+            None
+        }
+    }
+}
+
+pub fn cache_meta(meta: SiteMeta) {
+    match LINK_PREVIEW_CACHE.lock() {
+        Ok(mut cache) => cache.insert(meta),
+        Err(e) => {
+            log::error!("link preview cache poisoned: {e}");
+            return;
+        }
+    }
+    schedule_save();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn meta(url: &str, icon_size: usize) -> SiteMeta {
+        SiteMeta {
+            url: url.into(),
+            icon: "x".repeat(icon_size),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn the_oldest_previews_are_evicted() {
+        let mut cache = LinkPreviewCache::default();
+        let third = CACHE_MAX_SIZE / 3;
+        cache.insert(meta("https://a.com", third));
+        cache.insert(meta("https://b.com", third));
+        // inserted in the same second, so they are aged by hand
+        cache.entries.get_mut("https://a.com").unwrap().fetched_at -= 2;
+        cache.entries.get_mut("https://b.com").unwrap().fetched_at -= 1;
+        cache.insert(meta("https://c.com", third));
+
+        assert!(cache.get("https://a.com").is_none());
+        assert!(cache.get("https://b.com").is_some());
+        assert!(cache.get("https://c.com").is_some());
+        let total: usize = cache.entries.values().map(CacheEntry::size).sum();
+        assert!(total <= CACHE_MAX_SIZE);
+
+        // too big to ever fit
+        cache.insert(meta("https://huge.com", CACHE_MAX_SIZE));
+        assert!(cache.get("https://huge.com").is_none());
+    }
+
+    #[test]
+    fn expired_previews_are_dropped() {
+        let mut cache = LinkPreviewCache::default();
+        cache.insert(meta("https://a.com", 1));
+        cache.entries.get_mut("https://a.com").unwrap().fetched_at -=
+            Duration::days(CACHE_TTL_DAYS + 1).num_seconds();
+        assert!(cache.get("https://a.com").is_none());
+        assert!(cache.entries.is_empty());
+    }
+}
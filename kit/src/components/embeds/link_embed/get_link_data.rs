@@ -1,6 +1,6 @@
 use std::error::Error;
 
-use reqwest::Url;
+use reqwest::{header::CONTENT_TYPE, Url};
 use scraper::{Html, Selector};
 
 pub fn get_image_data(document: Html, meta_selector: Selector) -> Option<String> {
@@ -86,6 +86,35 @@ pub fn get_description_data(document: Html, meta_selector: Selector) -> String {
     description
 }
 
+// icons are inlined into the cache, so anything bigger than this is dropped
+const MAX_ICON_SIZE: usize = 256 * 1024;
+
+/// downloads an image and returns it as a data URL, so that rendering the embed
+/// doesn't contact the linked site again.
+pub async fn fetch_image_as_data_url(url: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let resp = reqwest::get(url).await?;
+    if !resp.status().is_success() {
+        return Ok(None);
+    }
+    let mime = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.split(';').next().unwrap_or_default().trim().to_string())
+        .unwrap_or_default();
+    if !mime.starts_with("image/") {
+        return Ok(None);
+    }
+    let bytes = resp.bytes().await?;
+    if bytes.len() > MAX_ICON_SIZE {
+        return Ok(None);
+    }
+    Ok(Some(format!(
+        "data:{mime};base64,{}",
+        base64::encode(bytes)
+    )))
+}
+
 pub async fn fetch_icon(url: &str, document: Html) -> Result<Option<String>, Box<dyn Error>> {
     let selectors = vec![
         r#"link[rel="icon"]"#,
@@ -110,9 +139,8 @@ pub async fn fetch_icon(url: &str, document: Html) -> Result<Option<String>, Box
             };
             if let Some(path) = element.value().attr(attr) {
                 let full_url = base_url.join(path)?.to_string();
-                let resp = reqwest::get(&full_url).await?;
-                if resp.status().is_success() {
-                    return Ok(Some(full_url));
+                if let Some(icon) = fetch_image_as_data_url(&full_url).await? {
+                    return Ok(Some(icon));
                 }
             }
         }
@@ -4,12 +4,15 @@ use dioxus::prelude::{rsx, Props};
 use dioxus_core::{Element, Scope};
 use dioxus_hooks::use_future;
 use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
 
+use self::cache::{cache_meta, get_cached_meta};
 use self::get_link_data::*;
 
+mod cache;
 mod get_link_data;
 
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SiteMeta {
     pub title: String,
     pub description: String,
@@ -35,7 +38,15 @@ pub async fn get_meta(url: &str) -> Result<SiteMeta, reqwest::Error> {
     let icon = if let Ok(Some(icon)) = fetch_icon(url, document.clone()).await {
         icon
     } else {
-        get_image_data(document.clone(), meta_selector.clone()).unwrap_or_default()
+        let image = get_image_data(document.clone(), meta_selector.clone()).unwrap_or_default();
+        match reqwest::Url::parse(url).and_then(|base| base.join(&image)) {
+            Ok(image_url) if !image.is_empty() => fetch_image_as_data_url(image_url.as_str())
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
     };
     let title = get_title_data(document.clone(), meta_selector.clone());
     let description = get_description_data(document.clone(), meta_selector.clone());
@@ -48,6 +59,16 @@ pub async fn get_meta(url: &str) -> Result<SiteMeta, reqwest::Error> {
     })
 }
 
+/// same as get_meta but served from the on-disk cache when possible
+pub async fn get_meta_cached(url: &str) -> Result<SiteMeta, reqwest::Error> {
+    if let Some(meta) = get_cached_meta(url) {
+        return Ok(meta);
+    }
+    let meta = get_meta(url).await?;
+    cache_meta(meta.clone());
+    Ok(meta)
+}
+
 #[derive(Props, PartialEq)]
 pub struct LinkEmbedProps {
     link: String,
@@ -57,7 +78,7 @@ pub struct LinkEmbedProps {
 #[allow(non_snake_case)]
 pub fn EmbedLinks(cx: Scope<LinkEmbedProps>) -> Element {
     let fetch_meta = use_future(cx, &cx.props.link, |link| async move {
-        get_meta(link.as_str()).await
+        get_meta_cached(link.as_str()).await
     });
 
     let meta = match fetch_meta.value() {
@@ -0,0 +1,965 @@
+// Syntax highlighting for fenced code blocks.
+// Tokens are emitted as `<span class="token {kind}">` so the one-dark theme that
+// used to style Prism keeps working without any javascript.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Syntax {
+    CLike,
+    Rust,
+    Python,
+    Shell,
+    Json,
+    Config,
+    Sql,
+    Markup,
+}
+
+#[derive(Debug)]
+pub struct Language {
+    pub name: &'static str,
+    aliases: &'static [&'static str],
+    syntax: Syntax,
+    // whitespace separated
+    keywords: &'static str,
+    builtins: &'static str,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+}
+
+const BOOLEANS: &str = "true false True False None null nil undefined";
+
+static LANGUAGES: &[Language] = &[
+    Language {
+        name: "rust",
+        aliases: &["rs"],
+        syntax: Syntax::Rust,
+        keywords:
+            "as async await break const continue crate dyn else enum extern fn for if impl in \
+            let loop match mod move mut pub ref return self Self static struct super trait \
+            type union unsafe use where while",
+        builtins:
+            "bool char str u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 String \
+            Vec Option Result Box Some Ok Err",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"'],
+    },
+    Language {
+        name: "javascript",
+        aliases: &["js", "jsx", "mjs", "cjs", "node"],
+        syntax: Syntax::CLike,
+        keywords: "async await break case catch class const continue debugger default delete do \
+            else export extends finally for from function if import in instanceof let new of \
+            return static super switch this throw try typeof var void while with yield",
+        builtins: "Array Boolean console Date document Error JSON Map Math Number Object Promise \
+            Set String window",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+    },
+    Language {
+        name: "typescript",
+        aliases: &["ts", "tsx"],
+        syntax: Syntax::CLike,
+        keywords: "abstract as async await break case catch class const continue declare default \
+            delete do else enum export extends finally for from function if implements \
+            import in instanceof interface keyof let namespace new of private protected \
+            public readonly return static super switch this throw try type typeof var void \
+            while yield",
+        builtins: "any boolean never number object string symbol unknown Array console Promise \
+            Record",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+    },
+    Language {
+        name: "python",
+        aliases: &["py", "python3"],
+        syntax: Syntax::Python,
+        keywords:
+            "and as assert async await break class continue def del elif else except finally \
+            for from global if import in is lambda nonlocal not or pass raise return try \
+            while with yield",
+        builtins: "bool dict float int len list object print range self set str super tuple",
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "go",
+        aliases: &["golang"],
+        syntax: Syntax::CLike,
+        keywords: "break case chan const continue default defer else fallthrough for func go goto \
+            if import interface map package range return select struct switch type var",
+        builtins: "bool byte error float32 float64 int int32 int64 rune string uint uint8 uint32 \
+            uint64 append cap len make new panic",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+    },
+    Language {
+        name: "c",
+        aliases: &["h"],
+        syntax: Syntax::CLike,
+        keywords: "auto break case const continue default do else enum extern for goto if inline \
+            register return sizeof static struct switch typedef union volatile while",
+        builtins: "char double float int long short signed unsigned void size_t NULL",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "cpp",
+        aliases: &["c++", "cc", "cxx", "hpp"],
+        syntax: Syntax::CLike,
+        keywords:
+            "auto break case catch class const constexpr continue default delete do else enum \
+            explicit extern for friend goto if inline namespace new noexcept nullptr \
+            operator private protected public return sizeof static struct switch template \
+            this throw try typedef typename union using virtual while",
+        builtins: "bool char double float int long short signed unsigned void size_t std string \
+            vector",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "java",
+        aliases: &["kotlin", "kt"],
+        syntax: Syntax::CLike,
+        keywords: "abstract break case catch class continue default do else enum extends final \
+            finally for fun if implements import instanceof interface new package private \
+            protected public return static super switch this throw throws try val var void \
+            while",
+        builtins: "boolean byte char double float int long short String System",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "bash",
+        aliases: &["sh", "shell", "zsh", "console"],
+        syntax: Syntax::Shell,
+        keywords:
+            "case do done elif else esac export fi for function if in local return then until \
+            while",
+        builtins: "cd echo exit printf pwd read set source sudo unset",
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "json",
+        aliases: &["json5", "jsonc"],
+        syntax: Syntax::Json,
+        keywords: "",
+        builtins: "",
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"'],
+    },
+    Language {
+        name: "toml",
+        aliases: &["yaml", "yml", "ini", "conf"],
+        syntax: Syntax::Config,
+        keywords: "",
+        builtins: "",
+        line_comments: &["#", ";"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Language {
+        name: "sql",
+        aliases: &["mysql", "postgres", "postgresql", "sqlite"],
+        syntax: Syntax::Sql,
+        keywords: "add all alter and as asc by case create delete desc distinct drop else end \
+            exists from group having in index inner insert into is join key left like limit \
+            not null on or order outer primary right select set table then union update \
+            values when where",
+        builtins: "avg count int integer max min sum text varchar",
+        line_comments: &["--"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['\'', '"'],
+    },
+    Language {
+        name: "html",
+        aliases: &["xml", "svg", "markup"],
+        syntax: Syntax::Markup,
+        keywords: "",
+        builtins: "",
+        line_comments: &[],
+        block_comment: Some(("<!--", "-->")),
+        quotes: &['"', '\''],
+    },
+];
+
+/// looks up a language by the name given after the opening fence, e.g. ```rs
+pub fn find_language(name: &str) -> Option<&'static Language> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+    LANGUAGES
+        .iter()
+        .find(|l| l.name == name || l.aliases.contains(&name.as_str()))
+}
+
+/// guesses the language of a code block that didn't specify one.
+pub fn detect_language(code: &str) -> Option<&'static Language> {
+    let trimmed = code.trim();
+    if let Some(first_line) = trimmed.strip_prefix("#!").and_then(|s| s.lines().next()) {
+        let interpreter = if first_line.contains("python") {
+            "python"
+        } else if first_line.contains("node") {
+            "javascript"
+        } else {
+            "bash"
+        };
+        return find_language(interpreter);
+    }
+    if trimmed.starts_with('<') && trimmed.ends_with('>') {
+        return find_language("html");
+    }
+    if ((trimmed.starts_with('{') && trimmed.ends_with('}'))
+        || (trimmed.starts_with('[') && trimmed.ends_with(']')))
+        && !trimmed.contains(';')
+        && (trimmed.contains("\":") || !trimmed.chars().any(char::is_alphabetic))
+    {
+        return find_language("json");
+    }
+
+    // each hint that appears in the code counts towards its language
+    let hints: &[(&str, &[&str])] = &[
+        (
+            "rust",
+            &[
+                "fn ", "let mut ", "impl ", "pub fn ", "println!", "-> ", "&mut ", "use std",
+                "#[derive", "::new(", "match ", "Some(", "Ok(",
+            ],
+        ),
+        (
+            "python",
+            &[
+                "def ",
+                "import ",
+                "self.",
+                "elif ",
+                "print(",
+                "__init__",
+                "None",
+                "):\n",
+                "in range(",
+            ],
+        ),
+        (
+            "javascript",
+            &[
+                "function ",
+                "const ",
+                "=> ",
+                "console.log",
+                "require(",
+                "document.",
+                "let ",
+                "===",
+            ],
+        ),
+        (
+            "typescript",
+            &[
+                ": string",
+                ": number",
+                "interface ",
+                "export type ",
+                ": boolean",
+            ],
+        ),
+        ("go", &["package ", "func ", ":= ", "fmt.", "go func"]),
+        ("c", &["#include", "int main", "printf(", "malloc(", "->"]),
+        (
+            "cpp",
+            &[
+                "std::",
+                "#include <iostream>",
+                "cout <<",
+                "template<",
+                "template <",
+            ],
+        ),
+        (
+            "java",
+            &[
+                "public class ",
+                "public static void",
+                "System.out",
+                "import java",
+                "fun ",
+            ],
+        ),
+        (
+            "bash",
+            &[
+                "echo ", "sudo ", "apt ", "cd ", "fi\n", "done\n", "$(", "export ", "&& ",
+            ],
+        ),
+    ];
+    let mut best: Option<(&str, usize)> = None;
+    for (name, needles) in hints {
+        let score = needles.iter().filter(|n| trimmed.contains(*n)).count();
+        if score > best.map(|(_, s)| s).unwrap_or(1) {
+            best = Some((name, score));
+        }
+    }
+    if let Some((name, _)) = best {
+        return find_language(name);
+    }
+
+    let upper = trimmed.to_uppercase();
+    let sql_hints = [
+        "SELECT ",
+        "FROM ",
+        "WHERE ",
+        "INSERT INTO ",
+        "CREATE TABLE ",
+        "UPDATE ",
+    ];
+    if sql_hints.iter().filter(|h| upper.contains(*h)).count() >= 2 {
+        return find_language("sql");
+    }
+
+    let config_lines = trimmed
+        .lines()
+        .filter(|l| {
+            let l = l.trim();
+            (l.starts_with('[') && l.ends_with(']')) || l.contains(" = ") || l.ends_with(':')
+        })
+        .count();
+    if config_lines > 1 && config_lines * 2 >= trimmed.lines().count() {
+        return find_language("toml");
+    }
+
+    None
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    // None for text which isn't highlighted
+    pub kind: Option<&'static str>,
+    pub text: &'a str,
+}
+
+pub fn tokenize<'a>(code: &'a str, lang: &Language) -> Vec<Token<'a>> {
+    let mut tokens = Lexer {
+        code,
+        lang,
+        pos: 0,
+        plain_start: 0,
+        tokens: vec![],
+    };
+    if lang.syntax == Syntax::Markup {
+        tokens.markup();
+    } else {
+        tokens.code();
+    }
+    tokens.finish()
+}
+
+struct Lexer<'a, 'l> {
+    code: &'a str,
+    lang: &'l Language,
+    pos: usize,
+    // start of the plain text which hasn't been pushed yet
+    plain_start: usize,
+    tokens: Vec<Token<'a>>,
+}
+
+impl<'a, 'l> Lexer<'a, 'l> {
+    fn rest(&self) -> &'a str {
+        &self.code[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn prev(&self) -> Option<char> {
+        self.code[..self.pos].chars().next_back()
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.code[..self.pos]
+            .rsplit('\n')
+            .next()
+            .map(|l| l.trim().is_empty())
+            .unwrap_or(true)
+    }
+
+    fn push(&mut self, kind: &'static str, len: usize) {
+        if len == 0 {
+            return;
+        }
+        if self.plain_start < self.pos {
+            self.tokens.push(Token {
+                kind: None,
+                text: &self.code[self.plain_start..self.pos],
+            });
+        }
+        let end = (self.pos + len).min(self.code.len());
+        self.tokens.push(Token {
+            kind: Some(kind),
+            text: &self.code[self.pos..end],
+        });
+        self.pos = end;
+        self.plain_start = end;
+    }
+
+    fn skip_char(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn finish(mut self) -> Vec<Token<'a>> {
+        if self.plain_start < self.code.len() {
+            self.tokens.push(Token {
+                kind: None,
+                text: &self.code[self.plain_start..],
+            });
+        }
+        self.tokens
+    }
+
+    fn code(&mut self) {
+        while let Some(c) = self.peek() {
+            let rest = self.rest();
+            if let Some(len) = self.comment_len() {
+                self.push("comment", len);
+            } else if let Some(len) = self.prefixed_string_len() {
+                self.push("string", len);
+            } else if self.lang.quotes.contains(&c) {
+                self.quoted(c);
+            } else if self.lang.syntax == Syntax::Rust && c == '\'' {
+                self.rust_quote();
+            } else if c.is_ascii_digit()
+                || (c == '.' && rest[1..].starts_with(|c: char| c.is_ascii_digit()))
+            {
+                let len = rest
+                    .char_indices()
+                    .skip(1)
+                    .find(|(i, c)| {
+                        !(c.is_ascii_alphanumeric()
+                            || *c == '_'
+                            || (*c == '.'
+                                && rest[i + 1..].starts_with(|c: char| c.is_ascii_digit())))
+                    })
+                    .map(|(i, _)| i)
+                    .unwrap_or(rest.len());
+                self.push("number", len);
+            } else if c == '$' && self.lang.syntax == Syntax::Shell {
+                self.shell_variable();
+            } else if c.is_alphabetic()
+                || c == '_'
+                || (c == '$' && self.lang.syntax == Syntax::CLike)
+            {
+                self.word();
+            } else if c == '#' && self.at_line_start() && matches!(self.lang.name, "c" | "cpp") {
+                let len = rest.find('\n').unwrap_or(rest.len());
+                self.push("macro property", len);
+            } else if c == '[' && self.lang.syntax == Syntax::Config && self.at_line_start() {
+                let len = rest.find('\n').unwrap_or(rest.len());
+                self.push("class-name", len);
+            } else if "+-*/%=&|^!<>?:~@".contains(c) {
+                let len = rest
+                    .find(|c: char| !"+-*/%=&|^!<>?:~@".contains(c))
+                    .unwrap_or(rest.len());
+                self.push("operator", len);
+            } else if "{}[]();,.".contains(c) {
+                self.push("punctuation", 1);
+            } else {
+                self.skip_char();
+            }
+        }
+    }
+
+    fn comment_len(&self) -> Option<usize> {
+        let rest = self.rest();
+        if let Some((open, close)) = self.lang.block_comment {
+            if let Some(body) = rest.strip_prefix(open) {
+                return Some(
+                    body.find(close)
+                        .map(|i| open.len() + i + close.len())
+                        .unwrap_or(rest.len()),
+                );
+            }
+        }
+        let is_comment = self.lang.line_comments.iter().any(|c| rest.starts_with(c))
+            // `$#` and `a#b` aren't comments in a shell
+            && !(self.lang.syntax == Syntax::Shell
+                && self.prev().map(|c| !c.is_whitespace()).unwrap_or(false));
+        is_comment.then(|| rest.find('\n').unwrap_or(rest.len()))
+    }
+
+    // raw and byte strings in rust, prefixed and triple quoted strings in python
+    fn prefixed_string_len(&self) -> Option<usize> {
+        let rest = self.rest();
+        if self.prev().map(is_word_char).unwrap_or(false) {
+            return None;
+        }
+        match self.lang.syntax {
+            Syntax::Rust => {
+                if let Some(quote) = rest
+                    .strip_prefix('b')
+                    .and_then(|s| s.chars().next())
+                    .filter(|c| *c == '"' || *c == '\'')
+                {
+                    return Some(1 + quoted_len(&rest[1..], quote, true, quote == '"'));
+                }
+                let after_prefix = rest.strip_prefix("br").or_else(|| rest.strip_prefix('r'))?;
+                let hashes = after_prefix.len() - after_prefix.trim_start_matches('#').len();
+                let body = after_prefix[hashes..].strip_prefix('"')?;
+                let close = format!("\"{}", "#".repeat(hashes));
+                let start = rest.len() - body.len();
+                Some(
+                    body.find(&close)
+                        .map(|i| start + i + close.len())
+                        .unwrap_or(rest.len()),
+                )
+            }
+            Syntax::Python => {
+                let prefix_len = rest
+                    .find(|c: char| !matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'f' | 'u'))
+                    .unwrap_or(0);
+                if prefix_len > 2 {
+                    return None;
+                }
+                let body = &rest[prefix_len..];
+                let triple = ["\"\"\"", "'''"].into_iter().find(|q| body.starts_with(q));
+                match triple {
+                    Some(q) => Some(
+                        body[3..]
+                            .find(q)
+                            .map(|i| prefix_len + 3 + i + 3)
+                            .unwrap_or(rest.len()),
+                    ),
+                    None if prefix_len > 0 => {
+                        let quote = body.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+                        Some(prefix_len + quoted_len(body, quote, true, false))
+                    }
+                    None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn quoted(&mut self, quote: char) {
+        let rest = self.rest();
+        let escapes = !(self.lang.syntax == Syntax::Shell && quote == '\'');
+        let multiline = quote == '`' || matches!(self.lang.syntax, Syntax::Rust | Syntax::Shell);
+        let len = quoted_len(rest, quote, escapes, multiline);
+        let kind = if self.lang.syntax == Syntax::Json && rest[len..].trim_start().starts_with(':')
+        {
+            "property"
+        } else {
+            "string"
+        };
+        self.push(kind, len);
+    }
+
+    // a char literal like 'a' or '\n', otherwise a lifetime
+    fn rust_quote(&mut self) {
+        let rest = self.rest();
+        let mut chars = rest.char_indices().skip(1);
+        match (chars.next(), chars.next()) {
+            (Some((_, '\\')), _) => {
+                let len = quoted_len(rest, '\'', true, false);
+                self.push("string", len);
+            }
+            (Some(_), Some((i, '\''))) => self.push("string", i + 1),
+            _ => {
+                let len = rest[1..]
+                    .find(|c: char| !is_word_char(c))
+                    .map(|i| i + 1)
+                    .unwrap_or(rest.len());
+                self.push("symbol", len);
+            }
+        }
+    }
+
+    fn shell_variable(&mut self) {
+        let rest = self.rest();
+        let len = if rest[1..].starts_with('{') {
+            rest.find('}').map(|i| i + 1).unwrap_or(rest.len())
+        } else {
+            rest[1..]
+                .find(|c: char| !is_word_char(c))
+                .map(|i| i + 1)
+                .unwrap_or(rest.len())
+        };
+        if len > 1 {
+            self.push("variable", len);
+        } else {
+            self.skip_char();
+        }
+    }
+
+    fn word(&mut self) {
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| !(is_word_char(*c) || (*c == '$' && self.lang.syntax == Syntax::CLike)))
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        let word = &rest[..len];
+        let after = &rest[len..];
+        let lang = self.lang;
+
+        // sql is case insensitive
+        let lookup = if lang.syntax == Syntax::Sql {
+            word.to_lowercase()
+        } else {
+            word.to_string()
+        };
+        let kind = if has_word(lang.keywords, &lookup) {
+            Some("keyword")
+        } else if has_word(BOOLEANS, word) {
+            Some("boolean")
+        } else if has_word(lang.builtins, &lookup) {
+            Some("builtin")
+        } else if lang.syntax == Syntax::Rust && after.starts_with('!') && !after.starts_with("!=")
+        {
+            Some("macro property")
+        } else if lang.syntax == Syntax::Config && self.at_line_start() && {
+            let after = after.trim_start();
+            after.starts_with('=') || after.starts_with(':')
+        } {
+            Some("property")
+        } else if matches!(lang.syntax, Syntax::CLike | Syntax::Rust | Syntax::Python) {
+            if after.starts_with('(') {
+                Some("function")
+            } else if word.len() > 1
+                && word
+                    .chars()
+                    .all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+            {
+                Some("constant")
+            } else if word.starts_with(char::is_uppercase) {
+                Some("class-name")
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        match kind {
+            Some(kind) => self.push(kind, len),
+            None => self.pos += len,
+        }
+    }
+
+    fn markup(&mut self) {
+        while let Some(c) = self.peek() {
+            let rest = self.rest();
+            if let Some(len) = self.comment_len() {
+                self.push("comment", len);
+            } else if c == '<'
+                && rest[1..]
+                    .starts_with(|c: char| c.is_alphabetic() || c == '/' || c == '!' || c == '?')
+            {
+                self.tag();
+            } else if c == '&' {
+                let len = rest
+                    .find(|c: char| c == ';' || c.is_whitespace())
+                    .filter(|i| rest[*i..].starts_with(';'))
+                    .map(|i| i + 1);
+                match len {
+                    Some(len) => self.push("entity", len),
+                    None => self.skip_char(),
+                }
+            } else {
+                self.skip_char();
+            }
+        }
+    }
+
+    fn tag(&mut self) {
+        let open = 1 + self.rest()[1..]
+            .chars()
+            .take_while(|c| "/!?".contains(*c))
+            .count();
+        self.push("punctuation", open);
+        let name_len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || "-_:.".contains(c)))
+            .unwrap_or(self.rest().len());
+        self.push("tag", name_len);
+        while let Some(c) = self.peek() {
+            let rest = self.rest();
+            if c == '>' {
+                self.push("punctuation", 1);
+                return;
+            } else if rest.starts_with("/>") || rest.starts_with("?>") {
+                self.push("punctuation", 2);
+                return;
+            } else if c == '"' || c == '\'' {
+                let len = quoted_len(rest, c, false, true);
+                self.push("attr-value", len);
+            } else if c == '=' {
+                self.push("punctuation", 1);
+            } else if c.is_alphabetic() {
+                let len = rest
+                    .find(|c: char| !(c.is_alphanumeric() || "-_:.".contains(c)))
+                    .unwrap_or(rest.len());
+                self.push("attr-name", len);
+            } else if c == '<' {
+                // an unclosed tag, let the outer loop handle the new one
+                return;
+            } else {
+                self.skip_char();
+            }
+        }
+    }
+}
+
+fn has_word(list: &str, word: &str) -> bool {
+    list.split_whitespace().any(|w| w == word)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// length of a string starting with `quote`, including both quotes
+fn quoted_len(s: &str, quote: char, escapes: bool, multiline: bool) -> usize {
+    let mut chars = s.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        if escapes && c == '\\' {
+            chars.next();
+        } else if c == quote {
+            return i + c.len_utf8();
+        } else if c == '\n' && !multiline {
+            return i;
+        }
+    }
+    s.len()
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// renders a code block with line numbers and a copy button. `lang` is the info string
+/// from the opening fence; when it is missing or unknown the language is guessed.
+pub fn render_code_block(code: &str, lang: Option<&str>, copy_label: &str) -> String {
+    let code = code.strip_suffix('\n').unwrap_or(code);
+    let language = lang
+        .and_then(find_language)
+        .or_else(|| detect_language(code));
+    let tokens = match language {
+        Some(language) => tokenize(code, language),
+        None => vec![Token {
+            kind: None,
+            text: code,
+        }],
+    };
+
+    // tokens such as block comments may span several lines, but every line gets its own element
+    let mut lines = vec![String::new()];
+    for token in tokens {
+        for (idx, part) in token.text.split('\n').enumerate() {
+            if idx > 0 {
+                lines.push(String::new());
+            }
+            if part.is_empty() {
+                continue;
+            }
+            let line = lines.last_mut().expect("lines is never empty");
+            match token.kind {
+                Some(kind) => {
+                    line.push_str(&format!(
+                        "<span class=\"token {kind}\">{}</span>",
+                        escape_html(part)
+                    ));
+                }
+                None => line.push_str(&escape_html(part)),
+            }
+        }
+    }
+
+    let language_name = language
+        .map(|l| l.name.to_string())
+        .or_else(|| lang.map(escape_html))
+        .unwrap_or_default();
+    let language_class = language.map(|l| l.name).unwrap_or("none");
+    let mut html = format!(
+        "<div class=\"code-block\"><div class=\"code-block-header\"><span class=\"code-block-language\">{language_name}</span><button class=\"code-block-copy\" aria-label=\"copy-code-button\">{}</button></div><pre class=\"language-{language_class}\"><code class=\"language-{language_class}\">",
+        escape_html(copy_label)
+    );
+    let line_count = lines.len();
+    for (idx, line) in lines.into_iter().enumerate() {
+        html.push_str(&format!(
+            "<span class=\"code-line\" data-line=\"{}\">{line}</span>",
+            idx + 1
+        ));
+        if idx + 1 < line_count {
+            html.push('\n');
+        }
+    }
+    html.push_str("</code></pre></div>");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds<'a>(code: &'a str, lang: &str) -> Vec<(&'static str, &'a str)> {
+        tokenize(code, find_language(lang).unwrap())
+            .into_iter()
+            .filter_map(|t| t.kind.map(|k| (k, t.text)))
+            .collect()
+    }
+
+    #[test]
+    fn finds_languages_by_alias() {
+        assert_eq!(find_language("rs").unwrap().name, "rust");
+        assert_eq!(find_language(" JS ").unwrap().name, "javascript");
+        assert!(find_language("brainfuck").is_none());
+        assert!(find_language("").is_none());
+    }
+
+    #[test]
+    fn detects_common_languages() {
+        let detect = |code| detect_language(code).map(|l| l.name);
+        assert_eq!(
+            detect("fn main() {\n    let mut x = 5;\n    println!(\"{x}\");\n}"),
+            Some("rust")
+        );
+        assert_eq!(
+            detect("def foo(self):\n    print(\"hi\")\n"),
+            Some("python")
+        );
+        assert_eq!(
+            detect("const f = (a) => a + 1;\nconsole.log(f(2));"),
+            Some("javascript")
+        );
+        assert_eq!(detect("{\"a\": 1, \"b\": [true, null]}"), Some("json"));
+        assert_eq!(detect("#!/bin/sh\necho hi"), Some("bash"));
+        assert_eq!(detect("<div class=\"a\">b</div>"), Some("html"));
+        assert_eq!(detect("select id from users where id = 1"), Some("sql"));
+        assert_eq!(detect("just some words"), None);
+    }
+
+    #[test]
+    fn comment_markers_inside_strings_are_not_comments() {
+        let tokens = kinds("let s = \"// not a comment\"; // comment", "rust");
+        assert!(tokens.contains(&("string", "\"// not a comment\"")));
+        assert!(tokens.contains(&("comment", "// comment")));
+    }
+
+    #[test]
+    fn rust_raw_strings_and_lifetimes() {
+        let tokens = kinds(r###"let s: &'a str = r#"say "hi" // there"#;"###, "rust");
+        assert!(tokens.contains(&("symbol", "'a")));
+        assert!(tokens.contains(&("string", r###"r#"say "hi" // there"#"###)));
+        assert!(!tokens.iter().any(|(k, _)| *k == "comment"));
+
+        let tokens = kinds("let c = '\\''; let d = 'x';", "rust");
+        assert!(tokens.contains(&("string", "'\\''")));
+        assert!(tokens.contains(&("string", "'x'")));
+    }
+
+    #[test]
+    fn escaped_quotes_do_not_end_strings() {
+        let tokens = kinds(r#"x = "a \" b" + 'c'"#, "python");
+        assert!(tokens.contains(&("string", r#""a \" b""#)));
+        assert!(tokens.contains(&("string", "'c'")));
+    }
+
+    #[test]
+    fn unterminated_strings_and_comments_run_to_the_end() {
+        let tokens = kinds("/* never closed\nlet x = 1;", "rust");
+        assert_eq!(tokens, vec![("comment", "/* never closed\nlet x = 1;")]);
+
+        let tokens = kinds("x = \"open\ny = 2", "python");
+        assert!(tokens.contains(&("string", "\"open")));
+        assert!(tokens.contains(&("number", "2")));
+    }
+
+    #[test]
+    fn python_triple_quotes() {
+        let tokens = kinds("s = f'''a\n' b\n'''\nprint(s)", "python");
+        assert!(tokens.contains(&("string", "f'''a\n' b\n'''")));
+        assert!(tokens.contains(&("builtin", "print")));
+    }
+
+    #[test]
+    fn shell_hash_is_only_a_comment_after_whitespace() {
+        let tokens = kinds("echo $# ${HOME} a#b # real", "bash");
+        assert!(tokens.contains(&("variable", "${HOME}")));
+        assert!(tokens.contains(&("comment", "# real")));
+        assert_eq!(tokens.iter().filter(|(k, _)| *k == "comment").count(), 1);
+    }
+
+    #[test]
+    fn json_keys_are_properties() {
+        let tokens = kinds("{\"key\" : \"value\", \"n\": 1.5e3}", "json");
+        assert!(tokens.contains(&("property", "\"key\"")));
+        assert!(tokens.contains(&("string", "\"value\"")));
+        assert!(tokens.contains(&("number", "1.5e3")));
+    }
+
+    #[test]
+    fn markup_tags() {
+        let tokens = kinds("<a href=\"x\">t</a><!-- c -->", "html");
+        assert!(tokens.contains(&("tag", "a")));
+        assert!(tokens.contains(&("attr-name", "href")));
+        assert!(tokens.contains(&("attr-value", "\"x\"")));
+        assert!(tokens.contains(&("comment", "<!-- c -->")));
+    }
+
+    #[test]
+    fn rendered_block_escapes_html() {
+        let html = render_code_block("<script>alert('x')</script>\n", Some("html"), "Copy");
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;"));
+
+        let html = render_code_block("<img src=x onerror=alert(1)>", Some("\"><b>"), "Copy");
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn rendered_block_has_a_span_per_line() {
+        let html = render_code_block("/* a\nb */\nfn x() {}\n", Some("rust"), "Copy");
+        assert_eq!(html.matches("class=\"code-line\"").count(), 3);
+        assert!(html.contains("data-line=\"3\""));
+        assert_eq!(
+            html.matches("<span").count(),
+            html.matches("</span>").count()
+        );
+        assert!(html.contains("copy-code-button"));
+    }
+
+    #[test]
+    fn multibyte_text_does_not_panic() {
+        let code = "let é = \"😀\"; // ü\n'ß' 'ü";
+        for lang in LANGUAGES {
+            let text: String = tokenize(code, lang).into_iter().map(|t| t.text).collect();
+            assert_eq!(text, code);
+        }
+    }
+}
@@ -2,8 +2,10 @@ use std::path::PathBuf;
 use std::{collections::HashSet, str::FromStr};
 
 use common::language::{get_local_text, get_local_text_with_args};
+use common::state::emoji_packs::{CustomEmoji, EmojiPacks};
 use common::state::utils::{mention_replacement_pattern, parse_mentions};
 use common::state::{Action, Identity, State, ToastNotification};
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
 use common::warp_runner::{thumbnail_to_base64, MultiPassCmd, WarpCmd};
 use common::{state::pending_message::progress_file, WARP_CMD_CH};
 //use common::icons::outline::Shape as Icon;
@@ -13,7 +15,7 @@ use dioxus::prelude::*;
 use futures::StreamExt;
 use linkify::{LinkFinder, LinkKind};
 use once_cell::sync::Lazy;
-use pulldown_cmark::{CodeBlockKind, Options, Tag};
+use pulldown_cmark::{CodeBlockKind, Event, Options, Tag};
 use regex::{Captures, Regex, Replacer};
 use uuid::Uuid;
 use warp::error::Error;
@@ -32,8 +34,19 @@ use crate::{components::embeds::file_embed::FileEmbed, elements::textarea};
 
 use super::embeds::link_embed::EmbedLinks;
 
+pub mod highlight;
+
+// `||hidden text||`, revealed when clicked
+const SPOILER_DELIMITER: &str = "||";
+const SPOILER_TAG: &str = "<span class=\"spoiler\">";
+
 pub static MARKDOWN_PROCESSOR_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new("(^|\n)((?:&gt;(?: *&gt;)*)|(?: ))").unwrap());
+pub static TABLE_DELIMITER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^ *\|? *:?-+:? *(?:\| *:?-+:? *)*\|? *$").unwrap());
+pub static SHORTCODE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r":([a-z0-9_+\-]{2,32}):").unwrap());
+pub static HTML_TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>?").unwrap());
 pub static LINK_TAGS_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r#"(?i)\b((?:(?:https?://|www\.)[^\s()<>]+|\(([^\s()<>]+|(\([^\s()<>]+\)))*\))+(?:\(([^\s()<>]+|(\([^\s()<>]+\)))*\)|[^\s`!()\[\]{};:'".,<>?«»“”‘’]))"#).unwrap()
 });
@@ -392,6 +405,20 @@ pub fn ChatText<'a>(cx: Scope<'a, ChatMessageProps<'a>>) -> Element<'a> {
         _ => rsx!(e.as_str()),
     });
 
+    let should_embed_link = |link: &str| {
+        let host = match reqwest::Url::parse(link) {
+            Ok(url) => url.host_str().unwrap_or_default().to_string(),
+            Err(_) => return false,
+        };
+        cx.props
+            .state
+            .read()
+            .configuration
+            .privacy
+            .link_previews
+            .allows(&host, cx.props.remote)
+    };
+
     let text_type_class = if cx.props.pending {
         "pending-text"
     } else {
@@ -406,7 +433,7 @@ pub fn ChatText<'a>(cx: Scope<'a, ChatMessageProps<'a>>) -> Element<'a> {
                 aria_label: "message-text",
                 dangerous_inner_html: "{formatted_text}",
             },
-            links.first().and_then(|l| cx.render(rsx!(
+            links.first().filter(|l| should_embed_link(l)).and_then(|l| cx.render(rsx!(
                 EmbedLinks {
                     link: l.to_string(),
                     remote: cx.props.remote
@@ -431,6 +458,7 @@ pub fn format_text(
         .replace('\'', "&#x27;")
         .replace('\n', "&nbsp;&nbsp;\n");
     let mut text = safe_text;
+    let packs = data.map(|(state, _, _)| &state.ui.emoji_packs);
     // We want to do this after we escape html tags
     if let Some((state, chat, visual)) = data {
         if let Some(participants) = state
@@ -444,37 +472,76 @@ pub fn format_text(
         }
     }
     if should_markdown {
-        markdown(&text, emojis)
+        markdown(&text, emojis, packs)
     } else if emojis {
         let s = replace_emojis(text.trim());
         if is_only_emojis(&s) {
             format!("<span class=\"big-emoji\">{s}</span>")
         } else {
-            format!("<p>{s}</p>")
+            format!("<p>{}</p>", replace_custom_emojis(&s, packs))
         }
     } else {
-        format!("<p>{}</p>", text.trim())
+        format!("<p>{}</p>", replace_custom_emojis(text.trim(), packs))
     }
 }
 
-fn stack_processor(stack: &str, unescape_html: bool, emojis: bool) -> &str {
-    if unescape_html {
-        match stack {
-            "&quot;" => return "\"",
-            "&amp;" => return "&",
-            "&lt;" => return "<",
-            "&gt;" => return ">",
-            "&nbsp;" => return " ",
-            _ => {}
+fn custom_emoji_html(emoji: &CustomEmoji) -> String {
+    let class = if emoji.sticker {
+        "custom-sticker"
+    } else {
+        "custom-emoji"
+    };
+    let src = get_fixed_path_to_load_local_file(emoji.path.clone());
+    format!(
+        "<img class=\"{class}\" src=\"{}\" alt=\":{code}:\" title=\":{code}:\"/>",
+        highlight::escape_html(&src),
+        code = emoji.shortcode
+    )
+}
+
+// shortcodes without a matching pack are left as they are, the same text the sender typed
+fn replace_custom_emojis(text: &str, packs: Option<&EmojiPacks>) -> String {
+    match packs.filter(|p| !p.is_empty()) {
+        Some(packs) => SHORTCODE_REGEX
+            .replace_all(text, |caps: &Captures| match packs.find(&caps[1]) {
+                Some(emoji) => custom_emoji_html(emoji),
+                None => caps[0].to_string(),
+            })
+            .into_owned(),
+        None => text.to_string(),
+    }
+}
+
+fn push_custom_emojis<'a>(events: &mut Vec<Event<'a>>, text: &str, packs: Option<&EmojiPacks>) {
+    let mut last = 0;
+    if let Some(packs) = packs.filter(|p| !p.is_empty()) {
+        for caps in SHORTCODE_REGEX.captures_iter(text) {
+            let emoji = match packs.find(&caps[1]) {
+                Some(emoji) => emoji,
+                None => continue,
+            };
+            let found = caps.get(0).expect("capture 0 is the whole match");
+            if found.start() > last {
+                events.push(Event::Text(text[last..found.start()].to_string().into()));
+            }
+            events.push(Event::Html(custom_emoji_html(emoji).into()));
+            last = found.end();
         }
     }
+    if last < text.len() {
+        events.push(Event::Text(text[last..].to_string().into()));
+    }
+}
+
+fn stack_processor(stack: &str, emojis: bool) -> &str {
     if !emojis {
         return stack;
     }
     match stack {
-        "<3" => "❤️",
-        ">:)" => "😈",
-        ">:(" => "😠",
+        // format_text escapes the text before it gets here
+        "<3" | "&lt;3" => "❤️",
+        ">:)" | "&gt;:)" => "😈",
+        ">:(" | "&gt;:(" => "😠",
         ":)" => "🙂",
         ":(" => " 🙁",
         ":/" => "🫤",
@@ -513,7 +580,7 @@ where
 }
 
 pub fn replace_emojis(input: &str) -> String {
-    process_string(input, |s| stack_processor(s, false, true))
+    process_string(input, |s| stack_processor(s, true))
 }
 
 struct RegexReplacer;
@@ -529,7 +596,7 @@ impl Replacer for RegexReplacer {
     }
 }
 
-fn markdown(text: &str, emojis: bool) -> String {
+fn markdown(text: &str, emojis: bool, packs: Option<&EmojiPacks>) -> String {
     let txt = text.trim();
     if emojis {
         let r = replace_emojis(txt);
@@ -551,73 +618,253 @@ fn markdown(text: &str, emojis: bool) -> String {
 
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_TASKLISTS);
 
-    let text = MARKDOWN_PROCESSOR_REGEX.replace_all(txt, RegexReplacer);
+    let txt = prepare_blocks(txt);
+    let text = MARKDOWN_PROCESSOR_REGEX.replace_all(&txt, RegexReplacer);
 
-    let mut html_output = String::new();
+    // everything goes through a single html writer, it keeps track of the table head/body state
+    let mut events: Vec<Event> = Vec::new();
     let mut in_paragraph = false;
     let mut in_code_block = false;
+    let mut code_lang: Option<String> = None;
+    let mut code = String::new();
+    // nesting of emphasis/strong/strikethrough/links, a spoiler has to end on the level it started
+    let mut inline_depth = 0;
+    // index of the opening spoiler tag in `events` and the nesting it was opened at
+    let mut spoiler: Option<(usize, usize)> = None;
+    // whether the link that was started is rendered, unsafe urls only keep their text
+    let mut links: Vec<bool> = Vec::new();
 
     let parser = pulldown_cmark::Parser::new_ext(&text, options);
-    for event in parser {
+    for event in merge_text(parser) {
         match event {
-            pulldown_cmark::Event::Start(pulldown_cmark::Tag::CodeBlock(
-                CodeBlockKind::Indented,
-            )) => {
-                html_output.push_str("</p>\n<p> </p><p>");
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                events.push(Event::Html("</p>\n<p> </p><p>".into()));
             }
-            pulldown_cmark::Event::End(pulldown_cmark::Tag::CodeBlock(CodeBlockKind::Indented)) => {
+            Event::End(Tag::CodeBlock(CodeBlockKind::Indented)) => {}
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                in_code_block = true;
+                code.clear();
+                code_lang = info
+                    .replace("&nbsp;", " ")
+                    .split_whitespace()
+                    .next()
+                    .map(unescape_code);
             }
-            pulldown_cmark::Event::SoftBreak => {
+            Event::End(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => {
+                in_code_block = false;
+                events.push(Event::Html(
+                    highlight::render_code_block(
+                        &unescape_code(&code),
+                        code_lang.as_deref(),
+                        &get_local_text("uplink.copy"),
+                    )
+                    .into(),
+                ));
+            }
+            Event::Text(t) if in_code_block => {
+                code.push_str(&t);
+            }
+            Event::SoftBreak => {
+                drop_open_spoiler(&mut events, &mut spoiler);
                 if in_paragraph {
-                    html_output.push_str("</p>\n<p>");
+                    events.push(Event::Html("</p>\n<p>".into()));
                 }
             }
-            pulldown_cmark::Event::Start(Tag::Paragraph) => {
+            Event::Start(Tag::Paragraph) => {
+                drop_open_spoiler(&mut events, &mut spoiler);
                 in_paragraph = true;
-                html_output.push_str("<p>");
+                events.push(Event::Html("<p>".into()));
             }
-            pulldown_cmark::Event::End(Tag::Paragraph) => {
+            Event::End(Tag::Paragraph) => {
+                drop_open_spoiler(&mut events, &mut spoiler);
                 in_paragraph = false;
             }
-            pulldown_cmark::Event::Text(t) => {
-                let text = if emojis || in_code_block {
-                    process_string(&t, |s| stack_processor(s, in_code_block, emojis))
+            Event::Start(tag @ (Tag::Emphasis | Tag::Strong | Tag::Strikethrough)) => {
+                inline_depth += 1;
+                events.push(Event::Start(tag));
+            }
+            Event::End(tag @ (Tag::Emphasis | Tag::Strong | Tag::Strikethrough)) => {
+                if matches!(spoiler, Some((_, depth)) if depth == inline_depth) {
+                    drop_open_spoiler(&mut events, &mut spoiler);
+                }
+                inline_depth -= 1;
+                events.push(Event::End(tag));
+            }
+            // images would be loaded from anywhere, they are shown as links instead
+            Event::Start(Tag::Link(kind, url, title) | Tag::Image(kind, url, title)) => {
+                inline_depth += 1;
+                let safe = is_safe_url(&url);
+                if safe {
+                    events.push(Event::Start(Tag::Link(kind, url, title)));
+                }
+                links.push(safe);
+            }
+            Event::End(Tag::Link(kind, url, title) | Tag::Image(kind, url, title)) => {
+                if matches!(spoiler, Some((_, depth)) if depth == inline_depth) {
+                    drop_open_spoiler(&mut events, &mut spoiler);
+                }
+                inline_depth -= 1;
+                if links.pop().unwrap_or_default() {
+                    events.push(Event::End(Tag::Link(kind, url, title)));
+                }
+            }
+            Event::Start(tag) => {
+                drop_open_spoiler(&mut events, &mut spoiler);
+                events.push(Event::Start(tag));
+            }
+            Event::End(tag) => {
+                drop_open_spoiler(&mut events, &mut spoiler);
+                events.push(Event::End(tag));
+            }
+            Event::HardBreak => {
+                drop_open_spoiler(&mut events, &mut spoiler);
+                events.push(Event::HardBreak);
+            }
+            // user input is escaped before parsing, so the only html left should be mentions
+            Event::Html(html) => {
+                if is_mention_html(&html) {
+                    events.push(Event::Html(html));
+                } else {
+                    events.push(Event::Text(html));
+                }
+            }
+            Event::Text(t) => {
+                let text = if emojis {
+                    process_string(&t, |s| stack_processor(s, emojis))
                 } else {
                     t.to_string()
                 };
-                let txt: pulldown_cmark::CowStr<'_> = if in_paragraph {
-                    text.replace("\n\n", "<br/>").into()
+                let text = if in_paragraph {
+                    text.replace("\n\n", "<br/>")
                 } else {
-                    text.into()
+                    text
                 };
-                if in_code_block {
-                    html_output.push_str(&txt);
-                } else {
-                    pulldown_cmark::html::push_html(
-                        &mut html_output,
-                        std::iter::once(pulldown_cmark::Event::Text(txt)),
-                    );
-                }
-            }
-            event => {
-                match event {
-                    pulldown_cmark::Event::Start(pulldown_cmark::Tag::CodeBlock(_)) => {
-                        in_code_block = true;
-                    }
-                    pulldown_cmark::Event::End(pulldown_cmark::Tag::CodeBlock(_)) => {
-                        in_code_block = false;
+                for (i, part) in text.split(SPOILER_DELIMITER).enumerate() {
+                    if i > 0 {
+                        match spoiler {
+                            Some((_, depth)) if depth == inline_depth => {
+                                spoiler = None;
+                                events.push(Event::Html("</span>".into()));
+                            }
+                            Some(_) => events.push(Event::Text(SPOILER_DELIMITER.into())),
+                            None => {
+                                spoiler = Some((events.len(), inline_depth));
+                                events.push(Event::Html(SPOILER_TAG.into()));
+                            }
+                        }
                     }
-                    _ => {}
+                    push_custom_emojis(&mut events, part, packs);
                 }
-                pulldown_cmark::html::push_html(&mut html_output, std::iter::once(event))
             }
+            event => events.push(event),
         }
     }
+    drop_open_spoiler(&mut events, &mut spoiler);
+
+    let mut html_output = String::new();
+    pulldown_cmark::html::push_html(&mut html_output, events.into_iter());
     html_output.push('\n');
     html_output
 }
 
+// pulldown_cmark splits text around anything that could have been markup, spoilers need it in one piece
+fn merge_text<'a>(events: impl Iterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+    let mut merged: Vec<Event> = Vec::new();
+    for event in events {
+        match (merged.last_mut(), event) {
+            (Some(Event::Text(prev)), Event::Text(t)) => *prev = format!("{prev}{t}").into(),
+            (_, event) => merged.push(event),
+        }
+    }
+    merged
+}
+
+// a spoiler which isn't closed within the same block is shown as typed
+fn drop_open_spoiler(events: &mut [Event], spoiler: &mut Option<(usize, usize)>) {
+    if let Some((idx, _)) = spoiler.take() {
+        events[idx] = Event::Text(SPOILER_DELIMITER.into());
+    }
+}
+
+// format_text appends `&nbsp;&nbsp;` to every line so empty lines are kept. fences and table rows
+// don't parse with it, and an empty line after a quote or a table has to stay empty, otherwise
+// the block swallows everything that follows.
+fn prepare_blocks(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    // fence character and length of the code block we are in
+    let mut fence: Option<(char, usize)> = None;
+    let mut after_block = false;
+    let mut table = false;
+    for line in text.split('\n') {
+        let trimmed = line.trim_end_matches("&nbsp;");
+        let stripped = trimmed.trim_start_matches([' ', '\t']);
+        let fence_char = stripped.chars().next().filter(|c| *c == '`' || *c == '~');
+        let fence_len = fence_char
+            .map(|c| stripped.chars().take_while(|x| *x == c).count())
+            .unwrap_or_default();
+        let info = &stripped[fence_len..];
+        let line = match (fence, fence_char) {
+            (Some((c, len)), Some(found))
+                if c == found && fence_len >= len && info.trim().is_empty() =>
+            {
+                fence = None;
+                trimmed
+            }
+            (Some(_), _) => line,
+            (None, Some(c)) if fence_len >= 3 && !(c == '`' && info.contains('`')) => {
+                fence = Some((c, fence_len));
+                trimmed
+            }
+            (None, _) if after_block && trimmed.is_empty() => "",
+            (None, _) if trimmed.contains('|') => trimmed,
+            (None, _) => line,
+        };
+
+        // spoilers in a table would otherwise be read as empty cells
+        if fence.is_none() && TABLE_DELIMITER_REGEX.is_match(line) {
+            if let Some(header) = lines.last_mut().filter(|l| l.contains('|')) {
+                *header = header.replace(SPOILER_DELIMITER, "\\|\\|");
+                table = true;
+            }
+        } else if table && line.contains('|') {
+            lines.push(line.replace(SPOILER_DELIMITER, "\\|\\|"));
+            continue;
+        } else {
+            table = false;
+        }
+        after_block = fence.is_none() && (stripped.starts_with("&gt;") || line.contains('|'));
+        lines.push(line.to_string());
+    }
+    lines.join("\n")
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_ascii_lowercase();
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+fn is_mention_html(html: &str) -> bool {
+    HTML_TAG_REGEX.find_iter(html).all(|tag| {
+        tag.as_str() == "</div>" || tag.as_str().starts_with("<div class=\"message-user-tag ")
+    })
+}
+
+// reverts the escaping done by format_text, the highlighter escapes the code again
+fn unescape_code(code: &str) -> String {
+    code.replace("&nbsp;&nbsp;\n", "\n")
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
 #[derive(Display)]
 pub enum IdentityCmd {
     #[display(fmt = "GetIdentity")]
@@ -796,7 +1043,7 @@ pub fn IdentityMessage(cx: Scope<IdentityMessageProps>) -> Element {
                                 p {
                                     class: "text",
                                     aria_label: "profile-name-value",
-                                    format!("{}", identity.username())
+                                    format!("{}", identity.display_name())
                                 }
                             }
                             identity.status_message().and_then(|s|{
@@ -828,7 +1075,7 @@ pub fn IdentityMessage(cx: Scope<IdentityMessageProps>) -> Element {
                             text: if disabled {
                                 get_local_text("friends.already-friends")
                             } else {
-                                get_local_text_with_args("friends.add-name", vec![("name", identity.username())])
+                                get_local_text_with_args("friends.add-name", vec![("name", identity.display_name())])
                             },
                             appearance: crate::elements::Appearance::Primary
                         }
@@ -882,6 +1129,8 @@ fn is_only_emojis(input: &str) -> bool {
             || is_emoji_presentation(c)
             // some emojis are multiple emojis joined by this character
             || c == '\u{200d}'
+            // asks for the emoji presentation of the character before it, e.g. ❤️
+            || c == '\u{fe0f}'
             // failsafe
             || emojis::get(&String::from(c)).is_some()
         })
@@ -913,6 +1162,230 @@ mod tests2 {
     }
 }
 
+#[cfg(test)]
+mod code_block_tests {
+    use super::*;
+
+    fn code_of(html: &str) -> &str {
+        let start = html.find("<code").expect("no code block");
+        let end = html.find("</code>").expect("code block not closed");
+        &html[start..end]
+    }
+
+    #[test]
+    fn fence_is_closed_before_following_text() {
+        let html = format_text("```rust\nfn main() {}\n```\nafter", true, false, None);
+        assert!(code_of(&html).contains("<span class=\"token keyword\">fn</span>"));
+        assert!(!code_of(&html).contains("after"));
+        assert!(html.contains("after"));
+    }
+
+    #[test]
+    fn language_is_detected_without_info_string() {
+        let html = format_text(
+            "```\nfn main() {\n    let mut x = 1;\n    println!(\"{x}\");\n}\n```",
+            true,
+            false,
+            None,
+        );
+        assert!(html.contains("language-rust"));
+        assert!(html.contains("token macro"));
+    }
+
+    #[test]
+    fn html_in_code_is_escaped_once() {
+        let html = format_text(
+            "```\n<script>alert('x')</script>\na && b != \"c\"\n```",
+            true,
+            false,
+            None,
+        );
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("&amp;amp;"));
+        assert!(!html.contains("&amp;lt;"));
+        assert!(html.contains("&amp;&amp;"));
+    }
+
+    #[test]
+    fn longer_fence_contains_shorter_fence() {
+        let html = format_text("````md\n```\ninner\n```\n````", true, false, None);
+        let code = code_of(&html);
+        assert_eq!(code.matches("```").count(), 2);
+        assert!(code.contains("inner"));
+    }
+
+    #[test]
+    fn unclosed_fence_runs_to_the_end() {
+        let html = format_text("```py\nprint(1)\nx = 2", true, false, None);
+        assert!(html.contains("language-python"));
+        assert!(code_of(&html).contains("data-line=\"2\""));
+        assert!(html.contains("</code></pre></div>"));
+    }
+
+    #[test]
+    fn blank_lines_and_indentation_are_kept() {
+        let html = format_text("~~~\na\n\n    b\n~~~", true, false, None);
+        let code = code_of(&html);
+        assert!(code.contains("<span class=\"code-line\" data-line=\"2\"></span>"));
+        assert!(code.contains("    b"));
+        assert!(!code.contains("&nbsp;"));
+    }
+
+    #[test]
+    fn emojis_are_not_replaced_in_code() {
+        let html = format_text("```\n:) <3\n```", true, true, None);
+        assert!(code_of(&html).contains(":) &lt;3"));
+    }
+}
+
+#[cfg(test)]
+mod markdown_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    static TAG_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"<\s*/?\s*([a-zA-Z0-9]*)([^>]*)").unwrap());
+    static ATTRIBUTE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"\s([a-zA-Z-]+)=(?:"[^"]*"|'[^']*'|[^\s>]*)"#).unwrap());
+    static HREF_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"href="([^"]*)""#).unwrap());
+
+    const ALLOWED_TAGS: &str = "p br em strong del code pre span div button a h1 h2 h3 h4 h5 h6 blockquote ul ol li input hr table thead tbody tr th td";
+    const ALLOWED_ATTRIBUTES: &str =
+        "class href title aria-label data-line style type checked disabled start";
+
+    fn assert_sanitized(html: &str) {
+        // text is escaped, every `<` left starts a tag
+        for tag in TAG_REGEX.captures_iter(html) {
+            let name = tag[1].to_ascii_lowercase();
+            assert!(
+                ALLOWED_TAGS.split_whitespace().any(|t| t == name),
+                "<{name}> in {html}"
+            );
+            for attribute in ATTRIBUTE_REGEX.captures_iter(&tag[2]) {
+                let name = attribute[1].to_ascii_lowercase();
+                assert!(
+                    ALLOWED_ATTRIBUTES.split_whitespace().any(|a| a == name),
+                    "{name}= in {html}"
+                );
+            }
+        }
+        for href in HREF_REGEX.captures_iter(html) {
+            assert!(is_safe_url(&href[1]), "href {} in {html}", &href[1]);
+        }
+    }
+
+    #[test]
+    fn tables_are_rendered() {
+        let html = format_text("| a | b |\n|:--|--:|\n| 1 | 2 |", true, false, None);
+        assert!(html.contains("<th style=\"text-align: left\">a</th>"));
+        assert!(html.contains("<td style=\"text-align: right\">2</td>"));
+        assert!(!html.contains("&nbsp;"));
+    }
+
+    #[test]
+    fn spoilers_are_hidden() {
+        let html = format_text("a ||secret **stuff**|| b", true, false, None);
+        assert!(html.contains("<span class=\"spoiler\">secret <strong>stuff</strong></span>"));
+    }
+
+    #[test]
+    fn spoilers_in_tables_are_not_cells() {
+        let html = format_text("| a | b |\n|---|---|\n| 1 | ||x|| |", true, false, None);
+        assert!(html.contains("<td><span class=\"spoiler\">x</span></td>"));
+    }
+
+    #[test]
+    fn unbalanced_spoilers_are_kept_as_text() {
+        let html = format_text("a || b\nc ||**d||**", true, false, None);
+        assert!(!html.contains("spoiler"));
+        assert_eq!(html.matches("||").count(), 3);
+    }
+
+    #[test]
+    fn spoilers_are_not_parsed_in_code() {
+        let html = format_text("`a || b || c`", true, false, None);
+        assert!(!html.contains("spoiler"));
+    }
+
+    #[test]
+    fn nested_quotes_end_at_an_empty_line() {
+        let html = format_text("> a\n> > b\n\nreply", true, false, None);
+        assert_eq!(html.matches("<blockquote>").count(), 2);
+        let end = html.rfind("</blockquote>").unwrap();
+        assert!(html[end..].contains("reply"));
+    }
+
+    #[test]
+    fn task_lists_and_headers() {
+        let html = format_text("# Title\n- [ ] todo\n- [x] done", true, false, None);
+        assert!(html.contains("<h1>"));
+        assert!(html.contains("<input disabled=\"\" type=\"checkbox\"/>"));
+        assert!(html.contains("checked=\"\""));
+    }
+
+    #[test]
+    fn unsafe_links_only_keep_their_text() {
+        let html = format_text(
+            "[a](javascript:alert(1)) [b](https://x.com)",
+            true,
+            false,
+            None,
+        );
+        assert!(!html.contains("javascript"));
+        assert!(html.contains("<a href=\"https://x.com\">b</a>"));
+    }
+
+    #[test]
+    fn images_are_shown_as_links() {
+        let html = format_text("![cat](https://x.com/cat.png)", true, false, None);
+        assert!(!html.contains("<img"));
+        assert!(html.contains("<a href=\"https://x.com/cat.png\">cat</a>"));
+    }
+
+    proptest! {
+        #[test]
+        fn no_input_injects_html(text in "\\PC*") {
+            assert_sanitized(&format_text(&text, true, true, None));
+            assert_sanitized(&format_text(&text, false, false, None));
+        }
+
+        #[test]
+        fn no_markup_injects_html(text in r#"([<>"'&|`*_~\[\]()!#:/= -]|[a-z]+|javascript:|on[a-z]+=|\n|script|img|src)*"#) {
+            assert_sanitized(&format_text(&text, true, true, None));
+        }
+
+        #[test]
+        fn emojis_only_are_big(emojis in proptest::collection::vec(
+            // ascii emojis are only replaced when they stand alone
+            prop_oneof![
+                Just("😮"),
+                Just("👍🏾"),
+                Just("👨‍👩‍👦‍👦"),
+                Just("❤️"),
+                Just(" :) "),
+                Just(" <3 "),
+                Just(" >:) "),
+            ],
+            1..6,
+        ), separator in "( |)") {
+            let text = emojis.join(&separator);
+            let html = format_text(&text, true, true, None);
+            prop_assert!(html.starts_with("<span class=\"big-emoji\">"), "{}", html);
+        }
+
+        #[test]
+        fn text_is_never_big_emoji(
+            prefix in "[😮🙂 ]*",
+            word in "[a-zA-Z]+",
+            suffix in "[😮🙂 ]*",
+        ) {
+            let text = format!("{prefix}{word}{suffix}");
+            prop_assert!(!format_text(&text, true, true, None).contains("big-emoji"));
+            prop_assert!(!format_text(&text, false, true, None).contains("big-emoji"));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
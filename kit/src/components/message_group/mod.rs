@@ -10,6 +10,9 @@ pub struct Props<'a> {
     children: Element<'a>,
     user_image: Element<'a>,
     sender: String,
+    // the real username, shown on hover when `sender` is a nickname
+    #[props(default = "".to_owned())]
+    sender_hint: String,
     #[props(optional)]
     remote: Option<bool>,
     #[props(optional)]
@@ -41,6 +44,7 @@ pub fn MessageGroup<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 p {
                     class: "time-ago noselect defaultcursor",
                     aria_label: "time-ago",
+                    title: "{cx.props.sender_hint}",
                     "{cx.props.sender} - {time_ago}"
                 }
             }
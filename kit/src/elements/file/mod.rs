@@ -5,7 +5,6 @@ use dioxus::prelude::*;
 use dioxus_elements::input_data::keyboard_types::Code;
 
 use crate::elements::input::{Input, Options, Size, SpecialCharsAction, Validation};
-use dioxus_html::input_data::keyboard_types::Modifiers;
 
 use common::icons::Icon as IconElement;
 use common::{icons::outline::Shape as Icon, is_video};
@@ -79,7 +78,8 @@ pub fn File<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 },
                 aria_label: "{aria_label}",
                 onclick: move |mouse_event_data| {
-                    if mouse_event_data.modifiers() != Modifiers::CONTROL {
+                    // clicks with modifiers are used to select files
+                    if mouse_event_data.modifiers().is_empty() {
                         emit_press(&cx);
                     }
                 },
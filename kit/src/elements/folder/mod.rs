@@ -65,7 +65,12 @@ pub fn Folder<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 aria_label: "{aria_label}",
                 div {
                     class: "icon alignment",
-                    onclick: move |_| emit_press(&cx),
+                    onclick: move |mouse_event_data| {
+                        // clicks with modifiers are used to select folders
+                        if mouse_event_data.modifiers().is_empty() {
+                            emit_press(&cx);
+                        }
+                    },
                     IconElement {
                         icon: icon,
                     },
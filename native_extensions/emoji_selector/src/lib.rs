@@ -1,8 +1,14 @@
 use common::language::get_local_text;
+use common::utils::local_file_path::get_fixed_path_to_load_local_file;
 use common::warp_runner::{RayGunCmd, WarpCmd};
 use common::{
     icons::outline::Shape as Icon,
-    state::{scope_ids::ScopeIds, ui::EmojiDestination, Action, State},
+    state::{
+        emoji_packs::{CustomEmoji, EmojiPacks},
+        scope_ids::ScopeIds,
+        ui::EmojiDestination,
+        Action, State,
+    },
 };
 use dioxus::prelude::*;
 use emojis::{Group, UnicodeVersion};
@@ -45,9 +51,21 @@ fn is_supported(unicode_version: UnicodeVersion) -> bool {
     unicode_version.major() <= major as u32 && unicode_version.minor() <= minor as u32
 }
 
+// custom emojis can't be used as reactions, the shortcode would be all the other side sees
+const CUSTOM_GROUP: &str = "Custom";
+
+fn show_custom_emojis(state: &State) -> bool {
+    !state.ui.emoji_packs.is_empty()
+        && matches!(
+            state.ui.emoji_destination,
+            None | Some(EmojiDestination::Chatbar)
+        )
+}
+
 #[component(no_case_check)]
 fn build_nav(cx: Scope<'_>) -> Element<'_> {
-    let routes = vec![
+    let state = use_shared_state::<State>(cx)?;
+    let mut routes = vec![
         Route {
             to: "Smileys & Emotion",
             name: group_to_str(Group::SmileysAndEmotion),
@@ -121,6 +139,16 @@ fn build_nav(cx: Scope<'_>) -> Element<'_> {
             ..Route::default()
         },
     ];
+    if show_custom_emojis(&state.read()) {
+        routes.push(Route {
+            to: CUSTOM_GROUP,
+            name: get_local_text("messages.custom-emojis"),
+            icon: Icon::Sparkles,
+            with_badge: None,
+            loading: None,
+            ..Route::default()
+        });
+    }
 
     let scroll_script = r#"
         var emoji_scrolling_element = document.getElementById('scrolling');
@@ -153,6 +181,12 @@ fn render_selector<'a>(
     let state = use_shared_state::<State>(cx)?;
     let mouse_over_emoji_selector = use_ref(cx, || false);
     let emoji_suggestions = use_state(cx, Vec::new);
+    let custom_suggestions: &UseState<Vec<CustomEmoji>> = use_state(cx, Vec::new);
+    let custom_packs = if show_custom_emojis(&state.read()) {
+        state.read().ui.emoji_packs.clone()
+    } else {
+        EmojiPacks::default()
+    };
 
     let eval = use_eval(cx);
 
@@ -240,21 +274,28 @@ fn render_selector<'a>(
                                 let emoji = &emoji[0];
                                 if emoji.contains(char::is_whitespace) {
                                     emoji_suggestions.set(vec![]);
+                                    custom_suggestions.set(vec![]);
                                     return;
                                 }
                                 let alias = emoji.replace(':', "");
                                     emoji_suggestions
                                         .set(state.read().ui.emojis.get_matching_emoji(&alias, false));
+                                    if show_custom_emojis(&state.read()) {
+                                        custom_suggestions.set(state.read().ui.emoji_packs.get_matching(&alias, false).into_iter().cloned().collect());
+                                    }
+                            }
+                            None => {
+                                emoji_suggestions.set(vec![]);
+                                custom_suggestions.set(vec![]);
                             }
-                            None => emoji_suggestions.set(vec![]),
                         }
                     },
                     }
                 },
             div {
                 id: "scrolling",
-                padding_top: if !emoji_suggestions.is_empty() {"4px"} else {""},
-                if !emoji_suggestions.is_empty() {
+                padding_top: if !emoji_suggestions.is_empty() || !custom_suggestions.is_empty() {"4px"} else {""},
+                if !emoji_suggestions.is_empty() || !custom_suggestions.is_empty() {
                     rsx!(emoji_suggestions.iter().map(|(emoji, _)| {
                         rsx!(
                             div {
@@ -264,7 +305,11 @@ fn render_selector<'a>(
                                 emoji.as_str()
                             }
                         )
-                    }))
+                    }),
+                    custom_suggestions.iter().map(|emoji| rsx!(render_custom_emoji {
+                        emoji: emoji.clone(),
+                        onselect: move |code| select_emoji_to_send(cx.scope, state, code, ch),
+                    })))
                 } else {
                     rsx! (emojis::Group::iter().map(|group| {
                         let name: String = group_to_str(group);
@@ -290,7 +335,28 @@ fn render_selector<'a>(
                                 })
                             }
                         )
-                    }))
+                    }),
+                    (!custom_packs.is_empty()).then(|| rsx!(
+                        div {
+                            id: CUSTOM_GROUP,
+                            Label {
+                                text: get_local_text("messages.custom-emojis"),
+                            },
+                        }
+                        custom_packs.iter().map(|pack| rsx!(
+                            Label {
+                                text: pack.name.clone(),
+                            },
+                            div {
+                                class: "emojis-container",
+                                aria_label: "custom-emojis-container",
+                                pack.emojis.iter().map(|emoji| rsx!(render_custom_emoji {
+                                    emoji: emoji.clone(),
+                                    onselect: move |code| select_emoji_to_send(cx.scope, state, code, ch),
+                                }))
+                            }
+                        ))
+                    )))
                 }
             }
             nav
@@ -299,6 +365,28 @@ fn render_selector<'a>(
     ))
 }
 
+#[component(no_case_check)]
+fn render_custom_emoji<'a>(
+    cx: Scope<'a>,
+    emoji: CustomEmoji,
+    onselect: EventHandler<'a, String>,
+) -> Element<'a> {
+    let shortcode = format!(":{}:", emoji.shortcode);
+    let src = get_fixed_path_to_load_local_file(emoji.path.clone());
+    cx.render(rsx!(
+        div {
+            aria_label: "{shortcode}",
+            class: "emoji custom",
+            title: "{shortcode}",
+            onclick: move |_| onselect.call(shortcode.clone()),
+            img {
+                src: "{src}",
+                alt: "{shortcode}",
+            }
+        }
+    ))
+}
+
 // this avoid a BorrowMut error. needs an argument to make the curly braces syntax work
 #[component(no_case_check)]
 fn render_1(cx: Scope, _unused: bool) -> Element {
@@ -0,0 +1,107 @@
+// messages are rendered as plain html, so their buttons are handled here instead of inline handlers.
+// the voice message player is driven from here too, which saves a round trip to rust on every timeupdate.
+// media players fall back to the file icon when the webview can't decode the file
+const VOICE_MESSAGE_SPEEDS = [1, 1.5, 2]
+
+document.addEventListener("click", (event) => {
+  const copy = event.target.closest(".code-block-copy")
+  if (copy) {
+    const code = copy.closest(".code-block").querySelector("code")
+    navigator.clipboard.writeText(code.innerText)
+    return
+  }
+  const spoiler = event.target.closest(".spoiler")
+  if (spoiler) {
+    spoiler.classList.add("revealed")
+    return
+  }
+  const voiceMessage = event.target.closest(".voice-message")
+  if (voiceMessage) {
+    handleVoiceMessageClick(voiceMessage, event)
+  }
+})
+
+function handleVoiceMessageClick(voiceMessage, event) {
+  const audio = voiceMessage.querySelector("audio")
+  if (!audio) {
+    return
+  }
+  if (event.target.closest(".voice-message-play")) {
+    if (audio.paused) {
+      // only one voice message plays at a time
+      document.querySelectorAll(".voice-message audio").forEach((other) => {
+        if (other !== audio) {
+          other.pause()
+        }
+      })
+      audio.play()
+    } else {
+      audio.pause()
+    }
+    return
+  }
+  const speed = event.target.closest(".voice-message-speed")
+  if (speed) {
+    const next =
+      VOICE_MESSAGE_SPEEDS[
+        (VOICE_MESSAGE_SPEEDS.indexOf(audio.playbackRate) + 1) %
+          VOICE_MESSAGE_SPEEDS.length
+      ]
+    audio.playbackRate = next
+    speed.innerText = `${next}x`
+    return
+  }
+  const waveform = event.target.closest(".voice-message-waveform")
+  if (waveform && isFinite(audio.duration)) {
+    const rect = waveform.getBoundingClientRect()
+    const fraction = Math.min(Math.max((event.clientX - rect.left) / rect.width, 0), 1)
+    audio.currentTime = fraction * audio.duration
+    updateVoiceMessage(audio)
+  }
+}
+
+function formatVoiceMessageTime(secs) {
+  const total = Math.floor(secs)
+  return `${Math.floor(total / 60)}:${String(total % 60).padStart(2, "0")}`
+}
+
+function updateVoiceMessage(audio) {
+  const voiceMessage = audio.closest(".voice-message")
+  if (!voiceMessage) {
+    return
+  }
+  voiceMessage.classList.toggle("playing", !audio.paused && !audio.ended)
+  const duration = isFinite(audio.duration) ? audio.duration : 0
+  const progress = duration > 0 ? audio.currentTime / duration : 0
+  const bars = voiceMessage.querySelectorAll(".voice-message-waveform .bar")
+  const played = Math.round(progress * bars.length)
+  bars.forEach((bar, i) => bar.classList.toggle("played", i < played))
+  const time = voiceMessage.querySelector(".voice-message-time")
+  if (time && (audio.currentTime > 0 || !audio.paused)) {
+    time.innerText = formatVoiceMessageTime(audio.currentTime)
+  }
+}
+
+// media events don't bubble, so they are caught on the way down instead
+for (const name of ["play", "pause", "ended", "timeupdate", "seeked"]) {
+  document.addEventListener(
+    name,
+    (event) => {
+      if (event.target instanceof HTMLAudioElement && event.target.closest(".voice-message")) {
+        updateVoiceMessage(event.target)
+      }
+    },
+    true
+  )
+}
+
+// error events don't bubble either. the webview can't play every container (avi, wmv...)
+document.addEventListener(
+  "error",
+  (event) => {
+    if (event.target instanceof HTMLMediaElement) {
+      event.target.closest(".media-player")?.classList.add("failed")
+    }
+  },
+  true
+)
@@ -1 +0,0 @@
-Prism.languages.t4=Prism.languages["t4-cs"]=Prism.languages["t4-templating"].createT4("csharp");
\ No newline at end of file
@@ -1 +0,0 @@
-Prism.languages["t4-vb"]=Prism.languages["t4-templating"].createT4("vbnet");
\ No newline at end of file
@@ -110,6 +110,8 @@ pub fn create_uplink_dirs() {
     std::fs::create_dir_all(&STATIC_ARGS.themes_path).expect("error creating themes directory");
     std::fs::create_dir_all(&STATIC_ARGS.fonts_path)
         .expect("error creating fonts themes directory");
+    std::fs::create_dir_all(&STATIC_ARGS.emoji_packs_path)
+        .expect("error creating emoji packs directory");
     std::fs::create_dir_all(&STATIC_ARGS.temp_files)
         .expect("error creatings temporary files directory");
 }
@@ -2,14 +2,18 @@ use dioxus::prelude::*;
 
 use common::{
     icons::outline::Shape as Icon,
+    language::get_local_text,
     state::{utils::get_available_themes, Action, State},
 };
-use kit::elements::{
-    button::Button,
-    label::Label,
-    switch::Switch,
-    tooltip::{ArrowPosition, Tooltip},
-    Appearance,
+use kit::{
+    components::message::highlight::render_code_block,
+    elements::{
+        button::Button,
+        label::Label,
+        switch::Switch,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
 };
 
 use common::icons::Icon as IconElement;
@@ -52,7 +56,11 @@ pub fn DebugLogger(cx: Scope) -> Element {
 
     let state = use_shared_state::<State>(cx)?;
 
-    let state_json = state.read().get_json();
+    let state_json = render_code_block(
+        &state.read().get_json(),
+        Some("json"),
+        &get_local_text("uplink.copy"),
+    );
 
     cx.render(rsx!(
         style { STYLE }
@@ -231,20 +239,7 @@ pub fn DebugLogger(cx: Scope) -> Element {
                 Tab::State => rsx!(div {
                     aria_label: "debug-logger-body",
                     class: "body",
-                    pre {
-                        class: "language-js",
-
-                        code {
-                            "{state_json}"
-                        }
-                    }
-                    script {
-                        r#"
-                        (() => {{
-                            Prism.highlightAll();
-                        }})();
-                        "#
-                    }
+                    dangerous_inner_html: "{state_json}",
                 })
             }
         },
@@ -1,11 +1,24 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use common::{
-    icons, language::get_local_text_args_builder, state::State, warp_runner::thumbnail_to_base64,
-    MAX_FILES_PER_MESSAGE,
+    icons,
+    language::{get_local_text, get_local_text_args_builder, get_local_text_with_args},
+    state::State,
+    utils::{
+        content_hash::hash_file,
+        image_processing::{process_image, ProcessedImage},
+    },
+    warp_runner::{thumbnail_to_base64, ConstellationCmd, WarpCmd},
+    MAX_FILES_PER_MESSAGE, WARP_CMD_CH,
 };
 use dioxus::prelude::*;
-use kit::components::embeds::file_embed::FileEmbed;
+use futures::channel::oneshot;
+use humansize::{format_size, DECIMAL};
+use kit::{
+    components::embeds::file_embed::FileEmbed,
+    elements::{button::Button, Appearance},
+};
+use tracing::log;
 use uuid::Uuid;
 use warp::raygun::Location;
 
@@ -21,6 +34,84 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
     let state = use_shared_state::<State>(cx)?;
     let files_attached_to_send = cx.props.files_to_attach.clone();
     let files_attached_to_send3 = files_attached_to_send;
+    let processed_images: &UseRef<HashMap<PathBuf, ProcessedImage>> = use_ref(cx, HashMap::new);
+    let image_processing = state.read().configuration.files.image_processing;
+    // attached files which are already in storage, with their path there
+    let in_storage: &UseRef<Vec<(PathBuf, String)>> = use_ref(cx, Vec::new);
+
+    // images get processed when they are attached so that the size which will be sent can be shown.
+    // the result is cached, so sending them doesn't do the work twice
+    use_future(
+        cx,
+        (&cx.props.files_to_attach, &image_processing),
+        |(files, image_processing)| {
+            to_owned![processed_images];
+            async move {
+                let paths: Vec<PathBuf> = files
+                    .into_iter()
+                    .filter_map(|location| match location {
+                        Location::Disk { path } => Some(path),
+                        Location::Constellation { .. } => None,
+                    })
+                    .collect();
+                let res = tokio::task::spawn_blocking(move || {
+                    paths
+                        .into_iter()
+                        .filter_map(|path| {
+                            process_image(&path, &image_processing)
+                                .ok()
+                                .flatten()
+                                .map(|processed| (path, processed))
+                        })
+                        .collect::<HashMap<_, _>>()
+                })
+                .await;
+                if let Ok(res) = res {
+                    processed_images.set(res);
+                }
+            }
+        },
+    );
+
+    // a file which is already in storage can be sent from there instead of being uploaded again
+    use_future(cx, &cx.props.files_to_attach, |files| {
+        to_owned![in_storage];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            let mut found = vec![];
+            for location in files {
+                let path = match location {
+                    Location::Disk { path } => path,
+                    Location::Constellation { .. } => continue,
+                };
+                // the file is hashed here, so a large file doesn't hold up the warp runner
+                let path2 = path.clone();
+                let hash = match tokio::task::spawn_blocking(move || hash_file(&path2)).await {
+                    Ok(Ok(hash)) => hash,
+                    res => {
+                        log::warn!("failed to hash {path:?}: {res:?}");
+                        continue;
+                    }
+                };
+                let (tx, rx) = oneshot::channel();
+                if let Err(e) =
+                    warp_cmd_tx.send(WarpCmd::Constellation(ConstellationCmd::FindInStorage {
+                        hash,
+                        rsp: tx,
+                    }))
+                {
+                    log::error!("failed to send warp command: {}", e);
+                    return;
+                }
+                if let Ok(Ok(Some(existing))) = rx.await {
+                    found.push((path, existing));
+                }
+            }
+            in_storage.set(found);
+        }
+    });
+
+    let reuse_notices = in_storage.read().clone();
 
     // todo: pick an icon based on the file extension
     let attachments = cx.render(rsx!(cx.props.files_to_attach.iter().map(|location| {
@@ -57,9 +148,25 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
             ),
         };
 
+        // before -> after
+        let size_change = processed_images
+            .read()
+            .get(&filepath)
+            .map(|processed| {
+                get_local_text_with_args(
+                    "files.image-size-reduced",
+                    vec![
+                        ("before", format_size(processed.original_size, DECIMAL)),
+                        ("after", format_size(processed.size, DECIMAL)),
+                    ],
+                )
+            })
+            .unwrap_or_default();
+
         rsx!(FileEmbed {
             filename: filename,
             filepath: filepath,
+            kind: size_change,
             remote: false,
             is_from_attachments: true,
             thumbnail: thumbnail,
@@ -100,5 +207,37 @@ pub fn Attachments<'a>(cx: Scope<'a, AttachmentProps>) -> Element<'a> {
                 }
             attachments
             }
+            reuse_notices.into_iter().map(|(path, existing)| {
+                let filename = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                rsx!(div {
+                    class: "attachment-in-storage",
+                    aria_label: "attachment-in-storage",
+                    p {
+                        get_local_text_with_args(
+                            "files.attachment-in-storage",
+                            vec![("file", filename), ("path", existing.clone())],
+                        )
+                    },
+                    Button {
+                        text: get_local_text("files.use-from-storage"),
+                        aria_label: "use-from-storage".into(),
+                        appearance: Appearance::Secondary,
+                        onpress: move |_| {
+                            let attachments = cx
+                                .props
+                                .files_to_attach
+                                .iter()
+                                .map(|location| match location {
+                                    Location::Disk { path: disk } if *disk == path => Location::Constellation {
+                                        path: existing.clone(),
+                                    },
+                                    _ => location.clone(),
+                                })
+                                .collect();
+                            cx.props.on_remove.call(attachments);
+                        },
+                    },
+                })
+            })
     }))
 }
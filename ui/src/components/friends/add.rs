@@ -2,6 +2,7 @@ use arboard::Clipboard;
 use std::str::FromStr;
 
 use common::language::get_local_text;
+use common::utils::qr_code;
 use dioxus::prelude::*;
 use dioxus_html::input_data::keyboard_types::Modifiers;
 use futures::{channel::oneshot, StreamExt};
@@ -12,8 +13,10 @@ use kit::elements::{
     input::{Input, Options, SpecialCharsAction, Validation},
     label::Label,
     tooltip::Tooltip,
+    Appearance,
 };
 
+use rfd::FileDialog;
 use warp::crypto::DID;
 use warp::error::Error;
 
@@ -21,17 +24,59 @@ use tracing::log;
 
 use common::icons::outline::Shape as Icon;
 use common::{
-    state::{Action, Identity, State, ToastNotification},
+    state::{friends::MAX_REQUEST_NOTE_LENGTH, Action, Identity, State, ToastNotification},
     warp_runner::{MultiPassCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
 
+// fills the input with the text of a scanned QR code
+// decoding a large image takes a while, so it is done off the UI thread
+async fn fill_from_qr(
+    decode: impl FnOnce() -> anyhow::Result<String> + Send + 'static,
+    friend_input: UseState<String>,
+    friend_input_valid: UseState<bool>,
+    error_toast: UseState<Option<String>>,
+) {
+    let result = tokio::task::spawn_blocking(decode)
+        .await
+        .unwrap_or_else(|e| Err(e.into()));
+    match result {
+        Ok(text) => {
+            let text = text.trim().to_string();
+            friend_input_valid.set(DID::from_str(&text).is_ok());
+            friend_input.set(text);
+        }
+        Err(e) => {
+            log::warn!("failed to read QR code: {e}");
+            error_toast.set(Some(get_local_text("friends.qr-not-found")));
+        }
+    }
+}
+
+// the id, the note and the requests which were already sent
+type FriendRequest = (String, Option<String>, Vec<Identity>);
+
+fn note(request_note: &UseState<String>) -> Option<String> {
+    let note = request_note.get().trim();
+    (!note.is_empty()).then(|| note.to_string())
+}
+
+fn paste_qr_image() -> anyhow::Result<String> {
+    let image = Clipboard::new()?.get_image()?;
+    qr_code::decode_qr_rgba(
+        image.width as u32,
+        image.height as u32,
+        image.bytes.into_owned(),
+    )
+}
+
 #[allow(non_snake_case)]
 pub fn AddFriend(cx: Scope) -> Element {
     let state = use_shared_state::<State>(cx)?;
     let clear_input = use_state(cx, || false);
     let friend_input = use_state(cx, String::new);
     let friend_input_valid = use_state(cx, || false);
+    let request_note = use_state(cx, String::new);
     let request_sent = use_state(cx, || false);
     let error_toast: &UseState<Option<String>> = use_state(cx, || None);
     let add_in_progress = use_state(cx, || false);
@@ -50,6 +95,7 @@ pub fn AddFriend(cx: Scope) -> Element {
     if *clear_input.get() {
         friend_input.set(String::new());
         friend_input_valid.set(false);
+        request_note.set(String::new());
         clear_input.set(false);
     }
 
@@ -117,7 +163,7 @@ pub fn AddFriend(cx: Scope) -> Element {
         Icon::UserPlus
     };
 
-    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<(String, Vec<Identity>)>| {
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<FriendRequest>| {
         to_owned![
             request_sent,
             error_toast,
@@ -127,13 +173,14 @@ pub fn AddFriend(cx: Scope) -> Element {
         ];
         async move {
             let warp_cmd_tx = WARP_CMD_CH.tx.clone();
-            while let Some((id, outgoing_requests)) = rx.next().await {
+            while let Some((id, note, outgoing_requests)) = rx.next().await {
                 let id_to_compare = id.clone();
 
                 //tokio::time::sleep(std::time::Duration::from_millis(5000)).await;
                 let (tx, rx) = oneshot::channel::<Result<(), warp::error::Error>>();
                 if let Err(e) = warp_cmd_tx.send(WarpCmd::MultiPass(MultiPassCmd::RequestFriend {
                     id,
+                    note,
                     outgoing_requests,
                     rsp: tx,
                 })) {
@@ -227,7 +274,7 @@ pub fn AddFriend(cx: Scope) -> Element {
                                     }
                                 } else {
                                     add_in_progress.set(true);
-                                    ch.send((friend_input.get().to_string(), state.read().outgoing_fr_identities()));
+                                    ch.send((friend_input.get().to_string(), note(request_note), state.read().outgoing_fr_identities()));
                                 }
                             },
                             onchange: |(s, is_valid)| {
@@ -274,9 +321,36 @@ pub fn AddFriend(cx: Scope) -> Element {
                                     }
                                 };
                             }
+                        },
+                        ContextItem {
+                            icon: Icon::QrCode,
+                            aria_label: "friend-add-input-paste-qr".into(),
+                            text: get_local_text("friends.paste-qr"),
+                            onpress: move |_| {
+                                cx.spawn(fill_from_qr(paste_qr_image, friend_input.clone(), friend_input_valid.clone(), error_toast.clone()));
+                            }
                         }
                     ))
                 }
+                Button {
+                    icon: Icon::QrCode,
+                    appearance: Appearance::Secondary,
+                    disabled: *add_in_progress.current(),
+                    tooltip: cx.render(rsx!(Tooltip{
+                        text: get_local_text("friends.scan-qr")
+                    })),
+                    onpress: move |_| {
+                        let path = match FileDialog::new()
+                            .add_filter("image", &["png", "jpg", "jpeg", "webp", "bmp", "gif"])
+                            .pick_file()
+                        {
+                            Some(path) => path,
+                            None => return,
+                        };
+                        cx.spawn(fill_from_qr(move || qr_code::decode_qr_file(&path), friend_input.clone(), friend_input_valid.clone(), error_toast.clone()));
+                    },
+                    aria_label: "Scan QR Button".into()
+                },
                 Button {
                     icon: add_friend_icon,
                     text: add_friend_label.to_string(),
@@ -290,7 +364,7 @@ pub fn AddFriend(cx: Scope) -> Element {
                             }
                         } else {
                             add_in_progress.set(true);
-                            ch.send((friend_input.get().to_string(), state.read().outgoing_fr_identities()));
+                            ch.send((friend_input.get().to_string(), note(request_note), state.read().outgoing_fr_identities()));
                         }
                     },
                     aria_label: "Add Someone Button".into()
@@ -382,6 +456,28 @@ pub fn AddFriend(cx: Scope) -> Element {
                     }
                 }
             }
+            (!friend_input.get().is_empty()).then(|| rsx!(
+                div {
+                    class: "request-note-input",
+                    Input {
+                        placeholder: get_local_text("friends.request-note-placeholder"),
+                        icon: Icon::ChatBubbleBottomCenterText,
+                        value: request_note.get().clone(),
+                        max_length: MAX_REQUEST_NOTE_LENGTH as i32,
+                        disable_onblur: true,
+                        disabled: *add_in_progress.current(),
+                        reset: clear_input.clone(),
+                        options: Options {
+                            replace_spaces_underscore: false,
+                            ..Options::default()
+                        },
+                        onchange: |(s, _)| {
+                            request_note.set(s);
+                        },
+                        aria_label: "Request Note Input".into()
+                    }
+                }
+            ))
         }
     ))
 }
@@ -97,7 +97,8 @@ pub fn BlockedUsers(cx: Scope) -> Element {
                             },
                         )),
                         Friend {
-                            username: blocked_user.username(),
+                            username: blocked_user.display_name(),
+                            username_hint: blocked_user.username_hint(),
                             aria_label: blocked_user.username(),
                             suffix: did_suffix,
                             status_message: blocked_user.status_message().unwrap_or_default(),
@@ -0,0 +1,294 @@
+use common::icons::outline::Shape as Icon;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::{Action, Identity, State, ToastNotification};
+use common::utils::contact_list::{self, ContactEntry, ImportStatus};
+use common::warp_runner::{MultiPassCmd, WarpCmd};
+use common::WARP_CMD_CH;
+use dioxus::prelude::*;
+use futures::channel::oneshot;
+use kit::{
+    elements::{
+        button::Button,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+use rfd::FileDialog;
+use warp::crypto::DID;
+
+use crate::utils::async_task_queue::{ListenerAction, ACTION_LISTENER};
+
+fn status_label(status: ImportStatus) -> String {
+    match status {
+        ImportStatus::New => get_local_text("friends.import-new"),
+        ImportStatus::Friend => get_local_text("friends.import-friend"),
+        ImportStatus::Pending => get_local_text("friends.pending"),
+        ImportStatus::Blocked => get_local_text("friends.blocked"),
+        ImportStatus::Block => get_local_text("friends.import-block"),
+        ImportStatus::Own => get_local_text("friends.import-own"),
+    }
+}
+
+fn status_class(status: ImportStatus) -> &'static str {
+    match status {
+        ImportStatus::New | ImportStatus::Block => "import-status changed",
+        _ => "import-status",
+    }
+}
+
+fn error_toast(text: &str, error: anyhow::Error) -> ToastNotification {
+    ToastNotification::init(
+        get_local_text("warning-messages.error"),
+        get_local_text_with_args(text, vec![("error", error.to_string())]),
+        Some(Icon::ExclamationTriangle),
+        4,
+    )
+}
+
+// runs on its own, so that closing the friends page doesn't stop it
+async fn import_contacts(contacts: Vec<(DID, ImportStatus)>, outgoing_requests: Vec<Identity>) {
+    let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+    let mut requested = 0;
+    let mut blocked = 0;
+    let mut failed = 0;
+    for (did, status) in contacts {
+        let (tx, rx) = oneshot::channel();
+        let cmd = match status {
+            ImportStatus::New => MultiPassCmd::RequestFriend {
+                id: did.to_string(),
+                note: None,
+                outgoing_requests: outgoing_requests.clone(),
+                rsp: tx,
+            },
+            ImportStatus::Block => MultiPassCmd::Block { did, rsp: tx },
+            _ => continue,
+        };
+        if let Err(e) = warp_cmd_tx.send(WarpCmd::MultiPass(cmd)) {
+            log::error!("failed to send warp command: {}", e);
+            failed += 1;
+            continue;
+        }
+        match rx.await.expect("command canceled") {
+            Ok(_) if status == ImportStatus::New => requested += 1,
+            Ok(_) => blocked += 1,
+            Err(e) => {
+                log::error!("failed to import contact: {}", e);
+                failed += 1;
+            }
+        }
+    }
+
+    let content = if failed > 0 {
+        get_local_text_with_args("friends.contacts-import-errors", vec![("count", failed)])
+    } else {
+        get_local_text_with_args(
+            "friends.contacts-imported",
+            vec![("requests", requested), ("blocked", blocked)],
+        )
+    };
+    let _ = ACTION_LISTENER.tx.send(ListenerAction::ToastAction {
+        title: "".into(),
+        content,
+        icon: None,
+        timeout: 3,
+    });
+}
+
+/// Buttons to save the friends and blocked users to a file, and to add them again from one
+#[allow(non_snake_case)]
+pub fn ContactListButtons(cx: Scope) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let imported: &UseState<Option<Vec<ContactEntry>>> = use_state(cx, || None);
+    let nothing_to_export = {
+        let state = state.read();
+        state.friends().all.is_empty() && state.friends().blocked.is_empty()
+    };
+
+    cx.render(rsx!(
+        Button {
+            icon: Icon::ArrowUpTray,
+            appearance: Appearance::Secondary,
+            aria_label: "export-contacts-button".into(),
+            disabled: nothing_to_export,
+            tooltip: cx.render(rsx!(Tooltip {
+                arrow_position: ArrowPosition::Top,
+                text: get_local_text("friends.export-contacts"),
+            })),
+            onpress: move |_| {
+                let path = match FileDialog::new()
+                    .set_file_name("contacts.json")
+                    .add_filter("json", &["json"])
+                    .add_filter("csv", &["csv"])
+                    .save_file()
+                {
+                    Some(path) => path,
+                    None => return,
+                };
+                let entries = contact_list::collect(&state.read());
+                let toast = match contact_list::export(&path, &entries) {
+                    Ok(_) => ToastNotification::init(
+                        "".into(),
+                        get_local_text("friends.contacts-exported"),
+                        None,
+                        2,
+                    ),
+                    Err(e) => {
+                        log::error!("failed to export contacts: {e}");
+                        error_toast("friends.contacts-export-failed", e)
+                    }
+                };
+                state.write().mutate(Action::AddToastNotification(toast));
+            },
+        },
+        Button {
+            icon: Icon::ArrowDownTray,
+            appearance: Appearance::Secondary,
+            aria_label: "import-contacts-button".into(),
+            tooltip: cx.render(rsx!(Tooltip {
+                arrow_position: ArrowPosition::TopRight,
+                text: get_local_text("friends.import-contacts"),
+            })),
+            onpress: move |_| {
+                let path = match FileDialog::new()
+                    .add_filter("contacts", &["json", "csv"])
+                    .pick_file()
+                {
+                    Some(path) => path,
+                    None => return,
+                };
+                match contact_list::import(&path) {
+                    Ok(entries) => imported.set(Some(entries)),
+                    Err(e) => {
+                        log::error!("failed to import contacts: {e}");
+                        state
+                            .write()
+                            .mutate(Action::AddToastNotification(error_toast(
+                                "friends.contacts-import-failed",
+                                e,
+                            )));
+                    }
+                }
+            },
+        },
+        imported.get().as_ref().map(|entries| rsx!(ImportContacts {
+            entries: entries.clone(),
+            on_dismiss: move |_| imported.set(None),
+        }))
+    ))
+}
+
+#[derive(Props)]
+pub struct ImportProps<'a> {
+    entries: Vec<ContactEntry>,
+    on_dismiss: EventHandler<'a, ()>,
+}
+
+/// Shows who is in an imported contact list and what importing them does
+#[allow(non_snake_case)]
+pub fn ImportContacts<'a>(cx: Scope<'a, ImportProps<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let preview: Vec<(ContactEntry, ImportStatus)> = cx
+        .props
+        .entries
+        .iter()
+        .map(|e| (e.clone(), contact_list::import_status(&state.read(), e)))
+        .collect();
+    let count = |status: ImportStatus| preview.iter().filter(|(_, s)| *s == status).count();
+    let summary = get_local_text_with_args(
+        "friends.import-summary",
+        vec![
+            ("new", count(ImportStatus::New)),
+            ("friends", count(ImportStatus::Friend)),
+            ("pending", count(ImportStatus::Pending)),
+            (
+                "blocked",
+                count(ImportStatus::Blocked) + count(ImportStatus::Block),
+            ),
+        ],
+    );
+    let nothing_to_import = count(ImportStatus::New) + count(ImportStatus::Block) == 0;
+    let to_import = preview.clone();
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        with_title: get_local_text("friends.import-contacts"),
+        onclose: move |_| cx.props.on_dismiss.call(()),
+        div {
+            class: "import-contacts",
+            aria_label: "import-contacts",
+            p {
+                class: "import-summary",
+                "{summary}"
+            },
+            div {
+                class: "import-contacts-list",
+                preview.iter().map(|(entry, status)| {
+                    let did = entry.did.to_string();
+                    let short_did = &did[did.len().saturating_sub(8)..];
+                    let name = match (&entry.nickname, entry.username.is_empty()) {
+                        (Some(nickname), false) => format!("{nickname} ({})", entry.username),
+                        (Some(nickname), true) => nickname.clone(),
+                        (None, _) => entry.username.clone(),
+                    };
+                    let label = status_label(*status);
+                    let class = status_class(*status);
+                    rsx!(div {
+                        key: "{did}-import",
+                        class: "import-contact",
+                        aria_label: "import-contact",
+                        title: "{did}",
+                        div {
+                            class: "import-contact-name",
+                            p { "{name}" },
+                            p { class: "import-contact-did", "#{short_did}" },
+                        },
+                        span {
+                            class: "{class}",
+                            "{label}"
+                        }
+                    })
+                })
+            },
+            div {
+                class: "import-contacts-buttons",
+                Button {
+                    text: get_local_text("uplink.cancel"),
+                    aria_label: "import-contacts-cancel".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| cx.props.on_dismiss.call(()),
+                },
+                Button {
+                    text: get_local_text("friends.import"),
+                    icon: Icon::UserPlus,
+                    aria_label: "import-contacts-confirm".into(),
+                    appearance: Appearance::Primary,
+                    disabled: nothing_to_import,
+                    onpress: move |_| {
+                        // what the user kept about someone only fills in what is missing here
+                        for (entry, status) in to_import.iter() {
+                            if *status == ImportStatus::Own {
+                                continue;
+                            }
+                            let contact = state.read().contacts.get(&entry.did).cloned().unwrap_or_default();
+                            if let (None, Some(nickname)) = (&contact.nickname, &entry.nickname) {
+                                state.write().mutate(Action::SetNickname(&entry.did, nickname.clone()));
+                            }
+                            if contact.note.is_empty() && !entry.note.is_empty() {
+                                state.write().mutate(Action::SetContactNote(&entry.did, entry.note.clone()));
+                            }
+                            for tag in entry.tags.iter() {
+                                state.write().mutate(Action::AddContactTag(&entry.did, tag.clone()));
+                            }
+                        }
+                        let contacts: Vec<(DID, ImportStatus)> = to_import.iter().map(|(e, s)| (e.did.clone(), *s)).collect();
+                        let outgoing_requests = state.read().outgoing_fr_identities();
+                        tokio::spawn(import_contacts(contacts, outgoing_requests));
+                        cx.props.on_dismiss.call(());
+                    },
+                },
+            }
+        }
+    }))
+}
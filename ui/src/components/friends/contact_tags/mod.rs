@@ -0,0 +1,69 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::get_local_text;
+use common::state::{contacts::MAX_TAG_LENGTH, Action, State};
+use dioxus::prelude::*;
+use dioxus_html::input_data::keyboard_types::Code;
+use kit::elements::input::{Input, Options};
+use warp::crypto::DID;
+
+#[derive(Props, PartialEq)]
+pub struct Props {
+    did: DID,
+}
+
+/// The tags put on someone, which can be removed, and an input to add more
+#[allow(non_snake_case)]
+pub fn ContactTags(cx: Scope<Props>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let tags: Vec<String> = state
+        .read()
+        .contacts
+        .get(&cx.props.did)
+        .map(|c| c.tags.iter().cloned().collect())
+        .unwrap_or_default();
+
+    cx.render(rsx!(
+        div {
+            class: "contact-tags",
+            aria_label: "contact-tags",
+            (!tags.is_empty()).then(|| rsx!(div {
+                class: "tag-chips",
+                tags.into_iter().map(|tag| {
+                    let tag2 = tag.clone();
+                    rsx!(div {
+                        key: "{tag}-contact-tag",
+                        class: "tag-chip",
+                        aria_label: "contact-tag",
+                        span { "{tag}" },
+                        span {
+                            class: "tag-chip-remove",
+                            aria_label: "contact-tag-remove",
+                            title: get_local_text("friends.remove-tag"),
+                            onclick: move |_| {
+                                state.write().mutate(Action::RemoveContactTag(&cx.props.did, tag2.clone()));
+                            },
+                            IconElement {
+                                icon: Icon::XMark,
+                            }
+                        }
+                    })
+                })
+            })),
+            Input {
+                aria_label: "contact-tag-input".into(),
+                placeholder: get_local_text("friends.add-tag-placeholder"),
+                icon: Icon::Tag,
+                max_length: MAX_TAG_LENGTH as i32,
+                disable_onblur: true,
+                options: Options {
+                    with_label: Some(get_local_text("friends.tags")),
+                    ..Default::default()
+                },
+                onreturn: move |(val, _, _): (String, bool, Code)| {
+                    state.write().mutate(Action::AddContactTag(&cx.props.did, val));
+                }
+            }
+        }
+    ))
+}
@@ -14,7 +14,8 @@ use kit::{
 };
 
 use common::icons::outline::Shape as Icon;
-use common::language::get_local_text;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
 use common::state::State;
 use warp::multipass::identity::Relationship;
 
@@ -22,12 +23,24 @@ use warp::multipass::identity::Relationship;
 pub struct Props<'a> {
     // The username of the friend request sender
     username: String,
+    // The real username, shown on hover when `username` is a nickname
+    #[props(default = "".to_owned())]
+    username_hint: String,
     // A suffix to the username, typically a unique identifier
     suffix: String,
     // Users relationship
     relationship: Relationship,
     // Status message from friend
     status_message: String,
+    // The names of the group chats shared with the user
+    #[props(default)]
+    mutual_groups: Vec<String>,
+    // The user compared safety numbers with them
+    #[props(default)]
+    verified: bool,
+    // Their safety number is not the one which was verified
+    #[props(default)]
+    key_changed: bool,
     // The user image element to display
     user_image: Element<'a>,
     // An optional event handler for the "onchat" event
@@ -49,6 +62,10 @@ pub fn Friend<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let relationship = cx.props.relationship;
     let status_message = cx.props.status_message.clone();
     let aria_label = cx.props.aria_label.clone().unwrap_or_default();
+    let mutual_groups = get_local_text_with_args(
+        "friends.mutual-groups",
+        vec![("names", cx.props.mutual_groups.join(", "))],
+    );
 
     let any_button_disabled = cx.props.accept_button_disabled.unwrap_or(false)
         || cx.props.block_button_disabled.unwrap_or(false)
@@ -64,13 +81,35 @@ pub fn Friend<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 aria_label: "Friend Info",
                 p {
                     aria_label: "friend-username",
+                    title: "{cx.props.username_hint}",
                     "{cx.props.username}",
                     (!state.read().ui.is_minimal_view()).then(|| rsx!(
                         span {
                             "#{cx.props.suffix}"
                         }
                     )),
+                    cx.props.verified.then(|| rsx!(
+                        span {
+                            class: "verified-badge",
+                            aria_label: "verified-badge",
+                            title: get_local_text("friends.verified"),
+                            IconElement {
+                                icon: Icon::ShieldCheck,
+                            }
+                        }
+                    )),
                 },
+                cx.props.key_changed.then(|| rsx!(
+                    p {
+                        class: "verification-warning",
+                        aria_label: "verification-warning",
+                        title: get_local_text("friends.key-changed-description"),
+                        IconElement {
+                            icon: Icon::ShieldExclamation,
+                        },
+                        get_local_text("friends.key-changed")
+                    }
+                )),
                 if relationship.friends() {
                    rsx!(p {
                         class: "status-message",
@@ -90,7 +129,15 @@ pub fn Friend<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                                 "friends.requested"
                             })
                     })
-                }
+                },
+                (!cx.props.mutual_groups.is_empty()).then(|| rsx!(
+                    p {
+                        class: "mutual-groups",
+                        aria_label: "mutual-groups",
+                        title: "{mutual_groups}",
+                        "{mutual_groups}"
+                    }
+                )),
             },
             div {
                 class: "request-controls",
@@ -16,6 +16,7 @@ use kit::{
         checkbox::Checkbox,
         input::{Input, Options},
         label::Label,
+        select::Select,
         Appearance,
     },
     layout::modal::Modal,
@@ -24,7 +25,11 @@ use kit::{
 use common::{get_images_dir, icons::outline::Shape as Icon, language::get_local_text_with_args};
 use common::{language::get_local_text, state::Identity};
 use common::{
-    state::{Action, Chat, State},
+    state::{
+        action::ConfigAction,
+        contacts::{FriendSort, VerificationStatus},
+        Action, Chat, State,
+    },
     warp_runner::{MultiPassCmd, RayGunCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
@@ -38,7 +43,12 @@ use warp::{
 use tracing::log;
 
 use crate::{
-    components::friends::friend::{Friend, SkeletalFriend},
+    components::friends::{
+        contact_list::ContactListButtons,
+        contact_tags::ContactTags,
+        friend::{Friend, SkeletalFriend},
+        verify_identity::VerifyIdentity,
+    },
     utils::build_participants,
     UplinkRoute,
 };
@@ -46,6 +56,8 @@ use crate::{
 #[allow(clippy::large_enum_variant)]
 enum ChanCmd {
     CreateConversation { recipient: DID, chat: Option<Chat> },
+    // with everyone who has the tag, named after it
+    CreateTagGroup { recipients: Vec<DID>, tag: String },
     RemoveFriend(DID),
     BlockFriend(DID),
     // will remove direct conversations involving the friend
@@ -63,18 +75,76 @@ pub fn Friends(cx: Scope) -> Element {
     }
     let filter = friend_filter.get().to_lowercase();
     let friends_all = state.read().friend_identities();
+    let all_tags = state.read().contacts.tags();
+    let tag_filter: &UseState<Option<String>> = use_state(cx, || None);
+    // the tag may have been removed from everyone since it was picked
+    let tag = tag_filter.get().clone().filter(|t| all_tags.contains(t));
+    let tagged_friends: Vec<DID> = match &tag {
+        Some(t) => friends_all
+            .iter()
+            .map(|f| f.did_key())
+            .filter(|did| state.read().contacts.has_tag(did, t))
+            .collect(),
+        None => vec![],
+    };
     let friends_list = HashMap::from_iter(
         friends_all
             .iter()
-            .filter(|id| filter.is_empty() || id.username().to_lowercase().starts_with(&filter))
+            .filter(|id| filter.is_empty() || id.name_starts_with(&filter))
+            .filter(|id| tag.is_none() || tagged_friends.contains(&id.did_key()))
             .map(|id| (id.did_key(), id.clone())),
     );
+    let sort = state.read().configuration.general.friend_sort;
+    let sort_options = [
+        (
+            FriendSort::Alphabetical,
+            get_local_text("friends.sort-alphabetical"),
+        ),
+        (
+            FriendSort::OnlineFirst,
+            get_local_text("friends.sort-online-first"),
+        ),
+        (
+            FriendSort::RecentlyChatted,
+            get_local_text("friends.sort-recently-chatted"),
+        ),
+    ];
+    let sort_label = sort_options
+        .iter()
+        .find(|(s, _)| *s == sort)
+        .map(|(_, label)| label.clone())
+        .unwrap_or_default();
+    let tags_did: &UseState<Option<DID>> = use_state(cx, || None);
+    let verify_did: &UseState<Option<DID>> = use_state(cx, || None);
     let block_in_progress: &UseState<HashSet<DID>> = use_state(cx, HashSet::new);
     let remove_in_progress: &UseState<HashSet<DID>> = use_state(cx, HashSet::new);
 
     let share_did = use_state(cx, || None);
 
-    let friends = State::get_friends_by_first_letter(friends_list);
+    let friends: Vec<(String, Vec<Identity>)> = match sort {
+        FriendSort::Alphabetical => State::get_friends_by_first_letter(friends_list)
+            .into_iter()
+            .map(|(letter, list)| (letter.to_string(), list))
+            .collect(),
+        FriendSort::OnlineFirst => {
+            let (online, offline) = State::get_friends_by_status(friends_list);
+            [
+                (get_local_text("friends.online"), online),
+                (get_local_text("friends.offline"), offline),
+            ]
+            .into_iter()
+            .filter(|(_, list)| !list.is_empty())
+            .collect()
+        }
+        FriendSort::RecentlyChatted => {
+            let list = state.read().get_friends_by_recent_chat(friends_list);
+            if list.is_empty() {
+                vec![]
+            } else {
+                vec![(get_local_text("friends.recent"), list)]
+            }
+        }
+    };
 
     let router = use_navigator(cx);
 
@@ -123,6 +193,25 @@ pub fn Friends(cx: Scope) -> Element {
                         };
                         chat_with.set(Some(chat));
                     }
+                    ChanCmd::CreateTagGroup { recipients, tag } => {
+                        let (tx, rx) = oneshot::channel();
+                        if let Err(e) =
+                            warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::CreateGroupConversation {
+                                recipients,
+                                group_name: Some(tag),
+                                rsp: tx,
+                            }))
+                        {
+                            log::error!("failed to send warp command: {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(c) => chat_with.set(Some(c)),
+                            Err(e) => log::error!("failed to create conversation: {}", e),
+                        }
+                    }
                     ChanCmd::RemoveFriend(did) => {
                         let (tx, rx) = oneshot::channel::<Result<(), warp::error::Error>>();
                         if let Err(e) =
@@ -220,6 +309,60 @@ pub fn Friends(cx: Scope) -> Element {
                     aria_label: "Search Friend".into()
                 })
             }),
+            div {
+                class: "friends-list-controls",
+                (!friends_all.is_empty()).then(|| rsx!(Select {
+                    initial_value: sort_label,
+                    options: sort_options.iter().map(|(_, label)| label.clone()).collect(),
+                    onselect: move |label: String| {
+                        if let Some((sort, _)) = sort_options.iter().find(|(_, l)| *l == label) {
+                            state.write().mutate(Action::Config(ConfigAction::SetFriendSort(*sort)));
+                        }
+                    }
+                })),
+                tag.as_ref().map(|t| {
+                    let recipients = tagged_friends.clone();
+                    let t = t.clone();
+                    rsx!(Button {
+                        text: get_local_text("friends.start-tag-group"),
+                        icon: Icon::UserGroup,
+                        aria_label: "friends-start-tag-group".into(),
+                        appearance: Appearance::Secondary,
+                        // a group needs at least two other people
+                        disabled: recipients.len() < 2,
+                        onpress: move |_| {
+                            ch.send(ChanCmd::CreateTagGroup { recipients: recipients.clone(), tag: t.clone() });
+                        },
+                    })
+                }),
+                div {
+                    class: "friends-list-file-buttons",
+                    ContactListButtons {},
+                }
+            },
+            (!all_tags.is_empty()).then(|| rsx!(
+                div {
+                    class: "tag-chips",
+                    aria_label: "friends-tag-filter",
+                    div {
+                        class: format_args!("tag-chip {}", if tag.is_none() { "active" } else { "" }),
+                        aria_label: "friends-tag-all",
+                        onclick: move |_| tag_filter.set(None),
+                        get_local_text("friends.all-tags")
+                    },
+                    all_tags.iter().cloned().map(|t| {
+                        let active = tag.as_ref() == Some(&t);
+                        let t2 = t.clone();
+                        rsx!(div {
+                            key: "{t}-tag-filter",
+                            class: format_args!("tag-chip {}", if active { "active" } else { "" }),
+                            aria_label: "friends-tag",
+                            onclick: move |_| tag_filter.set(if active { None } else { Some(t2.clone()) }),
+                            "{t}"
+                        })
+                    })
+                }
+            )),
             (friends.is_empty()).then(|| rsx! (
                 div {
                     class: "empty-friends-list",
@@ -233,14 +376,29 @@ pub fn Friends(cx: Scope) -> Element {
                     did: share_did.clone()
                 })
             }),
+            tags_did.get().as_ref().map(|did| rsx!(Modal {
+                open: true,
+                transparent: false,
+                with_title: get_local_text("friends.edit-tags"),
+                onclose: move |_| tags_did.set(None),
+                div {
+                    class: "modal-contact-tags",
+                    ContactTags {
+                        did: did.clone(),
+                    }
+                }
+            })),
+            verify_did.get().as_ref().map(|did| rsx!(VerifyIdentity {
+                did: did.clone(),
+                on_dismiss: move |_| verify_did.set(None),
+            })),
             friends.into_iter().map(|(letter, sorted_friends)| {
-                let group_letter = letter.to_string();
                 rsx!(
                     div {
-                        key: "friend-group-{group_letter}",
+                        key: "friend-group-{letter}",
                         Label {
-                            text: letter.into(),
-                            aria_label: letter.into()
+                            text: letter.clone(),
+                            aria_label: letter.clone()
                         },
                         sorted_friends.into_iter().map(|friend| {
                             let did = friend.did_key();
@@ -256,6 +414,9 @@ pub fn Friends(cx: Scope) -> Element {
                             let block_friend_2 = friend.clone();
                             let context_friend = friend.clone();
                             let share_friend = friend.clone();
+                            let tags_friend = friend.did_key();
+                            let verify_friend = friend.did_key();
+                            let verification = state.read().verification_status(&did);
                             let mut relationship = Relationship::default();
                             relationship.set_friends(true);
                             let platform = friend.platform().into();
@@ -282,6 +443,22 @@ pub fn Friends(cx: Scope) -> Element {
                                                 share_did.set(Some(share_friend.did_key()));
                                             }
                                         },
+                                        ContextItem {
+                                            icon: Icon::Tag,
+                                            text: get_local_text("friends.edit-tags"),
+                                            aria_label: "friends-edit-tags".into(),
+                                            onpress: move |_| {
+                                                tags_did.set(Some(tags_friend.clone()));
+                                            }
+                                        },
+                                        ContextItem {
+                                            icon: Icon::ShieldCheck,
+                                            text: get_local_text("friends.verify"),
+                                            aria_label: "friends-verify".into(),
+                                            onpress: move |_| {
+                                                verify_did.set(Some(verify_friend.clone()));
+                                            }
+                                        },
                                         if let Some(f) = favorite {
                                             rsx!(ContextItem {
                                                 icon: if f {Icon::HeartSlash} else {Icon::Heart},
@@ -333,11 +510,14 @@ pub fn Friends(cx: Scope) -> Element {
                                         },
                                     )),
                                     Friend {
-                                        username: friend.username(),
+                                        username: friend.display_name(),
+                                        username_hint: friend.username_hint(),
                                         aria_label: friend.username(),
                                         suffix: did_suffix,
                                         status_message: friend.status_message().unwrap_or_default(),
                                         relationship: relationship,
+                                        verified: verification == VerificationStatus::Verified,
+                                        key_changed: verification == VerificationStatus::Changed,
                                         block_button_disabled: block_in_progress.current().contains(&friend.did_key()),
                                         remove_button_disabled: remove_in_progress.current().contains(&friend.did_key()),
                                         user_image: cx.render(rsx! (
@@ -478,7 +658,7 @@ pub fn ShareFriendsModal(cx: Scope<FriendProps>) -> Element {
                     _ => match &unwrapped_message.attachments()[..] {
                         [] => get_local_text("sidebar.chat-new"),
                         [ file ] => file.name(),
-                        _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.username()) {
+                        _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.display_name()) {
                             Some(name) => get_local_text_with_args("sidebar.subtext", vec![("user", name)]),
                             None => {
                                 log::error!("error calculating subtext for sidebar chat");
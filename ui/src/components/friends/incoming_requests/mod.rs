@@ -5,7 +5,7 @@ use crate::components::friends::friend::Friend;
 use common::icons::outline::Shape as Icon;
 use common::language::get_local_text;
 use common::{
-    state::{Action, State},
+    state::{contacts::VerificationStatus, Action, State},
     warp_runner::{MultiPassCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
@@ -98,8 +98,24 @@ pub fn PendingFriends(cx: Scope) -> Element {
         friends_list.into_iter().map(|friend| {
             let friend = Rc::new(friend);
             let _username = friend.username();
+            let display_name = friend.display_name();
+            let username_hint = friend.username_hint();
             let _status_message = friend.status_message().unwrap_or_default();
             let did = friend.did_key();
+            let verification = state.read().verification_status(&did);
+            let mutual_groups = {
+                let state = state.read();
+                let mut names: Vec<String> = state
+                    .mutual_group_chats(&did)
+                    .iter()
+                    .map(|chat| match &chat.conversation_name {
+                        Some(name) => name.clone(),
+                        None => State::join_usernames(&state.remove_self(&state.chat_participants(chat))),
+                    })
+                    .collect();
+                names.sort_by_key(|name| name.to_lowercase());
+                names
+            };
             let did2 = did.clone();
             let did_suffix = friend.short_id().to_string();
             let platform = friend.platform().into();
@@ -148,10 +164,13 @@ pub fn PendingFriends(cx: Scope) -> Element {
                         }
                     )),
                     Friend {
-                        aria_label: _username.clone(),
-                        username: _username,
+                        aria_label: _username,
+                        username: display_name,
+                        username_hint: username_hint,
                         suffix: did_suffix,
                         status_message: _status_message,
+                        mutual_groups: mutual_groups,
+                        key_changed: verification == VerificationStatus::Changed,
                         relationship: {
                             let mut relationship = Relationship::default();
                             relationship.set_received_friend_request(true);
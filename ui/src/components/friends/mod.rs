@@ -1,7 +1,10 @@
 pub mod add;
 pub mod blocked;
+pub mod contact_list;
+pub mod contact_tags;
 pub mod friend;
 pub mod friends_list;
 pub mod incoming_requests;
 pub mod nothing_here;
 pub mod outgoing_requests;
+pub mod verify_identity;
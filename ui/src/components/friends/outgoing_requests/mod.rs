@@ -91,7 +91,8 @@ pub fn OutgoingRequests(cx: Scope) -> Element {
                             },
                         )),
                         Friend {
-                            username: friend.username(),
+                            username: friend.display_name(),
+                            username_hint: friend.username_hint(),
                             aria_label: friend.username(),
                             suffix: did_suffix,
                             status_message: friend.status_message().unwrap_or_default(),
@@ -0,0 +1,153 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::{contacts::VerificationStatus, Action, State, ToastNotification};
+use common::utils::{
+    qr_code,
+    safety_number::{safety_number, safety_words},
+};
+use dioxus::prelude::*;
+use kit::{
+    elements::{button::Button, Appearance},
+    layout::modal::Modal,
+};
+use rfd::FileDialog;
+use warp::crypto::DID;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    did: DID,
+    on_dismiss: EventHandler<'a, ()>,
+}
+
+/// Shows the safety number with someone, so that it can be compared with what they see
+#[allow(non_snake_case)]
+pub fn VerifyIdentity<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let own_did = state.read().did_key();
+    let did = &cx.props.did;
+    let number = safety_number(&own_did, did);
+    let words = safety_words(&own_did, did).join(" ");
+    let expected = number.clone();
+    // the code holds the safety number, so scanning theirs compares it
+    let qr_code = match qr_code::qr_data_url(&number) {
+        Ok(url) => Some(url),
+        Err(e) => {
+            log::error!("failed to render the safety number as a QR code: {e}");
+            None
+        }
+    };
+    let status = state.read().verification_status(did);
+    let name = state
+        .read()
+        .get_identity(did)
+        .map(|i| i.display_name())
+        .unwrap_or_default();
+    let description = get_local_text_with_args("friends.verify-description", vec![("name", name)]);
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        with_title: get_local_text("friends.verify"),
+        onclose: move |_| cx.props.on_dismiss.call(()),
+        div {
+            class: "verify-identity",
+            aria_label: "verify-identity",
+            (status == VerificationStatus::Changed).then(|| rsx!(
+                div {
+                    class: "verification-warning",
+                    aria_label: "verification-warning",
+                    IconElement {
+                        icon: Icon::ShieldExclamation,
+                    },
+                    p { get_local_text("friends.key-changed-description") }
+                }
+            )),
+            p {
+                class: "verify-description",
+                "{description}"
+            },
+            p {
+                class: "safety-words",
+                aria_label: "safety-words",
+                "{words}"
+            },
+            p {
+                class: "safety-number",
+                aria_label: "safety-number",
+                "{number}"
+            },
+            qr_code.map(|src| rsx!(img {
+                class: "safety-number-qr",
+                aria_label: "safety-number-qr",
+                src: "{src}",
+            })),
+            div {
+                class: "verify-identity-buttons",
+                Button {
+                    text: get_local_text("friends.verify-scan"),
+                    icon: Icon::QrCode,
+                    aria_label: "verify-scan".into(),
+                    appearance: Appearance::Secondary,
+                    onpress: move |_| {
+                        let path = match FileDialog::new()
+                            .add_filter("image", &["png", "jpg", "jpeg", "webp", "bmp", "gif"])
+                            .pick_file()
+                        {
+                            Some(path) => path,
+                            None => return,
+                        };
+                        let toast = match qr_code::decode_qr_file(&path) {
+                            Ok(text) if text.trim() == expected => {
+                                state.write().mutate(Action::VerifyContact(&cx.props.did));
+                                ToastNotification::init(
+                                    "".into(),
+                                    get_local_text("friends.verify-match"),
+                                    Some(Icon::ShieldCheck),
+                                    2,
+                                )
+                            }
+                            Ok(_) => ToastNotification::init(
+                                get_local_text("warning-messages.error"),
+                                get_local_text("friends.verify-mismatch"),
+                                Some(Icon::ShieldExclamation),
+                                4,
+                            ),
+                            Err(e) => {
+                                log::warn!("failed to read QR code: {e}");
+                                ToastNotification::init(
+                                    "".into(),
+                                    get_local_text("friends.qr-not-found"),
+                                    None,
+                                    2,
+                                )
+                            }
+                        };
+                        state.write().mutate(Action::AddToastNotification(toast));
+                    },
+                },
+                if status == VerificationStatus::Verified {
+                    rsx!(Button {
+                        text: get_local_text("friends.unverify"),
+                        icon: Icon::ShieldSlash,
+                        aria_label: "unverify-contact".into(),
+                        appearance: Appearance::Secondary,
+                        onpress: move |_| {
+                            state.write().mutate(Action::UnverifyContact(&cx.props.did));
+                        },
+                    })
+                } else {
+                    rsx!(Button {
+                        text: get_local_text("friends.mark-verified"),
+                        icon: Icon::ShieldCheck,
+                        aria_label: "verify-contact".into(),
+                        appearance: Appearance::Primary,
+                        onpress: move |_| {
+                            state.write().mutate(Action::VerifyContact(&cx.props.did));
+                        },
+                    })
+                }
+            }
+        }
+    }))
+}
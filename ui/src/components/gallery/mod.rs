@@ -0,0 +1,303 @@
+use std::path::PathBuf;
+
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::return_correct_icon;
+use common::warp_runner::thumbnail_to_base64;
+use dioxus::prelude::*;
+use dioxus_html::input_data::keyboard_types::Code;
+use kit::{
+    elements::{
+        button::Button,
+        tooltip::{ArrowPosition, Tooltip},
+        Appearance,
+    },
+    layout::modal::Modal,
+};
+use warp::constellation::file::File;
+
+use crate::layouts::storage::files_layout::file_preview::FilePreview;
+
+// so that the arrow keys work right away
+const FOCUS_SCRIPT: &str = "document.getElementById('gallery-stage').focus()";
+const ZOOM_STEP: f64 = 1.25;
+const MAX_ZOOM: f64 = 8.0;
+
+/// How the shown image is zoomed, rotated and moved
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    zoom: f64,
+    // degrees, clockwise
+    rotation: u16,
+    offset: (f64, f64),
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            rotation: 0,
+            offset: (0.0, 0.0),
+        }
+    }
+}
+
+impl View {
+    fn zoom_in(&mut self) {
+        self.zoom = (self.zoom * ZOOM_STEP).min(MAX_ZOOM);
+    }
+
+    // the image is centered again once it fits
+    fn zoom_out(&mut self) {
+        self.zoom = (self.zoom / ZOOM_STEP).max(1.0);
+        if self.zoom == 1.0 {
+            self.offset = (0.0, 0.0);
+        }
+    }
+
+    fn rotate(&mut self) {
+        self.rotation = (self.rotation + 90) % 360;
+    }
+
+    // only a zoomed image can be moved
+    fn pan(&mut self, x: f64, y: f64) {
+        if self.zoom > 1.0 {
+            self.offset = (self.offset.0 + x, self.offset.1 + y);
+        }
+    }
+
+    fn style(&self) -> String {
+        format!(
+            "transform: translate({}px, {}px) rotate({}deg) scale({});",
+            self.offset.0, self.offset.1, self.rotation, self.zoom
+        )
+    }
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    images: Vec<File>,
+    // the index of the image shown first
+    start: usize,
+    on_dismiss: EventHandler<'a, ()>,
+    // with the index of the image. like for `FilePreview`, a path loads the image and none saves it
+    on_download: EventHandler<'a, (usize, Option<PathBuf>)>,
+    // images sent in a chat can be shown in their conversation
+    on_jump: Option<EventHandler<'a, usize>>,
+}
+
+/// Shows images one at a time, with the others in a strip below. The arrow keys go to the
+/// previous and next image, the mouse wheel zooms and a zoomed image can be dragged around
+#[allow(non_snake_case)]
+pub fn Gallery<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let eval = use_eval(cx);
+    let count = cx.props.images.len();
+    let index = use_state(cx, || cx.props.start.min(count.saturating_sub(1)));
+    let view = use_ref(cx, View::default);
+    // where the mouse was while dragging the image
+    let dragging: &UseRef<Option<(f64, f64)>> = use_ref(cx, || None);
+
+    let current = *index.get();
+    let file = cx.props.images.get(current)?;
+    let id = file.id();
+    let name = file.name();
+    let position = get_local_text_with_args(
+        "files.gallery-position",
+        vec![
+            ("position", (current + 1).to_string()),
+            ("count", count.to_string()),
+        ],
+    );
+    let style = view.read().style();
+    let zoom = format!("{:.0}%", view.read().zoom * 100.0);
+
+    let go_to = move |i: usize| {
+        if i < count && i != *index.get() {
+            index.set(i);
+            view.set(View::default());
+        }
+    };
+
+    cx.render(rsx!(Modal {
+        open: true,
+        transparent: false,
+        dont_pad: true,
+        class: "gallery-modal",
+        onclose: move |_| cx.props.on_dismiss.call(()),
+        children: cx.render(rsx!(div {
+            class: "gallery",
+            aria_label: "gallery",
+            div {
+                class: "gallery-toolbar",
+                div {
+                    class: "gallery-title",
+                    p { "{name}" },
+                    p {
+                        class: "gallery-position",
+                        aria_label: "gallery-position",
+                        "{position}"
+                    }
+                },
+                Button {
+                    icon: Icon::MagnifyingGlassMinus,
+                    appearance: Appearance::Secondary,
+                    aria_label: "gallery-zoom-out".into(),
+                    tooltip: cx.render(rsx!(
+                        Tooltip {
+                            arrow_position: ArrowPosition::Top,
+                            text: get_local_text("files.zoom-out"),
+                        }
+                    )),
+                    onpress: move |_| view.with_mut(|v| v.zoom_out()),
+                },
+                span {
+                    class: "gallery-zoom",
+                    aria_label: "gallery-zoom",
+                    onclick: move |_| view.set(View::default()),
+                    "{zoom}"
+                },
+                Button {
+                    icon: Icon::MagnifyingGlassPlus,
+                    appearance: Appearance::Secondary,
+                    aria_label: "gallery-zoom-in".into(),
+                    tooltip: cx.render(rsx!(
+                        Tooltip {
+                            arrow_position: ArrowPosition::Top,
+                            text: get_local_text("files.zoom-in"),
+                        }
+                    )),
+                    onpress: move |_| view.with_mut(|v| v.zoom_in()),
+                },
+                Button {
+                    icon: Icon::ArrowUturnRight,
+                    appearance: Appearance::Secondary,
+                    aria_label: "gallery-rotate".into(),
+                    tooltip: cx.render(rsx!(
+                        Tooltip {
+                            arrow_position: ArrowPosition::Top,
+                            text: get_local_text("files.rotate"),
+                        }
+                    )),
+                    onpress: move |_| view.with_mut(|v| v.rotate()),
+                },
+                cx.props.on_jump.as_ref().map(|on_jump| rsx!(Button {
+                    icon: Icon::ChatBubbleBottomCenterText,
+                    appearance: Appearance::Secondary,
+                    aria_label: "gallery-jump-to-message".into(),
+                    tooltip: cx.render(rsx!(
+                        Tooltip {
+                            arrow_position: ArrowPosition::Top,
+                            text: get_local_text("files.jump-to-message"),
+                        }
+                    )),
+                    onpress: move |_| on_jump.call(*index.get()),
+                })),
+                Button {
+                    icon: Icon::ArrowDownCircle,
+                    appearance: Appearance::Secondary,
+                    aria_label: "gallery-download".into(),
+                    tooltip: cx.render(rsx!(
+                        Tooltip {
+                            arrow_position: ArrowPosition::TopRight,
+                            text: get_local_text("files.download"),
+                        }
+                    )),
+                    onpress: move |_| cx.props.on_download.call((*index.get(), None)),
+                },
+            },
+            div {
+                id: "gallery-stage",
+                class: format_args!("gallery-stage {}", if view.read().zoom > 1.0 { "zoomed" } else { "" }),
+                aria_label: "gallery-stage",
+                tabindex: "0",
+                onmounted: move |_| { _ = eval(FOCUS_SCRIPT); },
+                onkeydown: move |e: Event<KeyboardData>| {
+                    match e.code() {
+                        Code::ArrowLeft => go_to(current.saturating_sub(1)),
+                        Code::ArrowRight => go_to(current + 1),
+                        Code::Equal | Code::NumpadAdd => view.with_mut(|v| v.zoom_in()),
+                        Code::Minus | Code::NumpadSubtract => view.with_mut(|v| v.zoom_out()),
+                        Code::Digit0 | Code::Numpad0 => view.set(View::default()),
+                        Code::KeyR => view.with_mut(|v| v.rotate()),
+                        Code::Escape => cx.props.on_dismiss.call(()),
+                        _ => {}
+                    }
+                },
+                onwheel: move |e: Event<WheelData>| {
+                    if e.delta().strip_units().y < 0.0 {
+                        view.with_mut(|v| v.zoom_in());
+                    } else {
+                        view.with_mut(|v| v.zoom_out());
+                    }
+                },
+                onmousedown: move |e: Event<MouseData>| {
+                    let point = e.client_coordinates();
+                    dragging.set(Some((point.x, point.y)));
+                },
+                onmousemove: move |e: Event<MouseData>| {
+                    let last = match *dragging.read() {
+                        Some(last) => last,
+                        None => return,
+                    };
+                    let point = e.client_coordinates();
+                    view.with_mut(|v| v.pan(point.x - last.0, point.y - last.1));
+                    dragging.set(Some((point.x, point.y)));
+                },
+                onmouseup: move |_| dragging.set(None),
+                onmouseleave: move |_| dragging.set(None),
+                (current > 0).then(|| rsx!(div {
+                    class: "gallery-nav previous",
+                    Button {
+                        icon: Icon::ChevronLeft,
+                        appearance: Appearance::Secondary,
+                        aria_label: "gallery-previous".into(),
+                        onpress: move |_| go_to(current - 1),
+                    }
+                })),
+                div {
+                    class: "gallery-image",
+                    style: "{style}",
+                    FilePreview {
+                        key: "{id}",
+                        file: file,
+                        on_download: move |temp_path| cx.props.on_download.call((current, temp_path)),
+                    }
+                },
+                (current + 1 < count).then(|| rsx!(div {
+                    class: "gallery-nav next",
+                    Button {
+                        icon: Icon::ChevronRight,
+                        appearance: Appearance::Secondary,
+                        aria_label: "gallery-next".into(),
+                        onpress: move |_| go_to(current + 1),
+                    }
+                })),
+            },
+            div {
+                class: "gallery-strip",
+                aria_label: "gallery-strip",
+                cx.props.images.iter().enumerate().map(|(i, image)| {
+                    let thumbnail = thumbnail_to_base64(image);
+                    let image_id = image.id();
+                    let image_name = image.name();
+                    rsx!(div {
+                        key: "{image_id}-{i}-thumbnail",
+                        class: format_args!("gallery-thumbnail {}", if i == current { "active" } else { "" }),
+                        title: "{image_name}",
+                        onclick: move |_| go_to(i),
+                        if thumbnail.is_empty() {
+                            rsx!(IconElement {
+                                icon: return_correct_icon(&image_name),
+                            })
+                        } else {
+                            rsx!(img {
+                                src: "{thumbnail}",
+                            })
+                        }
+                    })
+                })
+            }
+        }))
+    }))
+}
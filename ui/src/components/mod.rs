@@ -4,6 +4,7 @@ pub mod debug_logger;
 pub mod emoji_group;
 pub mod files;
 pub mod friends;
+pub mod gallery;
 pub mod media;
 pub mod settings;
 pub mod shortcuts;
@@ -22,7 +22,7 @@ pub enum Page {
     Extensions,
     General,
     Messages,
-    //Files,
+    Files,
     //Privacy,
     Keybinds,
     Profile,
@@ -54,7 +54,7 @@ impl FromStr for Page {
             "audio" => Ok(Page::Audio),
             "developer" => Ok(Page::Developer),
             "extensions" => Ok(Page::Extensions),
-            //"files" => Ok(Page::Files),
+            "files" => Ok(Page::Files),
             "general" => Ok(Page::General),
             "messages" => Ok(Page::Messages),
             "keybinds" => Ok(Page::Keybinds),
@@ -122,12 +122,12 @@ pub fn Sidebar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
         icon: Icon::LockClosed,
         ..UIRoute::default()
     };*/
-    /*let files = UIRoute {
+    let files = UIRoute {
         to: "files",
         name: get_local_text("settings.files"),
         icon: Icon::Folder,
         ..UIRoute::default()
-    };*/
+    };
     let extensions = UIRoute {
         to: "extensions",
         name: get_local_text("settings.extensions"),
@@ -175,7 +175,7 @@ pub fn Sidebar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     // To control order of routes, add them here.
     // routes.push(privacy);
     routes.push(audio);
-    // routes.push(files);
+    routes.push(files);
     routes.push(extensions);
     routes.push(keybinds);
     routes.push(accessibility);
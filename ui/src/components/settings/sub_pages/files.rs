@@ -1,39 +1,187 @@
-use common::language::get_local_text;
+use common::icons::outline::Shape as Icon;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::{action::ConfigAction, Action, State, ToastNotification};
 use dioxus::prelude::*;
-#[allow(unused_imports)]
-use kit::elements::{button::Button, switch::Switch};
+use kit::elements::{
+    button::Button,
+    input::{Input, Options},
+    range::Range,
+    select::Select,
+    switch::Switch,
+    Appearance,
+};
+use rfd::FileDialog;
 use tracing::log;
 
 use crate::components::settings::SettingSection;
 
-#[allow(dead_code)]
+const MAX_IMAGE_DIMENSIONS: [u32; 4] = [1280, 1920, 2560, 3840];
+// 0 never purges the trash
+const TRASH_RETENTION_DAYS: [u32; 6] = [7, 14, 30, 60, 90, 0];
+
+fn retention_label(days: u32) -> String {
+    if days == 0 {
+        get_local_text("settings-files.never")
+    } else {
+        get_local_text_with_args("settings-files.days", vec![("days", days.to_string())])
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn FilesSettings(cx: Scope) -> Element {
     log::trace!("Files settings page rendered.");
+    let state = use_shared_state::<State>(cx)?;
+    let image_processing = state.read().configuration.files.image_processing;
+    let trash_retention_days = state.read().configuration.files.trash_retention_days;
+    let local_sync = state.read().configuration.files.local_sync.clone();
+    let sync_folder = local_sync
+        .local_path
+        .as_ref()
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|| get_local_text("settings-files.no-sync-folder"));
+    let mut dimensions = MAX_IMAGE_DIMENSIONS.to_vec();
+    if !dimensions.contains(&image_processing.max_dimension) {
+        dimensions.push(image_processing.max_dimension);
+        dimensions.sort_unstable();
+    }
     cx.render(rsx!(
         div {
             id: "settings-files",
             aria_label: "settings-files",
+            SettingSection {
+                aria_label: "strip-image-metadata-section".into(),
+                section_label: get_local_text("settings-files.strip-metadata"),
+                section_description: get_local_text("settings-files.strip-metadata-description"),
+                Switch {
+                    active: image_processing.strip_metadata,
+                    onflipped: move |flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetStripImageMetadata(flag)));
+                    }
+                }
+            },
+            SettingSection {
+                aria_label: "resize-images-section".into(),
+                section_label: get_local_text("settings-files.resize-images"),
+                section_description: get_local_text("settings-files.resize-images-description"),
+                Switch {
+                    active: image_processing.resize,
+                    onflipped: move |flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetResizeImages(flag)));
+                    }
+                }
+            },
+            image_processing.resize.then(|| rsx!(
+                SettingSection {
+                    aria_label: "max-image-size-section".into(),
+                    section_label: get_local_text("settings-files.max-image-size"),
+                    section_description: get_local_text("settings-files.max-image-size-description"),
+                    Select {
+                        initial_value: image_processing.max_dimension.to_string(),
+                        options: dimensions.iter().map(|d| d.to_string()).collect(),
+                        onselect: move |value: String| {
+                            if let Ok(dimension) = value.parse() {
+                                state.write().mutate(Action::Config(ConfigAction::SetImageMaxDimension(dimension)));
+                            }
+                        }
+                    }
+                },
+                // only resized images are re-encoded
+                SettingSection {
+                    aria_label: "image-quality-section".into(),
+                    section_label: get_local_text("settings-files.image-quality"),
+                    section_description: get_local_text("settings-files.image-quality-description"),
+                    Range {
+                        aria_label: "image-quality-range".into(),
+                        initial_value: image_processing.quality as f32,
+                        min: 50.0,
+                        max: 100.0,
+                        step: 5.0,
+                        with_buttons: true,
+                        onchange: move |value: f32| {
+                            state.write().mutate(Action::Config(ConfigAction::SetImageQuality(value.round() as u8)));
+                        }
+                    }
+                }
+            )),
+            SettingSection {
+                aria_label: "trash-retention-section".into(),
+                section_label: get_local_text("settings-files.trash-retention"),
+                section_description: get_local_text("settings-files.trash-retention-description"),
+                Select {
+                    initial_value: retention_label(trash_retention_days),
+                    options: TRASH_RETENTION_DAYS.iter().map(|days| retention_label(*days)).collect(),
+                    onselect: move |value: String| {
+                        if let Some(days) = TRASH_RETENTION_DAYS.iter().find(|days| retention_label(**days) == value) {
+                            state.write().mutate(Action::Config(ConfigAction::SetTrashRetentionDays(*days)));
+                        }
+                    }
+                }
+            },
             SettingSection {
                 aria_label: "local-sync-section".into(),
                 section_label: get_local_text("settings-files.local-sync"),
                 section_description: get_local_text("settings-files.local-sync-description"),
                 Switch {
-
+                    active: local_sync.enabled,
+                    onflipped: move |flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetLocalSyncEnabled(flag)));
+                    }
                 }
             },
-            /*SettingSection {
-                section_label: get_local_text("settings-files.open-sync-folder"),
-                section_description: get_local_text("settings-files.open-sync-folder-description"),
-                Button {
-                    text: get_local_text("settings-files.open-sync-folder"),
-                    aria_label: "open-sync-folder-button".into(),
-                    appearance: kit::elements::Appearance::Secondary,
-                    icon: common::icons::outline::Shape::FolderOpen,
-                    onpress: |_| {
+            local_sync.enabled.then(|| rsx!(
+                SettingSection {
+                    aria_label: "sync-folder-section".into(),
+                    section_label: get_local_text("settings-files.sync-folder"),
+                    section_description: sync_folder,
+                    Button {
+                        text: get_local_text("settings-files.choose-sync-folder"),
+                        aria_label: "choose-sync-folder-button".into(),
+                        appearance: Appearance::Secondary,
+                        icon: Icon::FolderPlus,
+                        onpress: move |_| {
+                            if let Some(dir) = FileDialog::new().pick_folder() {
+                                state.write().mutate(Action::Config(ConfigAction::SetLocalSyncPath(dir)));
+                            }
+                        },
+                    },
+                    local_sync.local_path.clone().map(|path| rsx!(Button {
+                        aria_label: "open-sync-folder-button".into(),
+                        appearance: Appearance::Secondary,
+                        icon: Icon::FolderOpen,
+                        onpress: move |_| {
+                            let _ = opener::open(&path);
+                        },
+                    })),
+                },
+                SettingSection {
+                    aria_label: "sync-storage-folder-section".into(),
+                    section_label: get_local_text("settings-files.sync-storage-folder"),
+                    section_description: get_local_text("settings-files.sync-storage-folder-description"),
+                    Input {
+                        placeholder: "/Sync".into(),
+                        default_text: local_sync.remote_path.clone(),
+                        aria_label: "sync-storage-folder-input".into(),
+                        options: Options {
+                            with_clear_btn: true,
+                            ..Options::default()
+                        },
+                        onreturn: move |(v, _, _): (String, bool, _)| {
+                            let folder = v.trim().trim_matches('/');
+                            if folder.is_empty() {
+                                state.write().mutate(Action::AddToastNotification(ToastNotification::init(
+                                    "".into(),
+                                    get_local_text("settings-files.sync-root-not-allowed"),
+                                    None,
+                                    3,
+                                )));
+                                return;
+                            }
+                            let path = format!("/{folder}");
+                            state.write().mutate(Action::Config(ConfigAction::SetLocalSyncRemotePath(path)));
+                        },
                     }
-                }
-            },*/
+                },
+            )),
         }
     ))
 }
@@ -1,17 +1,69 @@
+use std::path::PathBuf;
+
 use common::{
-    language::get_local_text,
-    state::{Action, State},
+    icons::outline::Shape as Icon,
+    language::{get_local_text, get_local_text_with_args},
+    state::{
+        action::ConfigAction,
+        emoji_packs::{get_available_emoji_packs, import_emoji_pack, remove_emoji_pack},
+        Action, State, ToastNotification,
+    },
+    STATIC_ARGS,
 };
 use dioxus::prelude::*;
-use kit::elements::switch::Switch;
+use kit::elements::{
+    button::Button,
+    input::{Input, Options},
+    switch::Switch,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+use rfd::FileDialog;
 use tracing::log;
 
-use crate::components::settings::SettingSection;
+use crate::components::settings::{SettingSection, SettingSectionSimple};
+
+// a pack can be a large zip, so it is unpacked off the UI thread
+async fn import_pack(state: UseSharedState<State>, src: PathBuf) {
+    let res = tokio::task::spawn_blocking(move || import_emoji_pack(&src))
+        .await
+        .unwrap_or_else(|e| Err(e.into()));
+    let toast = match res {
+        Ok(_) => ToastNotification::init(
+            "".into(),
+            get_local_text("settings-messages.emoji-pack-imported"),
+            None,
+            2,
+        ),
+        Err(e) => {
+            log::error!("failed to import emoji pack: {e}");
+            ToastNotification::init(
+                get_local_text("warning-messages.error"),
+                get_local_text_with_args(
+                    "settings-messages.emoji-pack-import-failed",
+                    vec![("error", e.to_string())],
+                ),
+                Some(Icon::ExclamationTriangle),
+                4,
+            )
+        }
+    };
+    state.write().mutate(Action::AddToastNotification(toast));
+    state
+        .write()
+        .mutate(Action::SetEmojiPacks(get_available_emoji_packs()));
+}
 
 #[allow(non_snake_case)]
 pub fn Messages(cx: Scope) -> Element {
     log::trace!("Messages settings page rendered.");
     let state = use_shared_state::<State>(cx)?;
+    let link_previews = state.read().configuration.privacy.link_previews.clone();
+    let previews_enabled = link_previews.enabled;
+    let own_messages_only = link_previews.own_messages_only;
+    let whitelist_only = link_previews.whitelist_only;
+    let whitelisted_domains = link_previews.whitelisted_domains.join(", ");
+    let emoji_packs = state.read().ui.emoji_packs.clone();
     cx.render(rsx!(
         div {
             id: "settings-messages",
@@ -38,6 +90,134 @@ pub fn Messages(cx: Scope) -> Element {
                     }
                 }
             }
+            SettingSection {
+                aria_label: "link-previews-section".into(),
+                section_label: get_local_text("settings-messages.link-previews"),
+                section_description: get_local_text("settings-messages.link-previews-description"),
+                Switch {
+                    active: previews_enabled,
+                    onflipped: move|flag| {
+                        state.write().mutate(Action::Config(ConfigAction::SetLinkPreviewsEnabled(flag)));
+                    }
+                }
+            },
+            previews_enabled.then(|| rsx!(
+                SettingSection {
+                    aria_label: "link-previews-own-messages-section".into(),
+                    section_label: get_local_text("settings-messages.link-previews-own-messages"),
+                    section_description: get_local_text("settings-messages.link-previews-own-messages-description"),
+                    Switch {
+                        active: own_messages_only,
+                        onflipped: move|flag| {
+                            state.write().mutate(Action::Config(ConfigAction::SetLinkPreviewsOwnMessagesOnly(flag)));
+                        }
+                    }
+                },
+                SettingSection {
+                    aria_label: "link-previews-whitelist-section".into(),
+                    section_label: get_local_text("settings-messages.link-previews-whitelist"),
+                    section_description: get_local_text("settings-messages.link-previews-whitelist-description"),
+                    Switch {
+                        active: whitelist_only,
+                        onflipped: move|flag| {
+                            state.write().mutate(Action::Config(ConfigAction::SetLinkPreviewsWhitelistOnly(flag)));
+                        }
+                    }
+                },
+                whitelist_only.then(|| rsx!(
+                    SettingSection {
+                        aria_label: "link-previews-domains-section".into(),
+                        section_label: get_local_text("settings-messages.link-previews-domains"),
+                        section_description: get_local_text("settings-messages.link-previews-domains-description"),
+                        Input {
+                            placeholder: "example.com, github.com".into(),
+                            default_text: whitelisted_domains.clone(),
+                            aria_label: "link-previews-domains-input".into(),
+                            options: Options {
+                                with_clear_btn: true,
+                                ..Options::default()
+                            },
+                            onreturn: move |(v, _, _): (String, bool, _)| {
+                                let domains = v
+                                    .split(|c: char| c == ',' || c.is_whitespace())
+                                    .map(|d| d.trim().to_lowercase())
+                                    .filter(|d| !d.is_empty())
+                                    .collect();
+                                state.write().mutate(Action::Config(ConfigAction::SetLinkPreviewsWhitelist(domains)));
+                            },
+                        }
+                    }
+                ))
+            )),
+            SettingSection {
+                aria_label: "emoji-packs-section".into(),
+                section_label: get_local_text("settings-messages.emoji-packs"),
+                section_description: get_local_text("settings-messages.emoji-packs-description"),
+                Button {
+                    text: get_local_text("settings-messages.import-folder"),
+                    aria_label: "import-emoji-pack-folder-button".into(),
+                    appearance: Appearance::Secondary,
+                    icon: Icon::FolderPlus,
+                    onpress: move |_| {
+                        if let Some(dir) = FileDialog::new().pick_folder() {
+                            cx.spawn(import_pack(state.clone(), dir));
+                        }
+                    },
+                },
+                Button {
+                    text: get_local_text("settings-messages.import-zip"),
+                    aria_label: "import-emoji-pack-zip-button".into(),
+                    appearance: Appearance::Secondary,
+                    icon: Icon::ArchiveBoxArrowDown,
+                    onpress: move |_| {
+                        if let Some(file) = FileDialog::new().add_filter("zip", &["zip"]).pick_file() {
+                            cx.spawn(import_pack(state.clone(), file));
+                        }
+                    },
+                },
+                Button {
+                    icon: Icon::FolderOpen,
+                    aria_label: "open-emoji-packs-folder-button".into(),
+                    onpress: move |_| {
+                        let _ = opener::open(&STATIC_ARGS.emoji_packs_path);
+                    },
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Right,
+                        text: get_local_text("settings-developer.open-cache-folder"),
+                    }))
+                },
+            },
+            emoji_packs.iter().map(|pack| {
+                let id = pack.id.clone();
+                let count = get_local_text_with_args("settings-messages.emoji-pack-count", vec![("num", pack.emojis.len())]);
+                rsx!(
+                    SettingSectionSimple {
+                        aria_label: "emoji-pack".into(),
+                        div {
+                            class: "emoji-pack",
+                            p {
+                                class: "emoji-pack-name",
+                                "{pack.name}"
+                            },
+                            p {
+                                class: "emoji-pack-count",
+                                "{count}"
+                            },
+                        },
+                        Button {
+                            icon: Icon::Trash,
+                            aria_label: "remove-emoji-pack-button".into(),
+                            appearance: Appearance::Danger,
+                            onpress: move |_| {
+                                if let Err(e) = remove_emoji_pack(&id) {
+                                    log::error!("failed to remove emoji pack {id}: {e}");
+                                }
+                                state.write().mutate(Action::SetEmojiPacks(get_available_emoji_packs()));
+                            },
+                        }
+                    }
+                )
+            })
         }
     ))
 }
@@ -5,6 +5,7 @@ use common::get_images_dir;
 use common::icons::Icon as IconElement;
 use common::language::get_local_text;
 use common::state::{Action, Identity, State, ToastNotification};
+use common::utils::qr_code;
 use common::warp_runner::{MultiPassCmd, TesseractCmd, WarpCmd};
 use common::{icons::outline::Shape as Icon, WARP_CMD_CH};
 use dioxus::prelude::*;
@@ -301,6 +302,17 @@ pub fn ProfileSettings(cx: Scope) -> Element {
 
     let did_short = identity.short_id().to_string();
     let did_key = identity.did_key();
+    let did_string = did_key.to_string();
+    // only rendered again when the did changes
+    let qr_code = use_memo(cx, (&did_string,), |(did,)| {
+        match qr_code::qr_data_url(&did) {
+            Ok(url) => Some(url),
+            Err(e) => {
+                log::error!("failed to render the did as a QR code: {e}");
+                None
+            }
+        }
+    });
     let short_name = format!("{}#{}", username, did_short);
     let short_name_context = short_name.clone();
 
@@ -585,6 +597,56 @@ pub fn ProfileSettings(cx: Scope) -> Element {
                         }
                     },
                 },
+                qr_code.clone().map(|src| rsx!(
+                    SettingSection {
+                        aria_label: "qr-code-section".into(),
+                        section_label: get_local_text("settings-profile.qr-code"),
+                        section_description: get_local_text("settings-profile.qr-code-description"),
+                        div {
+                            class: "profile-qr-code",
+                            img {
+                                aria_label: "profile-qr-code",
+                                src: "{src}",
+                            },
+                            Button {
+                                text: get_local_text("settings-profile.save-qr"),
+                                aria_label: "save-qr-button".into(),
+                                appearance: Appearance::Secondary,
+                                icon: Icon::ArrowDownTray,
+                                onpress: move |_| {
+                                    let path = match FileDialog::new()
+                                        .set_file_name("did.png")
+                                        .add_filter("png", &["png"])
+                                        .save_file()
+                                    {
+                                        Some(path) => path,
+                                        None => return,
+                                    };
+                                    let result = qr_code::qr_png(&did_string)
+                                        .and_then(|png| std::fs::write(&path, png).map_err(anyhow::Error::from));
+                                    let toast = match result {
+                                        Ok(_) => ToastNotification::init(
+                                            "".into(),
+                                            get_local_text("settings-profile.qr-saved"),
+                                            None,
+                                            2,
+                                        ),
+                                        Err(e) => {
+                                            log::error!("failed to save QR code: {e}");
+                                            ToastNotification::init(
+                                                get_local_text("warning-messages.error"),
+                                                get_local_text("settings-profile.qr-save-failed"),
+                                                Some(Icon::ExclamationTriangle),
+                                                3,
+                                            )
+                                        }
+                                    };
+                                    state.write().mutate(Action::AddToastNotification(toast));
+                                }
+                            }
+                        }
+                    }
+                )),
                 if *phrase_exists.get() {rsx!(
                     SettingSection {
                         aria_label: "recovery-seed-section".into(),
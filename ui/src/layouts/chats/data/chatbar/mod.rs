@@ -4,10 +4,13 @@ mod typing_info;
 pub use typing_indicator::*;
 pub use typing_info::*;
 use uuid::Uuid;
+use warp::raygun::Location;
 
 pub struct MsgChInput {
     pub msg: Vec<String>,
     pub conv_id: Uuid,
     pub appended_msg_id: Option<Uuid>,
     pub replying_to: Option<Uuid>,
+    // sent instead of the files attached in the chatbar, which are then left alone. used for voice messages
+    pub attachments: Option<Vec<Location>>,
 }
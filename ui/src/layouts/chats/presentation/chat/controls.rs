@@ -10,7 +10,7 @@ use kit::{
     layout::modal::Modal,
 };
 
-use super::pinned_messages::PinnedMessages;
+use super::{pinned_messages::PinnedMessages, shared_content::SharedContentPanel};
 use crate::layouts::chats::data::{ChatData, ChatProps};
 
 use common::{
@@ -56,6 +56,7 @@ pub fn get_controls(cx: Scope<ChatProps>) -> Element {
     let call_in_progress = active_call.is_some(); // active_chat.map(|chat| chat.id) == active_call.map(|call| call.conversation_id);
 
     let show_pinned = use_state(cx, || false);
+    let show_shared = use_state(cx, || false);
 
     use_effect(cx, &minimal, |_| {
         to_owned![show_more];
@@ -199,6 +200,17 @@ pub fn get_controls(cx: Scope<ChatProps>) -> Element {
                 show_more.set(false);
             }
         }
+        Button {
+            icon: Icon::Photo,
+            aria_label: "shared-label".into(),
+            appearance: if *show_shared.clone() { Appearance::Primary } else { Appearance::Secondary },
+            text: text_builder("messages.shared"),
+            tooltip: tooltip_builder("messages.shared", arrow_top),
+            onpress: move |_| {
+                show_shared.set(true);
+                show_more.set(false);
+            }
+        }
         Button {
             icon: Icon::PhoneArrowUpRight,
             disabled: !state.read().configuration.developer.experimental_features || *call_pending.current() || call_in_progress,
@@ -242,6 +254,21 @@ pub fn get_controls(cx: Scope<ChatProps>) -> Element {
         }
     )),));
 
+    let shared = cx.render(rsx!(show_shared.then(|| rsx!(
+        Modal {
+            open: true,
+            transparent: true,
+            change_horizontal_position: true,
+            with_title: get_local_text("messages.shared"),
+            onclose: move |_| {
+                show_shared.set(false);
+            },
+            if chat_data.read().active_chat.is_initialized {
+                rsx!(SharedContentPanel{ show_shared: show_shared.clone()})
+            }
+        }
+    )),));
+
     if minimal {
         return cx.render(rsx!(
             div {
@@ -276,8 +303,9 @@ pub fn get_controls(cx: Scope<ChatProps>) -> Element {
                         buttons
                     })
             }),
-            pinned
+            pinned,
+            shared
         ));
     }
-    cx.render(rsx!(buttons, pinned))
+    cx.render(rsx!(buttons, pinned, shared))
 }
@@ -63,13 +63,13 @@ pub fn EditGroup(cx: Scope) -> Element {
 
     friends_not_in_group_list.retain(|_, friend| {
         friend
-            .username()
+            .display_name()
             .to_ascii_lowercase()
             .contains(&friend_prefix.to_ascii_lowercase())
     });
     friends_group_list.retain(|_, friend| {
         friend
-            .username()
+            .display_name()
             .to_ascii_lowercase()
             .contains(&friend_prefix.to_ascii_lowercase())
     });
@@ -81,7 +81,7 @@ pub fn EditGroup(cx: Scope) -> Element {
         friends_group_list.values().cloned().collect()
     };
 
-    friends.sort_by_key(|d| d.username());
+    friends.sort_by_key(|d| d.display_name());
 
     let add_friends = rsx!(Button {
         aria_label: "edit-group-add-members".into(),
@@ -269,7 +269,7 @@ fn friend_row(cx: Scope<FriendRowProps>) -> Element {
                 p {
                     class: "ellipsis-overflow",
                     aria_label: "friend-username",
-                    _friend.username(),
+                    _friend.display_name(),
                 },
             },
             Button {
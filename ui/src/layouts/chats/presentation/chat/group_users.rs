@@ -114,7 +114,7 @@ fn render_friends(cx: Scope<FriendsProps>) -> Element {
     // reduce group participants vector to just the name_prefix matched
     group_participants.retain(|friend| {
         friend
-            .username()
+            .display_name()
             .to_ascii_lowercase()
             .contains(&name_prefix.to_ascii_lowercase())
     });
@@ -182,7 +182,7 @@ fn render_friend(cx: Scope<FriendProps>) -> Element {
                 p {
                     class: "ellipsis-overflow",
                     aria_label: "friend-username",
-                    cx.props.friend.username(),
+                    cx.props.friend.display_name(),
                 },
             },
             if cx.props.is_creator {
@@ -3,6 +3,7 @@ pub mod coroutines;
 mod edit_group;
 mod group_users;
 mod pinned_messages;
+mod shared_content;
 mod topbar;
 
 use dioxus::prelude::*;
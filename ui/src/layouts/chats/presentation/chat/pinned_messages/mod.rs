@@ -221,7 +221,7 @@ pub fn PinnedMessage<'a>(cx: Scope<'a, PinnedMessageProp<'a>>) -> Element<'a> {
                                     p {
                                         class: "ellipsis-overflow",
                                         aria_label: "pinned-sender",
-                                        sender.username()
+                                        sender.display_name()
                                     },
                                 )
                             }),
@@ -0,0 +1,426 @@
+use std::{ffi::OsStr, path::PathBuf};
+
+use chrono::{DateTime, Utc};
+use common::{
+    icons::outline::Shape as Icon,
+    icons::Icon as IconElement,
+    language::get_local_text,
+    return_correct_icon,
+    state::State,
+    utils::shared_content::SharedContent,
+    warp_runner::{
+        thumbnail_to_base64, ui_adapter::MessageAttachment, FetchMessagesConfig,
+        FetchMessagesResponse, RayGunCmd, WarpCmd,
+    },
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use futures::StreamExt;
+use kit::elements::{
+    button::Button,
+    loader::Loader,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+use rfd::FileDialog;
+use tracing::log;
+use uuid::Uuid;
+
+use crate::{
+    components::gallery::Gallery,
+    layouts::{
+        chats::{
+            data::{self, ChatData},
+            presentation::chat::coroutines::fetch_window,
+        },
+        storage::functions::format_item_size,
+    },
+    utils::{async_task_queue::download_stream_handler, download::download_attachment},
+};
+
+// how many messages are fetched at once while indexing the conversation
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Media,
+    Files,
+    Links,
+}
+
+pub enum ChannelCommand {
+    GoToMessage {
+        conversation_id: Uuid,
+        message_id: Uuid,
+        message_date: DateTime<Utc>,
+        show_shared: UseState<bool>,
+    },
+    DownloadAttachment {
+        attachment: MessageAttachment,
+        path: PathBuf,
+    },
+}
+
+// asks where an attachment should be saved
+fn save_path(attachment: &MessageAttachment) -> Option<PathBuf> {
+    let file_name = attachment.file.name();
+    let file_extension = std::path::Path::new(&file_name)
+        .extension()
+        .and_then(OsStr::to_str)
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+    let file_stem = PathBuf::from(&file_name)
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .map(str::to_string)
+        .unwrap_or_default();
+
+    FileDialog::new()
+        .set_directory(dirs::download_dir().unwrap_or_default())
+        .set_file_name(&file_stem)
+        .add_filter("", &[&file_extension])
+        .save_file()
+}
+
+#[derive(Props, PartialEq)]
+pub struct Props {
+    show_shared: UseState<bool>,
+}
+
+/// The media, files and links sent in the active chat, newest first
+#[allow(non_snake_case)]
+pub fn SharedContentPanel(cx: Scope<'_, Props>) -> Element<'_> {
+    log::trace!("rendering shared_content");
+    let state = use_shared_state::<State>(cx)?;
+    let chat_data = use_shared_state::<ChatData>(cx)?;
+    let conversation_id = chat_data.read().active_chat.id();
+    let tab = use_state(cx, || Tab::Media);
+    let content = use_ref(cx, SharedContent::default);
+    let loading = use_state(cx, || true);
+    // the index of the media shown in the gallery
+    let gallery: &UseState<Option<usize>> = use_state(cx, || None);
+    let download_streams = download_stream_handler(cx);
+
+    // the whole conversation is indexed a page at a time, so the newest items show up first
+    use_future(cx, &conversation_id, |conversation_id| {
+        to_owned![content, loading];
+        async move {
+            content.set(SharedContent::default());
+            loading.set(true);
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            let mut config = FetchMessagesConfig::MostRecent { limit: PAGE_SIZE };
+            loop {
+                let (tx, rx) = futures::channel::oneshot::channel();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::FetchMessages {
+                    conv_id: conversation_id,
+                    config,
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                    break;
+                }
+                let FetchMessagesResponse {
+                    messages, has_more, ..
+                } = match rx.await.expect("command canceled") {
+                    Ok(r) => r,
+                    Err(e) => {
+                        log::error!("failed to fetch messages: {}", e);
+                        break;
+                    }
+                };
+                let oldest = messages.iter().map(|m| m.inner.date()).min();
+                content.with_mut(|c| c.add(messages.iter().map(|m| &m.inner)));
+                match oldest {
+                    Some(start_date) if has_more => {
+                        config = FetchMessagesConfig::Earlier {
+                            start_date,
+                            limit: PAGE_SIZE,
+                        };
+                    }
+                    _ => break,
+                }
+            }
+            loading.set(false);
+        }
+    });
+
+    let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<ChannelCommand>| {
+        to_owned![chat_data, state, download_streams];
+        async move {
+            while let Some(cmd) = rx.next().await {
+                match cmd {
+                    ChannelCommand::GoToMessage {
+                        conversation_id,
+                        message_id,
+                        message_date,
+                        show_shared,
+                    } => {
+                        let view_init = data::ViewInit {
+                            scroll_to: data::ScrollTo::ScrollUp {
+                                view_top: message_id,
+                            },
+                            msg_time: Some(message_date),
+                            limit: data::DEFAULT_MESSAGES_TO_TAKE,
+                        };
+                        let behavior = data::ChatBehavior {
+                            view_init,
+                            // these fields will be overwritten by fetch_window
+                            on_scroll_end: data::ScrollBehavior::FetchMore,
+                            on_scroll_top: data::ScrollBehavior::FetchMore,
+                            ..Default::default()
+                        };
+                        let r = fetch_window(
+                            conversation_id,
+                            behavior,
+                            message_date,
+                            data::DEFAULT_MESSAGES_TO_TAKE / 2,
+                        )
+                        .await;
+
+                        match r {
+                            Ok((messages, behavior)) => {
+                                chat_data.write().set_active_chat(
+                                    &state.read(),
+                                    &conversation_id,
+                                    behavior,
+                                    messages,
+                                );
+                            }
+                            Err(e) => log::error!("{e}"),
+                        }
+
+                        show_shared.set(false);
+                    }
+                    ChannelCommand::DownloadAttachment { attachment, path } => {
+                        if let Some(download) = download_attachment(
+                            attachment.conversation_id,
+                            attachment.message_id,
+                            &attachment.file,
+                            path,
+                        )
+                        .await
+                        {
+                            download_streams.write().append(download);
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    let go_to = move |attachment_or_link: (Uuid, DateTime<Utc>)| {
+        ch.send(ChannelCommand::GoToMessage {
+            conversation_id,
+            message_id: attachment_or_link.0,
+            message_date: attachment_or_link.1,
+            show_shared: cx.props.show_shared.clone(),
+        });
+    };
+    let download = move |attachment: &MessageAttachment| {
+        if let Some(path) = save_path(attachment) {
+            ch.send(ChannelCommand::DownloadAttachment {
+                attachment: attachment.clone(),
+                path,
+            });
+        }
+    };
+    let sender_name = |attachment_sender: &warp::crypto::DID| {
+        state
+            .read()
+            .get_identity(attachment_sender)
+            .map(|identity| identity.display_name())
+            .unwrap_or_default()
+    };
+    let date_format = get_local_text("uplink.date-time-format");
+
+    let shared = content.read();
+    let media = shared.media.clone();
+    let is_empty = match *tab.get() {
+        Tab::Media => shared.media.is_empty(),
+        Tab::Files => shared.files.is_empty(),
+        Tab::Links => shared.links.is_empty(),
+    };
+    let tab_button = |this: Tab, icon: Icon, label: &'static str, text: &'static str| {
+        cx.render(rsx!(Button {
+            icon: icon,
+            aria_label: label.into(),
+            appearance: if *tab.get() == this {
+                Appearance::Primary
+            } else {
+                Appearance::Secondary
+            },
+            text: get_local_text(text),
+            onpress: move |_| tab.set(this),
+        }))
+    };
+
+    let items = match *tab.get() {
+        Tab::Media => rsx!(div {
+            class: "shared-media",
+            shared.media.iter().enumerate().map(|(i, attachment)| {
+                let key = format!("{}-{}", attachment.message_id, attachment.file.id());
+                let name = attachment.file.name();
+                let thumbnail = thumbnail_to_base64(&attachment.file);
+                rsx!(div {
+                    key: "{key}",
+                    class: "shared-media-item",
+                    aria_label: "shared-media-item",
+                    title: "{name}",
+                    onclick: move |_| gallery.set(Some(i)),
+                    if thumbnail.is_empty() {
+                        rsx!(IconElement {
+                            icon: return_correct_icon(&name),
+                        })
+                    } else {
+                        rsx!(img {
+                            src: "{thumbnail}",
+                        })
+                    }
+                })
+            })
+        }),
+        Tab::Files => rsx!(shared.files.iter().map(|attachment| {
+            let key = format!("{}-{}", attachment.message_id, attachment.file.id());
+            let name = attachment.file.name();
+            let details = format!(
+                "{} · {} · {}",
+                format_item_size(attachment.file.size()),
+                sender_name(&attachment.sender),
+                attachment.date.format(&date_format)
+            );
+            let message = (attachment.message_id, attachment.date);
+            let attachment = attachment.clone();
+            rsx!(div {
+                key: "{key}",
+                class: "shared-entry",
+                aria_label: "shared-file",
+                IconElement {
+                    icon: return_correct_icon(&name),
+                },
+                div {
+                    class: "shared-entry-text",
+                    p { title: "{name}", "{name}" },
+                    p { class: "shared-entry-details", "{details}" },
+                },
+                Button {
+                    icon: Icon::ArrowDownCircle,
+                    appearance: Appearance::Secondary,
+                    aria_label: "shared-download".into(),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::Top,
+                        text: get_local_text("files.download"),
+                    })),
+                    onpress: move |_| download(&attachment),
+                },
+                Button {
+                    icon: Icon::ChatBubbleBottomCenterText,
+                    appearance: Appearance::Secondary,
+                    aria_label: "shared-jump-to-message".into(),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::TopRight,
+                        text: get_local_text("files.jump-to-message"),
+                    })),
+                    onpress: move |_| go_to(message),
+                },
+            })
+        })),
+        Tab::Links => rsx!(shared.links.iter().enumerate().map(|(i, link)| {
+            let key = format!("{}-{i}", link.message_id);
+            let url = link.url.clone();
+            let details = format!(
+                "{} · {}",
+                sender_name(&link.sender),
+                link.date.format(&date_format)
+            );
+            let message = (link.message_id, link.date);
+            rsx!(div {
+                key: "{key}",
+                class: "shared-entry",
+                aria_label: "shared-link",
+                IconElement {
+                    icon: Icon::Link,
+                },
+                div {
+                    class: "shared-entry-text",
+                    a { href: "{url}", title: "{url}", "{url}" },
+                    p { class: "shared-entry-details", "{details}" },
+                },
+                Button {
+                    icon: Icon::ChatBubbleBottomCenterText,
+                    appearance: Appearance::Secondary,
+                    aria_label: "shared-jump-to-message".into(),
+                    tooltip: cx.render(rsx!(Tooltip {
+                        arrow_position: ArrowPosition::TopRight,
+                        text: get_local_text("files.jump-to-message"),
+                    })),
+                    onpress: move |_| go_to(message),
+                },
+            })
+        })),
+    };
+
+    cx.render(rsx!(
+        gallery.get().map(|start| {
+            let images: Vec<_> = media.iter().map(|a| a.file.clone()).collect();
+            let media2 = media.clone();
+            let media3 = media.clone();
+            rsx!(Gallery {
+                images: images,
+                start: start,
+                on_dismiss: move |_| gallery.set(None),
+                on_download: move |(index, temp_path): (usize, Option<PathBuf>)| {
+                    let attachment = match media2.get(index) {
+                        Some(a) => a,
+                        None => return,
+                    };
+                    match temp_path {
+                        Some(path) => {
+                            if !path.exists() {
+                                ch.send(ChannelCommand::DownloadAttachment {
+                                    attachment: attachment.clone(),
+                                    path,
+                                });
+                            }
+                        }
+                        None => download(attachment),
+                    }
+                },
+                on_jump: move |index: usize| {
+                    if let Some(a) = media3.get(index) {
+                        gallery.set(None);
+                        go_to((a.message_id, a.date));
+                    }
+                },
+            })
+        }),
+        div {
+            id: "shared-content-container",
+            aria_label: "shared-content-container",
+            div {
+                class: "shared-tabs",
+                tab_button(Tab::Media, Icon::Photo, "shared-media-tab", "messages.shared-media"),
+                tab_button(Tab::Files, Icon::Document, "shared-files-tab", "messages.shared-files"),
+                tab_button(Tab::Links, Icon::Link, "shared-links-tab", "messages.shared-links"),
+            },
+            div {
+                class: "shared-items",
+                aria_label: "shared-items",
+                if is_empty && !*loading.get() {
+                    rsx!(div {
+                        class: "shared-empty",
+                        aria_label: "shared-empty",
+                        get_local_text("messages.shared-none")
+                    })
+                }
+                items,
+                loading.then(|| rsx!(div {
+                    class: "shared-loading",
+                    Loader {
+                        spinning: true
+                    },
+                    get_local_text("messages.fetching")
+                })),
+            }
+        }
+    ))
+}
@@ -5,6 +5,7 @@ use std::{
 
 use common::{
     state::{Action, State},
+    utils::image_processing::process_attachments,
     warp_runner::{RayGunCmd, WarpCmd},
     STATIC_ARGS, WARP_CMD_CH,
 };
@@ -34,38 +35,47 @@ pub fn get_msg_ch(
                 conv_id,
                 appended_msg_id,
                 replying_to,
+                attachments,
             }) = rx.next().await
             {
                 let (tx, rx) = oneshot::channel();
-                let attachments = state
-                    .read()
-                    .get_active_chat()
-                    .map(|f| f.files_attached_to_send)
-                    .unwrap_or_default();
+                let from_chatbar = attachments.is_none();
+                let attachments = attachments.unwrap_or_else(|| {
+                    state
+                        .read()
+                        .get_active_chat()
+                        .map(|f| f.files_attached_to_send)
+                        .unwrap_or_default()
+                });
+                // metadata is stripped from images and big ones are downscaled before they leave the device
+                let image_processing = state.read().configuration.files.image_processing;
+                let to_process = attachments.clone();
+                let processed_attachments = tokio::task::spawn_blocking(move || {
+                    process_attachments(to_process, &image_processing)
+                })
+                .await
+                .unwrap_or_else(|_| attachments.clone());
                 let msg_clone = msg.clone();
                 let cmd = match replying_to {
                     Some(reply_to) => RayGunCmd::Reply {
                         conv_id,
                         reply_to,
                         msg: msg.clone(),
-                        attachments,
+                        attachments: processed_attachments,
                         rsp: tx,
                     },
                     None => RayGunCmd::SendMessage {
                         conv_id,
                         msg: msg.clone(),
-                        attachments,
+                        attachments: processed_attachments,
                         rsp: tx,
                     },
                 };
-                let attachments = state
-                    .read()
-                    .get_active_chat()
-                    .map(|f| f.files_attached_to_send)
-                    .unwrap_or_default();
-                state
-                    .write_silent()
-                    .mutate(Action::ClearChatAttachments(conv_id));
+                if from_chatbar {
+                    state
+                        .write_silent()
+                        .mutate(Action::ClearChatAttachments(conv_id));
+                }
                 let attachment_files: Vec<String> = attachments
                     .iter()
                     .map(|p| {
@@ -7,8 +7,9 @@ use common::{
     language::{get_local_text, get_local_text_with_args},
     state::{
         utils::{mention_to_did_key, parse_mentions},
-        Action, Identity, State,
+        Action, Identity, State, ToastNotification,
     },
+    voice_message::{VoiceRecorder, MAX_VOICE_MESSAGE_DURATION},
     MAX_FILES_PER_MESSAGE, STATIC_ARGS,
 };
 use dioxus::prelude::*;
@@ -78,6 +79,10 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
     let update_script = use_state(cx, String::new);
     let upload_button_menu_uuid = &*cx.use_hook(|| Uuid::new_v4().to_string());
     let show_storage_modal = use_state(cx, || false);
+    let voice_recorder: &UseRef<Option<VoiceRecorder>> = use_ref(cx, || None);
+    let recording_secs = use_state(cx, || 0);
+    let starting_recording = use_state(cx, || false);
+    let is_recording = voice_recorder.read().is_some();
 
     let suggestions = use_state(cx, || SuggestionType::None);
     let mentions = use_ref(cx, Vec::new);
@@ -144,6 +149,7 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
     // this is used to scroll to the bottom of the chat.
     let scroll_ch = coroutines::get_scroll_ch(cx, chat_data, state);
     let msg_ch: Coroutine<MsgChInput> = coroutines::get_msg_ch(cx, state);
+    let voice_msg_ch = msg_ch.clone();
     let local_typing_ch = coroutines::get_typing_ch(cx);
     let local_typing_ch2 = local_typing_ch.clone();
 
@@ -178,6 +184,23 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
         }
     });
 
+    // updates the timer shown while a voice message is recorded
+    use_future(cx, &is_recording, |is_recording| {
+        to_owned![voice_recorder, recording_secs];
+        async move {
+            while is_recording {
+                let secs = match voice_recorder.read().as_ref() {
+                    Some(recorder) => recorder.elapsed().min(MAX_VOICE_MESSAGE_DURATION).as_secs(),
+                    None => break,
+                };
+                if *recording_secs.current() != secs {
+                    recording_secs.set(secs);
+                }
+                tokio::time::sleep(Duration::from_millis(250)).await;
+            }
+        }
+    });
+
     use_future(cx, &active_chat_id, |current_chat| async move {
         loop {
             tokio::time::sleep(Duration::from_secs(STATIC_ARGS.typing_indicator_refresh)).await;
@@ -265,12 +288,79 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                 conv_id: active_chat_id,
                 appended_msg_id,
                 replying_to,
+                attachments: None,
             });
         }
     };
 
     let submit_fn2 = submit_fn.clone();
 
+    // opening the input device can take a while, so it isn't done on the UI thread
+    let start_voice_message = move || {
+        if *starting_recording.get() || voice_recorder.read().is_some() {
+            return;
+        }
+        starting_recording.set(true);
+        let input_device = state.read().settings.input_device.clone();
+        cx.spawn({
+            to_owned![state, voice_recorder, recording_secs, starting_recording];
+            async move {
+                let res = tokio::task::spawn_blocking(move || {
+                    VoiceRecorder::start(input_device.as_deref())
+                })
+                .await;
+                starting_recording.set(false);
+                match res {
+                    Ok(Ok(recorder)) => {
+                        recording_secs.set(0);
+                        voice_recorder.set(Some(recorder));
+                    }
+                    Ok(Err(e)) => show_voice_message_error(&state, e),
+                    Err(e) => show_voice_message_error(&state, e.into()),
+                }
+            }
+        });
+    };
+
+    // voice messages are sent on their own, the draft and the attached files stay in the chatbar
+    let send_voice_message = move || {
+        let recorder = match voice_recorder.write().take() {
+            Some(recorder) => recorder,
+            None => return,
+        };
+        let conv_id = active_chat_id;
+        cx.spawn({
+            to_owned![state, voice_msg_ch];
+            async move {
+                let path = match tokio::task::spawn_blocking(move || recorder.finish()).await {
+                    Ok(Ok(path)) => path,
+                    Ok(Err(e)) => return show_voice_message_error(&state, e),
+                    Err(e) => return show_voice_message_error(&state, e.into()),
+                };
+                let attachments = vec![Location::Disk { path }];
+                let replying_to = if state.read().chats().active == Some(conv_id) {
+                    state.read().chats().get_replying_to()
+                } else {
+                    None
+                };
+                if replying_to.is_some() {
+                    state.write().mutate(Action::CancelReply(conv_id));
+                }
+                let appended_msg_id =
+                    state
+                        .write()
+                        .increment_outgoing_messages_for(vec![], &attachments, conv_id);
+                voice_msg_ch.send(MsgChInput {
+                    msg: vec![],
+                    conv_id,
+                    appended_msg_id,
+                    replying_to,
+                    attachments: Some(attachments),
+                });
+            }
+        });
+    };
+
     let extensions = &state.read().ui.extensions;
     let ext_renders = extensions
         .values()
@@ -315,7 +405,7 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
         "...".to_string()
     };
 
-    let typing_users: Vec<String> = users_typing.iter().map(|id| (*id).username()).collect();
+    let typing_users: Vec<String> = users_typing.iter().map(|id| (*id).display_name()).collect();
 
     let chatbar = cx.render(rsx!(
         Chatbar {
@@ -412,7 +502,7 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                                 return;
                             }
                             let lower = tag.to_lowercase();
-                            let users: Vec<_> = chat_participants.iter().filter(|id|id.username().to_lowercase().starts_with(&lower))
+                            let users: Vec<_> = chat_participants.iter().filter(|id|id.name_starts_with(&lower))
                                 .cloned().collect();
                             suggestions.set(SuggestionType::Tag(tag, users));
                         }
@@ -446,19 +536,72 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
                 }
             },
             controls: cx.render(
-                rsx!(
-                    Button {
-                        icon: icons::outline::Shape::ChevronDoubleRight,
-                        disabled: is_loading || disabled,
-                        appearance: if * can_send.get() { Appearance::Primary } else { Appearance::Secondary },
-                        aria_label: "send-message-button".into(),
-                        onpress: move |_| submit_fn2(),
-                        tooltip: cx.render(rsx!(Tooltip {
-                            arrow_position: ArrowPosition::Bottom,
-                            text :get_local_text("uplink.send"),
-                        })),
-                    }
-                ),
+                if is_recording {
+                    let secs = *recording_secs.get();
+                    rsx!(
+                        div {
+                            class: "voice-recording",
+                            aria_label: "voice-recording",
+                            span {
+                                class: "recording-dot",
+                            },
+                            format!("{}:{:02}", secs / 60, secs % 60),
+                        },
+                        Button {
+                            icon: icons::outline::Shape::Trash,
+                            appearance: Appearance::Secondary,
+                            aria_label: "voice-message-cancel-button".into(),
+                            onpress: move |_| {
+                                if let Some(recorder) = voice_recorder.write().take() {
+                                    recorder.cancel();
+                                }
+                            },
+                            tooltip: cx.render(rsx!(Tooltip {
+                                arrow_position: ArrowPosition::Bottom,
+                                text: get_local_text("messages.voice-message-cancel"),
+                            })),
+                        },
+                        Button {
+                            icon: icons::outline::Shape::ChevronDoubleRight,
+                            disabled: is_loading || disabled,
+                            appearance: Appearance::Primary,
+                            aria_label: "voice-message-send-button".into(),
+                            onpress: move |_| send_voice_message(),
+                            tooltip: cx.render(rsx!(Tooltip {
+                                arrow_position: ArrowPosition::Bottom,
+                                text: get_local_text("messages.voice-message-send"),
+                            })),
+                        }
+                    )
+                } else {
+                    rsx!(
+                        (!STATIC_ARGS.use_mock).then(|| rsx!(
+                            Button {
+                                icon: icons::outline::Shape::Microphone,
+                                disabled: is_loading || disabled || *starting_recording.get(),
+                                loading: *starting_recording.get(),
+                                appearance: Appearance::Secondary,
+                                aria_label: "voice-message-button".into(),
+                                onpress: move |_| start_voice_message(),
+                                tooltip: cx.render(rsx!(Tooltip {
+                                    arrow_position: ArrowPosition::Bottom,
+                                    text: get_local_text("messages.voice-message"),
+                                })),
+                            }
+                        )),
+                        Button {
+                            icon: icons::outline::Shape::ChevronDoubleRight,
+                            disabled: is_loading || disabled,
+                            appearance: if * can_send.get() { Appearance::Primary } else { Appearance::Secondary },
+                            aria_label: "send-message-button".into(),
+                            onpress: move |_| submit_fn2(),
+                            tooltip: cx.render(rsx!(Tooltip {
+                                arrow_position: ArrowPosition::Bottom,
+                                text :get_local_text("uplink.send"),
+                            })),
+                        }
+                    )
+                }
             ),
             with_replying_to: (!disabled).then(|| {
                 cx.render(
@@ -627,6 +770,21 @@ pub fn get_chatbar<'a>(cx: &'a Scoped<'a, ChatProps>) -> Element<'a> {
     ))
 }
 
+fn show_voice_message_error(state: &UseSharedState<State>, e: anyhow::Error) {
+    log::error!("failed to record a voice message: {e}");
+    state
+        .write()
+        .mutate(Action::AddToastNotification(ToastNotification::init(
+            get_local_text("warning-messages.error"),
+            get_local_text_with_args(
+                "messages.voice-message-failed",
+                vec![("error", e.to_string())],
+            ),
+            Some(icons::outline::Shape::ExclamationTriangle),
+            4,
+        )));
+}
+
 fn get_platform_and_status(msg_sender: Option<&Identity>) -> (Platform, Status, String) {
     let sender = match msg_sender {
         Some(identity) => identity,
@@ -1,8 +1,7 @@
 use std::time::Duration;
 
 use common::{
-    language::get_local_text_with_args,
-    state::{Action, State, ToastNotification},
+    state::{Action, State},
     warp_runner::{FetchMessagesConfig, FetchMessagesResponse, RayGunCmd, WarpCmd},
     WARP_CMD_CH,
 };
@@ -17,9 +16,10 @@ use warp::raygun::{PinState, ReactionState};
 use crate::{
     layouts::chats::{
         data::{self, ChatBehavior, ChatData, JsMsg, ScrollBtn, DEFAULT_MESSAGES_TO_TAKE},
+        presentation::chat::coroutines::fetch_window,
         scripts,
     },
-    utils::{async_task_queue::download_stream_handler, download::get_download_path},
+    utils::{async_task_queue::download_stream_handler, download::download_attachment},
 };
 
 use super::{DownloadTracker, MessagesCommand};
@@ -440,11 +440,12 @@ pub fn fetch_later_ch(
 pub fn handle_warp_commands(
     cx: &ScopeState,
     state: &UseSharedState<State>,
+    chat_data: &UseSharedState<ChatData>,
     pending_downloads: &UseSharedState<DownloadTracker>,
 ) -> Coroutine<MessagesCommand> {
     let download_streams = download_stream_handler(cx);
     let ch = use_coroutine(cx, |mut rx: UnboundedReceiver<MessagesCommand>| {
-        to_owned![state, pending_downloads, download_streams];
+        to_owned![state, chat_data, pending_downloads, download_streams];
         async move {
             let warp_cmd_tx = WARP_CMD_CH.tx.clone();
             while let Some(cmd) = rx.next().await {
@@ -506,59 +507,10 @@ pub fn handle_warp_commands(
                         file,
                         file_path_to_download,
                     } => {
-                        let (temp_file_path, on_finish) = get_download_path(file_path_to_download);
-                        let (tx, rx) = futures::channel::oneshot::channel();
-                        if let Err(e) =
-                            warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::DownloadAttachment {
-                                conv_id,
-                                msg_id,
-                                file_name: file.name(),
-                                file_path_to_download: temp_file_path,
-                                rsp: tx,
-                            }))
+                        if let Some(download) =
+                            download_attachment(conv_id, msg_id, &file, file_path_to_download).await
                         {
-                            log::error!("failed to send warp command: {}", e);
-                            state.write().mutate(Action::AddToastNotification(
-                                ToastNotification::init(
-                                    "".into(),
-                                    get_local_text_with_args(
-                                        "files.download-failed",
-                                        vec![("file", file.name())],
-                                    ),
-                                    None,
-                                    2,
-                                ),
-                            ));
-                            if let Some(conv) = pending_downloads.write().get_mut(&conv_id) {
-                                conv.remove(&file);
-                            }
-                            continue;
-                        }
-
-                        let res = rx.await.expect("command canceled");
-                        match res {
-                            Ok(stream) => {
-                                download_streams.write().append((
-                                    stream,
-                                    file.name(),
-                                    on_finish,
-                                    true,
-                                ));
-                            }
-                            Err(e) => {
-                                state.write().mutate(Action::AddToastNotification(
-                                    ToastNotification::init(
-                                        "".into(),
-                                        get_local_text_with_args(
-                                            "files.download-failed",
-                                            vec![("file", file.name())],
-                                        ),
-                                        None,
-                                        2,
-                                    ),
-                                ));
-                                log::error!("failed to download attachment: {}", e);
-                            }
+                            download_streams.write().append(download);
                         }
                         if let Some(conv) = pending_downloads.write().get_mut(&conv_id) {
                             conv.remove(&file);
@@ -607,6 +559,37 @@ pub fn handle_warp_commands(
                             log::error!("failed to pin message: {}", e);
                         }
                     }
+                    MessagesCommand::GoToMessage {
+                        conv_id,
+                        msg_id,
+                        date,
+                    } => {
+                        let view_init = data::ViewInit {
+                            scroll_to: data::ScrollTo::ScrollUp { view_top: msg_id },
+                            msg_time: Some(date),
+                            limit: DEFAULT_MESSAGES_TO_TAKE,
+                        };
+                        let behavior = ChatBehavior {
+                            view_init,
+                            // these fields will be overwritten by fetch_window
+                            on_scroll_end: data::ScrollBehavior::FetchMore,
+                            on_scroll_top: data::ScrollBehavior::FetchMore,
+                            ..Default::default()
+                        };
+                        match fetch_window(conv_id, behavior, date, DEFAULT_MESSAGES_TO_TAKE / 2)
+                            .await
+                        {
+                            Ok((messages, behavior)) => {
+                                chat_data.write().set_active_chat(
+                                    &state.read(),
+                                    &conv_id,
+                                    behavior,
+                                    messages,
+                                );
+                            }
+                            Err(e) => log::error!("failed to go to message: {e}"),
+                        }
+                    }
                 }
             }
         }
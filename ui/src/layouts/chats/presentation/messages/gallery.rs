@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use common::{
+    is_image,
+    warp_runner::{ui_adapter::MessageAttachment, RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
+use dioxus::prelude::*;
+use tracing::log;
+use uuid::Uuid;
+use warp::constellation::file::File;
+
+use crate::components::gallery::Gallery;
+
+use super::{download_file, DownloadTracker, MessagesCommand};
+
+const PAGE_SIZE: usize = 100;
+
+#[derive(Props)]
+pub struct Props<'a> {
+    conversation_id: Uuid,
+    // the message of the clicked image
+    message_id: Uuid,
+    file: File,
+    on_dismiss: EventHandler<'a, ()>,
+}
+
+/// Shows every image sent in the conversation, starting with the clicked one
+#[allow(non_snake_case)]
+pub fn ConversationGallery<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let pending_downloads = use_shared_state::<DownloadTracker>(cx)?;
+    let ch = use_coroutine_handle::<MessagesCommand>(cx)?;
+    let conv_id = cx.props.conversation_id;
+    let attachments: &UseState<Option<Vec<MessageAttachment>>> = use_state(cx, || None);
+
+    use_future(cx, (), |_| {
+        to_owned![attachments];
+        async move {
+            let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+            // the conversation is read a page at a time, from the newest messages back
+            let mut images: Vec<MessageAttachment> = vec![];
+            let mut before = Utc::now();
+            loop {
+                let (tx, rx) = futures::channel::oneshot::channel();
+                if let Err(e) = warp_cmd_tx.send(WarpCmd::RayGun(RayGunCmd::FetchAttachments {
+                    conv_id,
+                    before,
+                    limit: PAGE_SIZE,
+                    rsp: tx,
+                })) {
+                    log::error!("failed to send warp command: {}", e);
+                    break;
+                }
+                let (page, next) = match rx.await.expect("command canceled") {
+                    Ok(r) => r,
+                    Err(e) => {
+                        log::error!("failed to fetch attachments: {}", e);
+                        break;
+                    }
+                };
+                // pages can overlap where they meet
+                let page: Vec<_> = page
+                    .into_iter()
+                    .filter(|a| is_image(&a.file.name()))
+                    .filter(|a| {
+                        !images
+                            .iter()
+                            .any(|b| b.message_id == a.message_id && b.file.id() == a.file.id())
+                    })
+                    .collect();
+                images.splice(0..0, page);
+                match next {
+                    Some(date) => before = date,
+                    None => break,
+                }
+            }
+            attachments.set(Some(images));
+        }
+    });
+
+    // the clicked image is shown by itself until the others are fetched
+    let (images, loaded) = match attachments.get() {
+        Some(all) if !all.is_empty() => (all.clone(), true),
+        _ => (vec![], false),
+    };
+    let start = images
+        .iter()
+        .position(|a| a.message_id == cx.props.message_id && a.file.id() == cx.props.file.id())
+        .unwrap_or_default();
+    let files: Vec<File> = if loaded {
+        images.iter().map(|a| a.file.clone()).collect()
+    } else {
+        vec![cx.props.file.clone()]
+    };
+    let images2 = images.clone();
+    let clicked = cx.props.file.clone();
+    let message_id = cx.props.message_id;
+
+    cx.render(rsx!(Gallery {
+        // shows the right image once the others are loaded
+        key: "{loaded}",
+        images: files,
+        start: start,
+        on_dismiss: move |_| cx.props.on_dismiss.call(()),
+        on_download: move |(index, temp_path): (usize, Option<PathBuf>)| {
+            let (msg_id, file) = match images.get(index) {
+                Some(a) => (a.message_id, a.file.clone()),
+                None => (message_id, clicked.clone()),
+            };
+            match temp_path {
+                Some(path) => {
+                    if !path.exists() {
+                        ch.send(MessagesCommand::DownloadAttachment {
+                            conv_id,
+                            msg_id,
+                            file,
+                            file_path_to_download: path,
+                        });
+                    }
+                }
+                None => download_file(&file, conv_id, msg_id, pending_downloads, ch),
+            }
+        },
+        on_jump: move |index: usize| {
+            if let Some(a) = images2.get(index) {
+                ch.send(MessagesCommand::GoToMessage {
+                    conv_id,
+                    msg_id: a.message_id,
+                    date: a.date,
+                });
+            }
+            cx.props.on_dismiss.call(());
+        },
+    }))
+}
@@ -5,10 +5,12 @@ use std::{
 };
 
 use arboard::Clipboard;
+use chrono::{DateTime, Utc};
 use dioxus::prelude::{EventHandler, *};
 
 mod coroutines;
 mod effects;
+mod gallery;
 
 use kit::{
     components::{
@@ -29,6 +31,7 @@ use common::state::{pending_message::PendingMessage, Action, Identity, State};
 use common::{
     icons::outline::Shape as Icon,
     icons::Icon as IconElement,
+    is_audio, is_image, is_video,
     language::get_local_text_with_args,
     state::{ui::EmojiDestination, ToastNotification},
 };
@@ -77,6 +80,12 @@ pub enum MessagesCommand {
         msg: Vec<String>,
     },
     Pin(raygun::Message),
+    // shows the message in the conversation, like a pinned message
+    GoToMessage {
+        conv_id: Uuid,
+        msg_id: Uuid,
+        date: DateTime<Utc>,
+    },
 }
 
 pub type DownloadTracker = HashMap<Uuid, HashSet<warp::constellation::file::File>>;
@@ -99,7 +108,7 @@ pub fn get_messages(
     effects::init_msg_scroll(cx, chat_data, eval, ch);
 
     // used by child Elements via use_coroutine_handle
-    let _ch = coroutines::handle_warp_commands(cx, state, pending_downloads);
+    let _ch = coroutines::handle_warp_commands(cx, state, chat_data, pending_downloads);
 
     let active_chat_id = chat_data.read().active_chat.id();
     // used by the intersection observer to terminate itself.
@@ -294,8 +303,9 @@ fn render_message_group<'a>(cx: Scope<'a, MessageGroupProps<'a>>) -> Element<'a>
     let sender_name = if sender.username().is_empty() {
         get_local_text("uplink.unknown")
     } else {
-        sender.username()
+        sender.display_name()
     };
+    let sender_hint = sender.username_hint();
     let active_language = &state.read().settings.language_id();
 
     let mut sender_status = sender.identity_status().into();
@@ -321,6 +331,7 @@ fn render_message_group<'a>(cx: Scope<'a, MessageGroupProps<'a>>) -> Element<'a>
             }),
             timestamp: format_timestamp_timeago(last_message_date, active_language),
             sender: sender_name.clone(),
+            sender_hint: sender_hint,
             remote: group.remote,
             children: cx.render(rsx!(wrap_messages_in_context_menu {
                 messages: &group.messages,
@@ -557,7 +568,7 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
         .map(|(emoji, users)| {
             let user_names: Vec<String> = users
                 .iter()
-                .filter_map(|id| state.read().get_identity(id).map(|x| x.username()))
+                .filter_map(|id| state.read().get_identity(id).map(|x| x.display_name()))
                 .collect();
             ReactionAdapter {
                 emoji: emoji.into(),
@@ -592,6 +603,16 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                     preview_file_in_the_message.set((false, None));
                 }
                 let file = preview_file_in_the_message.1.clone().unwrap();
+                if is_image(&file.name()) {
+                    return rsx!(gallery::ConversationGallery {
+                        conversation_id: message.inner.conversation_id(),
+                        message_id: message.inner.id(),
+                        file: file,
+                        on_dismiss: |_| {
+                            preview_file_in_the_message.set((false, None));
+                        },
+                    });
+                }
                 let file2 = file.clone();
                 rsx!(open_file_preview_modal {
                     on_dismiss: |_| {
@@ -663,7 +684,16 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                 parse_markdown: render_markdown,
                 transform_ascii_emojis: should_transform_ascii_emojis,
                 on_download: move |(file, temp_dir): (warp::constellation::file::File, Option<PathBuf>)| {
-                    if temp_dir.is_some() {
+                    let name = file.name();
+                    if let Some(path) = temp_dir.as_ref().filter(|_| is_video(&name) || is_audio(&name)) {
+                        // voice messages, audio and videos are played inline, so they are downloaded without opening the preview
+                        ch.send(MessagesCommand::DownloadAttachment {
+                            conv_id: message.inner.conversation_id(),
+                            msg_id: message.inner.id(),
+                            file: file.clone(),
+                            file_path_to_download: path.clone(),
+                        });
+                    } else if temp_dir.is_some() {
                         preview_file_in_the_message.set((true, Some(file.clone())));
                     } else {
                         download_file(&file, message.inner.conversation_id(), message.inner.id(), pending_downloads, ch);
@@ -679,13 +709,6 @@ fn render_message<'a>(cx: Scope<'a, MessageProps<'a>>) -> Element<'a> {
                     ch.send(MessagesCommand::EditMessage { conv_id: message.inner.conversation_id(), msg_id: message.inner.id(), msg})
                 }
             },
-            script {
-                r#"
-                (() => {{
-                    Prism.highlightAll();
-                }})();
-                "#
-            } // Highlights Pre blocks
         }
     ))
 }
@@ -5,12 +5,20 @@ use futures::{channel::oneshot, StreamExt};
 
 use kit::{
     components::context_menu::{ContextItem, ContextMenu, IdentityHeader},
-    elements::{input::Input, label::Label, range::Range},
+    elements::{
+        input::{Input, Options},
+        label::Label,
+        range::Range,
+    },
 };
 
 use common::{
     icons::outline::Shape as Icon,
-    state::{Identity, ToastNotification},
+    icons::Icon as IconElement,
+    state::{
+        contacts::{VerificationStatus, MAX_NICKNAME_LENGTH},
+        Identity, ToastNotification,
+    },
     warp_runner::{BlinkCmd, MultiPassCmd},
 };
 use common::{
@@ -27,7 +35,13 @@ use warp::{crypto::DID, error::Error};
 use tracing::log;
 
 use crate::{
-    components::{friends::friends_list::ShareFriendsModal, settings::sidebar::Page},
+    components::{
+        friends::{
+            contact_tags::ContactTags, friends_list::ShareFriendsModal,
+            verify_identity::VerifyIdentity,
+        },
+        settings::sidebar::Page,
+    },
     UplinkRoute,
 };
 
@@ -61,6 +75,7 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
     let settings_page = use_shared_state::<Page>(cx)?;
     let id = cx.props.id;
     let share_did = use_state(cx, || None);
+    let verify_did: &UseState<Option<DID>> = use_state(cx, || None);
 
     let identity = state
         .read()
@@ -72,6 +87,17 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
     let did = &identity.did_key();
     let did_cloned = did.clone();
     let did_cloned_2 = did.clone();
+    let did_cloned_3 = did.clone();
+    let did_cloned_4 = did.clone();
+    let did_cloned_5 = did.clone();
+    let verification = state.read().verification_status(did);
+    let nickname = identity.nickname().unwrap_or_default().to_string();
+    let note = state
+        .read()
+        .contacts
+        .get(did)
+        .map(|c| c.note.clone())
+        .unwrap_or_default();
     let chat_of = state.read().get_chat_with_friend(identity.did_key());
     let chat_send = chat_of.clone();
 
@@ -283,6 +309,7 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
                         let (tx, rx) = futures::channel::oneshot::channel();
                         let _ = warp_cmd_tx.send(WarpCmd::MultiPass(MultiPassCmd::RequestFriend {
                             id: id.to_string(),
+                            note: None,
                             outgoing_requests,
                             rsp: tx,
                         }));
@@ -337,8 +364,31 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
                     p {
                         class: "text",
                         aria_label: "profile-name-value",
-                        format!("{}", identity.username())
+                        format!("{}", identity.display_name())
                     }
+                    // the real username, when a nickname is shown above
+                    (!nickname.is_empty()).then(|| rsx!(p {
+                        class: "text profile-username",
+                        aria_label: "profile-username-value",
+                        format!("{}", identity.username())
+                    })),
+                    (!is_self && verification == VerificationStatus::Verified).then(|| rsx!(p {
+                        class: "text profile-verified",
+                        aria_label: "profile-verified",
+                        IconElement {
+                            icon: Icon::ShieldCheck,
+                        },
+                        get_local_text("friends.verified")
+                    })),
+                    (!is_self && verification == VerificationStatus::Changed).then(|| rsx!(p {
+                        class: "text verification-warning",
+                        aria_label: "verification-warning",
+                        title: get_local_text("friends.key-changed-description"),
+                        IconElement {
+                            icon: Icon::ShieldExclamation,
+                        },
+                        get_local_text("friends.key-changed")
+                    }))
                 }
                 identity.status_message().and_then(|s|{
                     cx.render(rsx!(
@@ -393,6 +443,42 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
                             hr{}
                         )
                     }
+                    div {
+                        class: "contact-details",
+                        Input {
+                            aria_label: "quick-profile-nickname".into(),
+                            placeholder: get_local_text("quickprofile.nickname-placeholder"),
+                            default_text: nickname.clone(),
+                            max_length: MAX_NICKNAME_LENGTH as i32,
+                            disable_onblur: true,
+                            options: Options {
+                                with_label: Some(get_local_text("quickprofile.nickname")),
+                                clear_on_submit: false,
+                                ..Default::default()
+                            },
+                            onreturn: move |(val, _, _): (String, bool, Code)| {
+                                state.write().mutate(Action::SetNickname(&did_cloned_3, val));
+                            }
+                        },
+                        Input {
+                            aria_label: "quick-profile-note".into(),
+                            placeholder: get_local_text("quickprofile.note-placeholder"),
+                            default_text: note.clone(),
+                            disable_onblur: true,
+                            options: Options {
+                                with_label: Some(get_local_text("quickprofile.note")),
+                                clear_on_submit: false,
+                                ..Default::default()
+                            },
+                            onreturn: move |(val, _, _): (String, bool, Code)| {
+                                state.write().mutate(Action::SetContactNote(&did_cloned_4, val));
+                            }
+                        },
+                        ContactTags {
+                            did: did.clone(),
+                        },
+                    },
+                    hr{},
                         /*ContextItem {
                         icon: Icon::UserCircle,
                         text: get_local_text("quickprofile.profile"),
@@ -432,6 +518,14 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
                             }
                         )
                     }
+                    ContextItem {
+                        icon: Icon::ShieldCheck,
+                        text: get_local_text("friends.verify"),
+                        aria_label: "quick-profile-verify".into(),
+                        onpress: move |_| {
+                            verify_did.set(Some(did_cloned_5.clone()));
+                        }
+                    },
                     if is_friend {
                         rsx!(ContextItem {
                             danger: false,
@@ -501,6 +595,10 @@ pub fn QuickProfileContext<'a>(cx: Scope<'a, QuickProfileProps<'a>>) -> Element<
                 })
             }
         }),
+        verify_did.get().as_ref().map(|did| rsx!(VerifyIdentity {
+            did: did.clone(),
+            on_dismiss: move |_| verify_did.set(None),
+        })),
         &cx.props.children
     }}))
 }
@@ -208,14 +208,7 @@ fn render_friends(cx: Scope<FriendsProps>) -> Element {
                         div {
                             key: "friend-group-{group_letter}",
                             class: "friend-group",
-                            sorted_friends.iter().filter(|friend| {
-                                let name = friend.username().to_lowercase();
-                                if name.len() < name_prefix.len() {
-                                    false
-                                } else {
-                                    name[..(name_prefix.len())] == name_prefix.to_lowercase()
-                                }
-                            } ).map(|_friend| {
+                            sorted_friends.iter().filter(|friend| friend.name_starts_with(name_prefix)).map(|_friend| {
                                 rsx!(
                                 render_friend {
                                     friend: _friend.clone(),
@@ -280,7 +273,7 @@ fn render_friend(cx: Scope<FriendProps>) -> Element {
                     onclick: move |_| {
                         update_fn();
                     },
-                    cx.props.friend.username(),
+                    cx.props.friend.display_name(),
                 },
             },
             Checkbox {
@@ -303,7 +303,7 @@ pub fn Sidebar(cx: Scope<SidebarProps>) -> Element {
                         _ => match &unwrapped_message.attachments()[..] {
                             [] => get_local_text("sidebar.chat-new"),
                             [ file ] => file.name(),
-                            _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.username()) {
+                            _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.display_name()) {
                                 Some(name) => get_local_text_with_args("sidebar.subtext", vec![("user", name)]),
                                 None => {
                                     log::error!("error calculating subtext for sidebar chat");
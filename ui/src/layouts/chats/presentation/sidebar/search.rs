@@ -29,7 +29,7 @@ pub fn search_friends<'a>(cx: Scope<'a, SearchProps<'a>>) -> Element<'a> {
     let mut friends_identities = cx.props.friends_identities.get().clone();
     let chats = cx.props.chats.get().clone();
 
-    friends_identities.sort_by_key(|identity| identity.username());
+    friends_identities.sort_by_key(|identity| identity.display_name());
 
     cx.render(rsx!(
         div {
@@ -56,12 +56,15 @@ pub fn search_friends<'a>(cx: Scope<'a, SearchProps<'a>>) -> Element<'a> {
                     })
             }
             friends_identities.iter().cloned().map(|identity| {
-                let username = identity.username();
+                let username = identity.display_name();
                 let did = identity.did_key();
                 let did2 = did.clone();
                 let search_typed_chars = cx.props.search_typed_chars.read().clone();
-                let start = username.to_lowercase().find(&search_typed_chars.to_lowercase()).unwrap_or(0);
-                let end = start + search_typed_chars.len();
+                // nothing is highlighted when the real username matched instead of the nickname
+                let (start, end) = match username.to_lowercase().find(&search_typed_chars.to_lowercase()) {
+                    Some(start) => (start, start + search_typed_chars.len()),
+                    None => (0, 0),
+                };
                 let blocked_friends: Vec<DID> = state
                     .read()
                     .blocked_fr_identities()
@@ -198,7 +201,7 @@ pub fn search_friends<'a>(cx: Scope<'a, SearchProps<'a>>) -> Element<'a> {
                         )
                     }
                     if !participants2.is_empty() &&
-                    participants2.iter().any(|identity| identity.username().to_lowercase().starts_with(&search_typed_chars.to_lowercase())
+                    participants2.iter().any(|identity| identity.name_starts_with(&search_typed_chars)
                     &&
                     identity.did_key() != state.read().did_key()
                 ) {
@@ -217,17 +220,19 @@ pub fn search_friends<'a>(cx: Scope<'a, SearchProps<'a>>) -> Element<'a> {
                         )
                     },
                     participants2.iter()
-                    .filter(|identity| identity.username().to_lowercase().starts_with(&search_typed_chars.to_lowercase())
+                    .filter(|identity| identity.name_starts_with(&search_typed_chars)
                         &&
                         identity.did_key() != state.read().did_key()
                     ).cloned()
                     .map(|identity| {
                         let typed_chars = search_typed_chars.clone();
-                        let username = identity.username();
+                        let username = identity.display_name();
                         let did = identity.did_key();
                         let did2 = did.clone();
-                        let start = username.to_lowercase().find(&typed_chars.to_lowercase()).unwrap_or(0);
-                        let end = start + typed_chars.len();
+                        let (start, end) = match username.to_lowercase().find(&typed_chars.to_lowercase()) {
+                            Some(start) => (start, start + typed_chars.len()),
+                            None => (0, 0),
+                        };
                         let blocked_friends: Vec<DID> = state
                         .read()
                         .blocked_fr_identities()
@@ -9,12 +9,12 @@ use crate::{
             audio::AudioSettings,
             developer::DeveloperSettings,
             extensions::ExtensionSettings,
+            files::FilesSettings,
             general::GeneralSettings,
             keybinds::KeybindSettings,
             licenses::Licenses,
             messages::Messages,
             notifications::NotificationSettings,
-            // files::FilesSettings,
             // privacy::PrivacySettings,
             profile::ProfileSettings,
         },
@@ -50,7 +50,7 @@ pub fn SettingsLayout(cx: Scope) -> Element {
         Page::Profile => rsx!(ProfileSettings {}),
         Page::Audio => rsx!(AudioSettings {}),
         // Page::Privacy => rsx!(PrivacySettings {}),
-        // Page::Files => rsx!(FilesSettings {}),
+        Page::Files => rsx!(FilesSettings {}),
         Page::Extensions => rsx!(ExtensionSettings {}),
         Page::Keybinds => rsx!(KeybindSettings {}),
         Page::Developer => rsx!(DeveloperSettings {}),
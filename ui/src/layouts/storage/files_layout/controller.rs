@@ -1,13 +1,22 @@
 use std::path::PathBuf;
 
 use common::{
-    state::{storage::Storage, State},
+    is_image,
+    state::{
+        storage::{Storage, StorageFilter},
+        State,
+    },
+    utils::{content_hash::DuplicateGroup, storage_usage::StorageUsage, trash::TrashEntry},
     ROOT_DIR_NAME,
 };
 use dioxus_core::ScopeState;
 use dioxus_hooks::{use_ref, UseRef, UseSharedState};
+use dioxus_html::input_data::keyboard_types::Modifiers;
 use uuid::Uuid;
-use warp::{constellation::directory::Directory, raygun::Location};
+use warp::{
+    constellation::{directory::Directory, item::Item},
+    raygun::Location,
+};
 
 use super::functions::{self, format_item_size};
 
@@ -26,6 +35,44 @@ pub struct StorageController {
     pub files_selected_to_send: Vec<Location>,
     pub current_dir_path_as_string: String,
     pub chats_selected_to_send: Vec<Uuid>,
+    pub selected_items: Vec<Uuid>,
+    // where a range selected with shift starts
+    pub selection_anchor: Option<Uuid>,
+    // items being dragged to another folder
+    pub dragged_items: Vec<Item>,
+    pub drop_target: Option<Uuid>,
+    // a move or copy waiting for the user to choose what happens to items with the same name
+    pub pending_transfer: Option<ItemsTransfer>,
+    pub filter: StorageFilter,
+    // the items in the order they are shown, after sorting and filtering
+    pub visible_items: Vec<Uuid>,
+    pub show_trash: bool,
+    pub show_transfers: bool,
+    pub show_duplicates: bool,
+    pub show_usage: bool,
+    pub trash: Vec<TrashEntry>,
+    pub trash_size: String,
+    pub duplicates: Vec<DuplicateGroup>,
+    pub usage: StorageUsage,
+}
+
+/// Items moved or copied into a directory
+#[derive(Clone)]
+pub struct ItemsTransfer {
+    pub items: Vec<Item>,
+    pub destination: Directory,
+    pub copy: bool,
+}
+
+impl ItemsTransfer {
+    /// names which are already taken in the destination
+    pub fn conflicts(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .map(|item| item.name())
+            .filter(|name| self.destination.has_item(name))
+            .collect()
+    }
 }
 
 impl StorageController {
@@ -60,6 +107,21 @@ impl StorageController {
                 .collect::<Vec<_>>()
                 .join("/"),
             chats_selected_to_send: Vec::new(),
+            selected_items: Vec::new(),
+            selection_anchor: None,
+            dragged_items: Vec::new(),
+            drop_target: None,
+            pending_transfer: None,
+            filter: StorageFilter::default(),
+            visible_items: Vec::new(),
+            show_trash: false,
+            show_transfers: false,
+            show_duplicates: false,
+            show_usage: false,
+            trash: Vec::new(),
+            trash_size: String::new(),
+            duplicates: Vec::new(),
+            usage: StorageUsage::default(),
         };
         use_ref(cx, || controller)
     }
@@ -87,12 +149,74 @@ impl StorageController {
                 format_item_size(storage.current_size),
             );
             self.storage_state = None;
+            let ids: Vec<Uuid> = self
+                .directories_list
+                .iter()
+                .map(|dir| dir.id())
+                .chain(self.files_list.iter().map(|file| file.id()))
+                .collect();
+            self.selected_items.retain(|id| ids.contains(id));
             Some(storage)
         } else {
             None
         }
     }
 
+    /// ctrl or cmd toggles an item, shift selects everything from the last item clicked
+    pub fn select_item(&mut self, id: Uuid, modifiers: Modifiers) {
+        if modifiers.contains(Modifiers::SHIFT) {
+            let ids = &self.visible_items;
+            let anchor = self.selection_anchor.unwrap_or(id);
+            let position = |id| ids.iter().position(|i| *i == id);
+            if let (Some(from), Some(to)) = (position(anchor), position(id)) {
+                self.selected_items = ids[from.min(to)..=from.max(to)].to_vec();
+            }
+            self.selection_anchor = Some(anchor);
+        } else if modifiers.intersects(Modifiers::CONTROL | Modifiers::META) {
+            if self.selected_items.contains(&id) {
+                self.selected_items.retain(|i| *i != id);
+            } else {
+                self.selected_items.push(id);
+            }
+            self.selection_anchor = Some(id);
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected_items.clear();
+        self.selection_anchor = None;
+    }
+
+    /// the images of the current directory, in the order they are shown
+    pub fn images_in_view(&self) -> Vec<warp::constellation::file::File> {
+        let mut images: Vec<_> = self
+            .files_list
+            .iter()
+            .filter(|file| is_image(&file.name()))
+            .cloned()
+            .collect();
+        images.sort_by_key(|file| self.visible_items.iter().position(|id| *id == file.id()));
+        images
+    }
+
+    /// a selected item stands for the whole selection, when it is dragged or downloaded
+    pub fn items_with_selection(&self, item: Item) -> Vec<Item> {
+        if !self.selected_items.contains(&item.id()) {
+            return vec![item];
+        }
+        self.directories_list
+            .iter()
+            .filter(|dir| self.selected_items.contains(&dir.id()))
+            .map(|dir| Item::from(dir.clone()))
+            .chain(
+                self.files_list
+                    .iter()
+                    .filter(|file| self.selected_items.contains(&file.id()))
+                    .map(|file| Item::from(file.clone())),
+            )
+            .collect()
+    }
+
     pub fn finish_renaming_item(&mut self, should_toggle: bool) {
         self.is_renaming_map.take();
         if should_toggle {
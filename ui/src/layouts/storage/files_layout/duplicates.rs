@@ -0,0 +1,148 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::return_correct_icon;
+use dioxus::prelude::*;
+use kit::elements::{
+    button::Button,
+    label::Label,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+
+use super::controller::StorageController;
+use crate::layouts::storage::functions::{format_item_size, ChanCmd};
+
+#[derive(Props)]
+pub struct Props<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+    ch: &'a Coroutine<ChanCmd>,
+}
+
+/// Files with the same content, so that the extra copies can be moved to the trash
+#[allow(non_snake_case)]
+pub fn DuplicatesView<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let storage_controller = cx.props.storage_controller;
+    let ch = cx.props.ch;
+    let groups = storage_controller.read().duplicates.clone();
+    let wasted = format_item_size(groups.iter().map(|group| group.wasted()).sum());
+    let description = if groups.is_empty() {
+        get_local_text("files.duplicates-description")
+    } else {
+        get_local_text_with_args("files.duplicates-wasted", vec![("size", wasted)])
+    };
+
+    cx.render(rsx!(
+        div {
+            class: "trash-header",
+            aria_label: "duplicates-header",
+            Button {
+                icon: Icon::ArrowLongLeft,
+                appearance: Appearance::Secondary,
+                aria_label: "duplicates-back".into(),
+                tooltip: cx.render(rsx!(
+                    Tooltip {
+                        arrow_position: ArrowPosition::TopLeft,
+                        text: get_local_text("files.back-to-files"),
+                    }
+                )),
+                onpress: move |_| {
+                    storage_controller.with_mut(|i| i.show_duplicates = false);
+                },
+            },
+            div {
+                class: "trash-title",
+                p { get_local_text("files.duplicates") },
+                p {
+                    class: "trash-description",
+                    "{description}"
+                }
+            },
+        },
+        span {
+            class: "file-parent",
+            div {
+                class: "files-list list duplicates-list",
+                aria_label: "duplicates-list",
+                groups.is_empty().then(|| rsx!(
+                    div {
+                        class: "no-files-div",
+                        Label {
+                            text: get_local_text("files.no-duplicates"),
+                        }
+                    }
+                )),
+                groups.iter().map(|group| {
+                    let name = group.files[0].path.rsplit('/').next().unwrap_or_default().to_string();
+                    let icon = return_correct_icon(&name);
+                    let summary = get_local_text_with_args(
+                        "files.duplicate-copies",
+                        vec![
+                            ("count", group.files.len().to_string()),
+                            ("size", format_item_size(group.size)),
+                        ],
+                    );
+                    let hash = group.hash.clone();
+                    let extra_copies: Vec<String> = group.files.iter().skip(1).map(|file| file.path.clone()).collect();
+                    rsx!(div {
+                        key: "{hash}",
+                        class: "duplicate-group",
+                        aria_label: "duplicate-group",
+                        div {
+                            class: "duplicate-group-header",
+                            div {
+                                class: "search-result-name",
+                                IconElement {
+                                    icon: icon,
+                                },
+                                div {
+                                    p { "{name}" },
+                                    p {
+                                        class: "search-result-location",
+                                        "{summary}"
+                                    }
+                                }
+                            },
+                            Button {
+                                icon: Icon::Trash,
+                                text: get_local_text("files.keep-first"),
+                                appearance: Appearance::Secondary,
+                                aria_label: "duplicates-keep-first".into(),
+                                onpress: move |_| {
+                                    for path in &extra_copies {
+                                        ch.send(ChanCmd::MoveFileToTrash(path.clone()));
+                                    }
+                                },
+                            },
+                        },
+                        group.files.iter().map(|file| {
+                            let id = file.id;
+                            let path = file.path.clone();
+                            rsx!(div {
+                                key: "{id}-duplicate",
+                                class: "duplicate-entry",
+                                aria_label: "duplicate-entry",
+                                title: "{path}",
+                                p { "{path}" },
+                                Button {
+                                    icon: Icon::Trash,
+                                    appearance: Appearance::Danger,
+                                    aria_label: "duplicate-move-to-trash".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::TopRight,
+                                            text: get_local_text("files.move-to-trash"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        ch.send(ChanCmd::MoveFileToTrash(path.clone()));
+                                    },
+                                },
+                            })
+                        })
+                    })
+                })
+            }
+        }
+    ))
+}
@@ -2,19 +2,28 @@ use std::path::PathBuf;
 
 use dioxus::prelude::*;
 
+use humansize::{format_size, DECIMAL};
 use kit::{
-    components::context_menu::{ContextItem, ContextMenu},
+    components::{
+        context_menu::{ContextItem, ContextMenu},
+        embeds::file_embed::media_player::MediaPlayer,
+        message::highlight::render_code_block,
+    },
     elements::loader::Loader,
     layout::modal::Modal,
 };
+use tracing::log;
 use warp::constellation::file::File;
 
 use common::{
     icons::outline::Shape as Icon,
-    is_audio, is_video,
+    icons::Icon as IconElement,
+    is_audio, is_image, is_video,
     language::get_local_text,
+    return_correct_icon,
     state::State,
     utils::{
+        file_preview::{load_file_preview, FilePreview},
         img_dimensions_preview::{IMAGE_MAX_HEIGHT, IMAGE_MAX_WIDTH},
         local_file_path::get_fixed_path_to_load_local_file,
     },
@@ -22,15 +31,10 @@ use common::{
     STATIC_ARGS,
 };
 
-#[derive(Debug, Clone, PartialEq)]
-enum FileType {
-    Video,
-    Image,
-    Audio,
-}
-
+// also used for documents and archives
 const TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD: u64 = 10000;
 const TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD: u64 = 1500;
+const FILE_PREVIEWS_DIR: &str = "file_previews";
 
 #[component(no_case_check)]
 pub fn open_file_preview_modal<'a>(
@@ -44,7 +48,8 @@ pub fn open_file_preview_modal<'a>(
         open: true,
         transparent: false,
         dont_pad: true,
-        close_on_click_inside_modal: true,
+        // documents can be scrolled and selected
+        close_on_click_inside_modal: is_image(&file.name()),
         children: cx.render(rsx!(FilePreview {
             file: file,
             on_download: |temp_path| {
@@ -55,13 +60,15 @@ pub fn open_file_preview_modal<'a>(
 }
 
 #[derive(Props)]
-struct Props<'a> {
+pub struct Props<'a> {
     file: &'a File,
     on_download: EventHandler<'a, Option<PathBuf>>,
 }
 
+/// Shows a file which is downloaded to a temporary file first. `on_download` is called with that
+/// path to load it, and without one to save it
 #[allow(non_snake_case)]
-fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+pub fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
     let state = use_shared_state::<State>(cx)?;
     let file_path_in_local_disk = use_ref(cx, PathBuf::new);
 
@@ -80,6 +87,8 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
 
     let is_video = is_video(&cx.props.file.name());
     let is_audio = is_audio(&cx.props.file.name());
+    let is_media = is_video || is_audio;
+    let is_image = is_image(&cx.props.file.name());
     if file_path_in_local_disk.read().to_string_lossy().is_empty() {
         if !temp_dir_with_file_id.exists() && *should_download.get() {
             cx.props.on_download.call(Some(temp_dir.clone()));
@@ -114,11 +123,11 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 }
                 tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
                 counter += 250;
-                if counter > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD && !is_video {
+                if counter > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD && is_image {
                     file_loading_counter.with_mut(|i| *i = counter);
                     break;
                 }
-                if counter > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD && is_video {
+                if counter > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD && !is_image {
                     file_loading_counter.with_mut(|i| *i = counter);
                     break;
                 }
@@ -144,34 +153,49 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
                 },
             )),
             if *file_loading_counter.read() > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD
-                && (is_video || is_audio) {
-                // It will show a video player with error, because take much time
-                // to download a video and is not possible to load it
-                rsx!(FileTypeTag {
-                    file_type: FileType::Video,
-                    source: "".to_string()
+                && !is_media && !thumbnail.is_empty() {
+                rsx!(ImagePreview {
+                    source: thumbnail
+                })
+            } else if *file_loading_counter.read() > TIME_TO_WAIT_FOR_VIDEO_TO_DOWNLOAD {
+                // It takes too long to download some files, so the file icon is shown instead
+                rsx!(div {
+                    class: "media-player failed",
+                    aria_label: "file-preview-fallback",
+                    div {
+                        class: "media-player-fallback",
+                        IconElement {
+                            icon: return_correct_icon(&cx.props.file.name())
+                        }
+                    }
                 })
             } else if !file_path_in_local_disk.read().exists()
                 && *file_loading_counter.read() > TIME_TO_WAIT_FOR_IMAGE_TO_DOWNLOAD
-                && !is_video {
+                && is_image {
                 // It will show image with thumbnail and not with high quality
                 // because image didn't download and is not possible to load it
-                rsx!(FileTypeTag {
-                    file_type: FileType::Image,
+                rsx!(ImagePreview {
                     source: thumbnail
                 })
-            } else if file_path_in_local_disk.read().exists() {
-                // Success for both video and image
-                rsx!(FileTypeTag {
-                    file_type: if is_video {
-                        FileType::Video
-                    } else if is_audio {
-                        FileType::Audio
-                    } else {
-                        FileType::Image
-                    },
+            } else if file_path_in_local_disk.read().exists() && is_media {
+                rsx!(div {
+                    class: "file-preview-media",
+                    MediaPlayer {
+                        path: file_path_in_local_disk.read().clone(),
+                        thumbnail: thumbnail,
+                        on_download: move |_| {},
+                    }
+                })
+            } else if file_path_in_local_disk.read().exists() && is_image {
+                rsx!(ImagePreview {
                     source: local_disk_path_fixed
                 })
+            } else if file_path_in_local_disk.read().exists() {
+                rsx!(DocumentPreview {
+                    path: file_path_in_local_disk.read().clone(),
+                    file_name: cx.props.file.name(),
+                    pages_dir: STATIC_ARGS.temp_files.join(FILE_PREVIEWS_DIR).join(cx.props.file.id().to_string()),
+                })
             } else {
                 rsx!(Loader {
                     spinning: true
@@ -182,31 +206,142 @@ fn FilePreview<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
 }
 
 #[derive(Props, PartialEq)]
-struct FileTypeTagProps {
-    file_type: FileType,
+struct ImagePreviewProps {
     source: String,
 }
 
 #[allow(non_snake_case)]
-fn FileTypeTag(cx: Scope<FileTypeTagProps>) -> Element {
-    let file_type = cx.props.file_type.clone();
+fn ImagePreview(cx: Scope<ImagePreviewProps>) -> Element {
     let source_path = cx.props.source.clone();
-    cx.render(match file_type {
-        FileType::Video | FileType::Audio => rsx!(video {
-            id: "file_preview_img",
-            aria_label: "file-preview-image",
-            max_height: IMAGE_MAX_HEIGHT,
-            max_width: IMAGE_MAX_WIDTH,
-            autoplay: true,
-            controls: true,
-            src: "{source_path}"
-        }),
-        FileType::Image => rsx!(img {
-            id: "file_preview_img",
-            aria_label: "file-preview-image",
-            max_height: IMAGE_MAX_HEIGHT,
-            max_width: IMAGE_MAX_WIDTH,
-            src: "{source_path}"
-        },),
-    })
+    cx.render(rsx!(img {
+        id: "file_preview_img",
+        aria_label: "file-preview-image",
+        max_height: IMAGE_MAX_HEIGHT,
+        max_width: IMAGE_MAX_WIDTH,
+        src: "{source_path}"
+    },))
+}
+
+#[derive(Props, PartialEq)]
+struct DocumentPreviewProps {
+    path: PathBuf,
+    file_name: String,
+    pages_dir: PathBuf,
+}
+
+#[allow(non_snake_case)]
+fn DocumentPreview(cx: Scope<DocumentPreviewProps>) -> Element {
+    let file_name = cx.props.file_name.clone();
+    let pages_dir = cx.props.pages_dir.clone();
+    let preview = use_future(cx, &cx.props.path, |path| {
+        to_owned![file_name, pages_dir];
+        async move {
+            tokio::task::spawn_blocking(move || load_file_preview(&path, &file_name, &pages_dir))
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|res| res)
+        }
+    });
+
+    let (content, truncated) = match preview.value() {
+        None => return cx.render(rsx!(Loader { spinning: true })),
+        Some(Err(e)) => {
+            log::error!("failed to preview {}: {e}", cx.props.file_name);
+            return cx.render(rsx!(div {
+                class: "media-player failed",
+                aria_label: "file-preview-fallback",
+                div {
+                    class: "media-player-fallback",
+                    IconElement {
+                        icon: return_correct_icon(&cx.props.file_name)
+                    }
+                }
+            }));
+        }
+        Some(Ok(FilePreview::Text {
+            text,
+            extension,
+            truncated,
+        })) => {
+            let html = render_code_block(
+                text,
+                Some(extension.as_str()),
+                &get_local_text("uplink.copy"),
+            );
+            (
+                rsx!(div {
+                    class: "file-preview-text",
+                    aria_label: "file-preview-text",
+                    dangerous_inner_html: "{html}",
+                }),
+                *truncated,
+            )
+        }
+        Some(Ok(FilePreview::Pdf(pages))) => (
+            rsx!(div {
+                class: "file-preview-pdf",
+                aria_label: "file-preview-pdf",
+                pages.iter().map(|page| {
+                    let src = get_fixed_path_to_load_local_file(page.clone());
+                    rsx!(img {
+                        key: "{src}",
+                        src: "{src}",
+                    })
+                })
+            }),
+            false,
+        ),
+        Some(Ok(FilePreview::Archive { entries, truncated })) => (
+            rsx!(div {
+                class: "file-preview-archive",
+                aria_label: "file-preview-archive",
+                entries.iter().map(|entry| {
+                    let size = if entry.is_dir {
+                        String::new()
+                    } else {
+                        format_size(entry.size, DECIMAL)
+                    };
+                    rsx!(div {
+                        key: "{entry.name}",
+                        class: "archive-entry",
+                        IconElement {
+                            icon: if entry.is_dir { Icon::Folder } else { return_correct_icon(&entry.name) }
+                        },
+                        span {
+                            class: "archive-entry-name",
+                            "{entry.name}"
+                        },
+                        span {
+                            class: "archive-entry-size",
+                            "{size}"
+                        }
+                    })
+                })
+            }),
+            *truncated,
+        ),
+        Some(Ok(FilePreview::Hex { lines, truncated })) => {
+            let hex = lines.join("\n");
+            (
+                rsx!(pre {
+                    class: "file-preview-hex",
+                    aria_label: "file-preview-hex",
+                    "{hex}"
+                }),
+                *truncated,
+            )
+        }
+    };
+
+    cx.render(rsx!(
+        div {
+            class: "file-preview-document",
+            aria_label: "file-preview-document",
+            content,
+            truncated.then(|| rsx!(p {
+                class: "file-preview-truncated",
+                get_local_text("files.preview-truncated")
+            }))
+        }
+    ))
 }
@@ -4,9 +4,12 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use common::icons::outline::Shape as Icon;
-use common::language::get_local_text;
+use common::is_image;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::state::storage::ConflictResolution;
 use common::state::{ui, Action, State};
 use common::upload_file_channel::CANCEL_FILE_UPLOADLISTENER;
+use common::utils::local_sync::sync_revision;
 use common::warp_runner::{RayGunCmd, WarpCmd};
 use common::WARP_CMD_CH;
 use dioxus::prelude::*;
@@ -21,16 +24,22 @@ use kit::{
         tooltip::{ArrowPosition, Tooltip},
         Appearance,
     },
-    layout::topbar::Topbar,
+    layout::{modal::Modal, topbar::Topbar},
 };
 use rfd::FileDialog;
 use uuid::Uuid;
 use warp::raygun::Location;
 
 pub mod controller;
+pub mod duplicates;
 pub mod file_preview;
+pub mod toolbar;
+pub mod transfers;
+pub mod trash;
+pub mod usage;
 
 use crate::components::files::upload_progress_bar::UploadProgressBar;
+use crate::components::gallery::Gallery;
 use crate::layouts::chats::ChatSidebar;
 use crate::layouts::slimbar::SlimbarLayout;
 use crate::layouts::storage::files_layout::file_preview::open_file_preview_modal;
@@ -44,6 +53,11 @@ use dioxus_html::input_data::keyboard_types::Code;
 use dioxus_html::input_data::keyboard_types::Modifiers;
 
 use self::controller::{StorageController, UploadFileController};
+use self::duplicates::DuplicatesView;
+use self::toolbar::StorageToolbar;
+use self::transfers::TransfersView;
+use self::trash::TrashView;
+use self::usage::UsageView;
 
 use super::functions::{self, ChanCmd, UseEvalFn};
 
@@ -81,6 +95,21 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
         }
     });
 
+    use_future(cx, (), |_| {
+        to_owned![ch];
+        async move {
+            // reload the items when the local sync changed something, so that their sync status is current
+            let mut revision = sync_revision();
+            loop {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                if sync_revision() != revision {
+                    revision = sync_revision();
+                    ch.send(ChanCmd::GetItemsFromCurrentDirectory);
+                }
+            }
+        }
+    });
+
     functions::run_verifications_and_update_storage(
         state,
         storage_controller,
@@ -90,7 +119,7 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
             .clone(),
     );
 
-    functions::get_items_from_current_directory(cx, ch);
+    functions::get_items_from_current_directory(cx, ch, state);
 
     #[cfg(not(target_os = "macos"))]
     functions::allow_drag_event_for_non_macos_systems(
@@ -150,17 +179,106 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
     cx.render(rsx!(
         if let Some(file) = storage_controller.read().show_file_modal.as_ref() {
             let file2 = file.clone();
-            rsx!(open_file_preview_modal {
+            if is_image(&file.name()) {
+                // images are shown with the others of the folder. one opened from a search result
+                // can be shown before its folder is, so it is added if it's missing
+                let mut images = storage_controller.read().images_in_view();
+                let start = match images.iter().position(|image| image.id() == file.id()) {
+                    Some(start) => start,
+                    None => {
+                        images.insert(0, file.clone());
+                        0
+                    }
+                };
+                // the gallery keeps the image it shows, so it starts over for another file or folder
+                let gallery_key = std::iter::once(file.id())
+                    .chain(images.iter().map(|image| image.id()))
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join("-");
+                let images2 = images.clone();
+                rsx!(Gallery {
+                    key: "{gallery_key}",
+                    images: images,
+                    start: start,
                     on_dismiss: |_| {
                         storage_controller.with_mut(|i| i.show_file_modal = None);
                     },
-                    on_download: move |temp_path| {
-                        let file_name = file2.clone().name();
-                        functions::download_file(&file_name, ch, temp_path);
+                    on_download: move |(index, temp_path): (usize, Option<PathBuf>)| {
+                        if let Some(image) = images2.get(index) {
+                            functions::download_file(&image.name(), ch, temp_path);
+                        }
+                    },
+                })
+            } else {
+                rsx!(open_file_preview_modal {
+                        on_dismiss: |_| {
+                            storage_controller.with_mut(|i| i.show_file_modal = None);
+                        },
+                        on_download: move |temp_path| {
+                            let file_name = file2.clone().name();
+                            functions::download_file(&file_name, ch, temp_path);
+                        },
+                        file: file.clone()
+                    }
+                )
+            }
+        }
+        if let Some(transfer) = storage_controller.read().pending_transfer.clone() {
+            let conflicts = transfer.conflicts();
+            let transfer2 = transfer.clone();
+            let transfer3 = transfer.clone();
+            rsx!(Modal {
+                open: true,
+                transparent: false,
+                with_title: get_local_text("files.name-conflict"),
+                onclose: move |_| {
+                    storage_controller.with_mut(|i| i.pending_transfer = None);
+                },
+                div {
+                    class: "name-conflict",
+                    aria_label: "name-conflict",
+                    p {
+                        get_local_text_with_args(
+                            "files.name-conflict-description",
+                            vec![
+                                ("count", conflicts.len().to_string()),
+                                ("folder", transfer.destination.name()),
+                                ("names", conflicts.join(", ")),
+                            ],
+                        )
                     },
-                    file: file.clone()
+                    div {
+                        class: "name-conflict-buttons",
+                        Button {
+                            text: get_local_text("files.keep-both"),
+                            aria_label: "name-conflict-keep-both".into(),
+                            onpress: move |_| {
+                                storage_controller.with_mut(|i| i.pending_transfer = None);
+                                ch.send(ChanCmd::TransferItems { transfer: transfer.clone(), resolution: ConflictResolution::Rename });
+                            },
+                        },
+                        Button {
+                            text: get_local_text("files.replace"),
+                            aria_label: "name-conflict-replace".into(),
+                            appearance: Appearance::Danger,
+                            onpress: move |_| {
+                                storage_controller.with_mut(|i| i.pending_transfer = None);
+                                ch.send(ChanCmd::TransferItems { transfer: transfer2.clone(), resolution: ConflictResolution::Replace });
+                            },
+                        },
+                        Button {
+                            text: get_local_text("files.skip"),
+                            aria_label: "name-conflict-skip".into(),
+                            appearance: Appearance::Secondary,
+                            onpress: move |_| {
+                                storage_controller.with_mut(|i| i.pending_transfer = None);
+                                ch.send(ChanCmd::TransferItems { transfer: transfer3.clone(), resolution: ConflictResolution::Skip });
+                            },
+                        },
+                    }
                 }
-            )
+            })
         }
         div {
             id: "files-layout",
@@ -184,9 +302,15 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
                                 files_been_uploaded2.with_mut(|i| *i = true);
                             }
                         }});
+                } else if keyboard_data.code() == Code::Escape && !storage_controller.read().selected_items.is_empty() {
+                    storage_controller.with_mut(|i| i.clear_selection());
                 }
             },
             ondragover: move |_| {
+                // items moved between folders aren't uploads
+                if !storage_controller.read().dragged_items.is_empty() {
+                    return;
+                }
                 let file_drop_event = get_drag_event();
                 if let FileDropEvent::Hovered { .. } = file_drop_event {
                     if upload_file_controller.are_files_hovering_app.with(|i| !(i)) {
@@ -234,6 +358,115 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
                                         }
                                     },
                                 },
+                                Button {
+                                    icon: Icon::Trash,
+                                    appearance: if storage_controller.read().show_trash { Appearance::Primary } else { Appearance::Secondary },
+                                    aria_label: "show-trash".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::Top,
+                                            text: get_local_text("files.trash"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        storage_controller.with_mut(|i| {
+                                            i.show_trash = !i.show_trash;
+                                            i.show_transfers = false;
+                                            i.show_duplicates = false;
+                                            i.show_usage = false;
+                                            i.clear_selection();
+                                        });
+                                        if storage_controller.read().show_trash {
+                                            ch.send(ChanCmd::GetTrash);
+                                        }
+                                    },
+                                },
+                                Button {
+                                    icon: Icon::ArrowsUpDown,
+                                    appearance: if storage_controller.read().show_transfers { Appearance::Primary } else { Appearance::Secondary },
+                                    aria_label: "show-transfers".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::Top,
+                                            text: get_local_text("files.transfers"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        storage_controller.with_mut(|i| {
+                                            i.show_transfers = !i.show_transfers;
+                                            i.show_trash = false;
+                                            i.show_duplicates = false;
+                                            i.show_usage = false;
+                                            i.clear_selection();
+                                        });
+                                    },
+                                },
+                                Button {
+                                    icon: Icon::DocumentDuplicate,
+                                    appearance: if storage_controller.read().show_duplicates { Appearance::Primary } else { Appearance::Secondary },
+                                    aria_label: "show-duplicates".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::Top,
+                                            text: get_local_text("files.duplicates"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        storage_controller.with_mut(|i| {
+                                            i.show_duplicates = !i.show_duplicates;
+                                            i.show_trash = false;
+                                            i.show_transfers = false;
+                                            i.show_usage = false;
+                                            i.clear_selection();
+                                        });
+                                        if storage_controller.read().show_duplicates {
+                                            ch.send(ChanCmd::GetDuplicates);
+                                        }
+                                    },
+                                },
+                                Button {
+                                    icon: Icon::ChartPie,
+                                    appearance: if storage_controller.read().show_usage { Appearance::Primary } else { Appearance::Secondary },
+                                    aria_label: "show-usage".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::Top,
+                                            text: get_local_text("files.usage"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        storage_controller.with_mut(|i| {
+                                            i.show_usage = !i.show_usage;
+                                            i.show_trash = false;
+                                            i.show_transfers = false;
+                                            i.show_duplicates = false;
+                                            i.clear_selection();
+                                        });
+                                        if storage_controller.read().show_usage {
+                                            ch.send(ChanCmd::GetStorageUsage);
+                                        }
+                                    },
+                                },
+                                Button {
+                                    icon: Icon::ArrowUpOnSquareStack,
+                                    appearance: Appearance::Secondary,
+                                    aria_label: "upload-folder".into(),
+                                    tooltip: cx.render(rsx!(
+                                        Tooltip {
+                                            arrow_position: ArrowPosition::Top,
+                                            text: get_local_text("files.upload-folder"),
+                                        }
+                                    )),
+                                    onpress: move |_| {
+                                        storage_controller.with_mut(|i|  i.is_renaming_map = None);
+                                        let folder_local_path = match FileDialog::new().set_directory(".").pick_folder() {
+                                            Some(path) => path,
+                                            None => return
+                                        };
+                                        functions::add_files_in_queue_to_upload(upload_file_controller.files_in_queue_to_upload, vec![folder_local_path], eval);
+                                        upload_file_controller.files_been_uploaded.with_mut(|i| *i = true);
+                                    },
+                                },
                                 Button {
                                     icon: Icon::Plus,
                                     appearance: Appearance::Secondary,
@@ -298,6 +531,15 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
                                             format!("{}", storage_controller.read().storage_size.1),
                                         }
                                     },
+                                    (!storage_controller.read().trash.is_empty()).then(|| rsx!(p {
+                                        class: "free-space",
+                                        aria_label: "free-space-trash-size",
+                                        get_local_text("files.trash-size"),
+                                        span {
+                                            class: "count",
+                                            format!("{}", storage_controller.read().trash_size),
+                                        }
+                                    })),
                                 )
                             }
                         }
@@ -322,33 +564,61 @@ pub fn FilesLayout(cx: Scope<'_>) -> Element<'_> {
                     send_ch.send((files_location, convs_id));
                 }
             },
-            FilesBreadcumbs {
-                storage_controller: storage_controller,
-                ch: ch,
-                send_files_mode: false,
-            },
-            if storage_controller.read().files_list.is_empty()
-                && storage_controller.read().directories_list.is_empty()
-                && !storage_controller.read().add_new_folder {
-                    rsx!(
-                        div {
-                            class: "no-files-div",
-                            Label {
-                                text: get_local_text("files.no-files-available"),
-                            }
-                        }
-                    )
-               } else {
-                rsx!(FilesAndFolders {
+            if storage_controller.read().show_usage {
+                rsx!(UsageView {
+                    storage_controller: storage_controller,
+                    ch: ch,
+                })
+            } else if storage_controller.read().show_duplicates {
+                rsx!(DuplicatesView {
                     storage_controller: storage_controller,
-                    on_click_share_files: move |files_pre_selected: Vec<Location>| {
-                        *files_pre_selected_to_send.write_silent() = files_pre_selected;
-                        send_files_from_storage.set(true);
-                    },
                     ch: ch,
-                    send_files_mode: false,
                 })
-               }
+            } else if storage_controller.read().show_transfers {
+                rsx!(TransfersView {
+                    storage_controller: storage_controller,
+                    ch: ch,
+                })
+            } else if storage_controller.read().show_trash {
+                rsx!(TrashView {
+                    storage_controller: storage_controller,
+                    ch: ch,
+                })
+            } else {
+                rsx!(
+                    FilesBreadcumbs {
+                        storage_controller: storage_controller,
+                        ch: ch,
+                        send_files_mode: false,
+                    },
+                    StorageToolbar {
+                        storage_controller: storage_controller,
+                    },
+                    if storage_controller.read().files_list.is_empty()
+                        && storage_controller.read().directories_list.is_empty()
+                        && !storage_controller.read().add_new_folder
+                        && !storage_controller.read().filter.is_search() {
+                            rsx!(
+                                div {
+                                    class: "no-files-div",
+                                    Label {
+                                        text: get_local_text("files.no-files-available"),
+                                    }
+                                }
+                            )
+                    } else {
+                        rsx!(FilesAndFolders {
+                            storage_controller: storage_controller,
+                            on_click_share_files: move |files_pre_selected: Vec<Location>| {
+                                *files_pre_selected_to_send.write_silent() = files_pre_selected;
+                                send_files_from_storage.set(true);
+                            },
+                            ch: ch,
+                            send_files_mode: false,
+                        })
+                    }
+                )
+            }
                 (state.read().ui.sidebar_hidden && state.read().ui.metadata.minimal_view).then(|| rsx!(
                     crate::AppNav {
                         active: crate::UplinkRoute::FilesLayout{},
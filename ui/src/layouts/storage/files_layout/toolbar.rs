@@ -0,0 +1,216 @@
+use common::icons::outline::Shape as Icon;
+use common::language::get_local_text;
+use common::state::{
+    action::ConfigAction,
+    storage::{DateFilter, FileKind, SizeFilter, SortKey, StorageLayout},
+    Action, State,
+};
+use dioxus::prelude::*;
+use dioxus_html::input_data::keyboard_types::Code;
+use kit::elements::{
+    button::Button,
+    input::{Input, Options},
+    select::Select,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+
+use super::controller::StorageController;
+
+fn kind_options() -> Vec<(Option<FileKind>, String)> {
+    vec![
+        (None, get_local_text("files.all-types")),
+        (Some(FileKind::Image), get_local_text("files.images")),
+        (Some(FileKind::Video), get_local_text("files.videos")),
+        (Some(FileKind::Audio), get_local_text("files.audio")),
+        (Some(FileKind::Document), get_local_text("files.documents")),
+    ]
+}
+
+fn size_options() -> Vec<(SizeFilter, String)> {
+    vec![
+        (SizeFilter::Any, get_local_text("files.any-size")),
+        (SizeFilter::Small, get_local_text("files.small-files")),
+        (SizeFilter::Medium, get_local_text("files.medium-files")),
+        (SizeFilter::Large, get_local_text("files.large-files")),
+    ]
+}
+
+fn date_options() -> Vec<(DateFilter, String)> {
+    vec![
+        (DateFilter::Any, get_local_text("files.any-date")),
+        (DateFilter::Today, get_local_text("files.today")),
+        (DateFilter::Week, get_local_text("files.past-week")),
+        (DateFilter::Month, get_local_text("files.past-month")),
+        (DateFilter::Year, get_local_text("files.past-year")),
+    ]
+}
+
+pub fn sort_key_label(key: SortKey) -> String {
+    match key {
+        SortKey::Name => get_local_text("files.sort-name"),
+        SortKey::Size => get_local_text("files.sort-size"),
+        SortKey::Date => get_local_text("files.sort-date"),
+        SortKey::Type => get_local_text("files.sort-type"),
+    }
+}
+
+const SORT_KEYS: [SortKey; 4] = [SortKey::Name, SortKey::Size, SortKey::Date, SortKey::Type];
+
+fn label_of<T: PartialEq>(options: &[(T, String)], value: &T) -> String {
+    options
+        .iter()
+        .find(|(v, _)| v == value)
+        .map(|(_, label)| label.clone())
+        .unwrap_or_default()
+}
+
+fn value_of<T: Copy>(options: &[(T, String)], label: &str) -> Option<T> {
+    options.iter().find(|(_, l)| l == label).map(|(v, _)| *v)
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+}
+
+/// Search, filters, sorting and the layout of the files list
+#[allow(non_snake_case)]
+pub fn StorageToolbar<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let storage_controller = cx.props.storage_controller;
+    let filter = storage_controller.read().filter.clone();
+    let files_config = state.read().configuration.files.clone();
+    let sort = files_config.storage_sort;
+    let is_list = files_config.storage_layout == StorageLayout::List;
+
+    let kinds = kind_options();
+    let sizes = size_options();
+    let dates = date_options();
+    let sort_keys: Vec<(SortKey, String)> = SORT_KEYS
+        .iter()
+        .map(|key| (*key, sort_key_label(*key)))
+        .collect();
+
+    cx.render(rsx!(div {
+        class: "storage-toolbar",
+        aria_label: "storage-toolbar",
+        div {
+            class: "storage-search",
+            Input {
+                placeholder: get_local_text("files.search-placeholder"),
+                aria_label: "storage-search-input".into(),
+                icon: Icon::MagnifyingGlass,
+                default_text: filter.query.clone(),
+                options: Options {
+                    with_clear_btn: true,
+                    react_to_esc_key: true,
+                    clear_on_submit: false,
+                    ..Options::default()
+                },
+                onchange: move |(v, _): (String, _)| {
+                    storage_controller.with_mut(|i| i.filter.query = v);
+                },
+                onreturn: move |(_, _, key): (String, _, Code)| {
+                    if key == Code::Escape {
+                        storage_controller.with_mut(|i| i.filter.query.clear());
+                    }
+                },
+            }
+        },
+        Select {
+            initial_value: label_of(&kinds, &filter.kind),
+            options: kinds.iter().map(|(_, label)| label.clone()).collect(),
+            onselect: move |label: String| {
+                if let Some(kind) = value_of(&kind_options(), &label) {
+                    storage_controller.with_mut(|i| i.filter.kind = kind);
+                }
+            }
+        },
+        Select {
+            initial_value: label_of(&sizes, &filter.size),
+            options: sizes.iter().map(|(_, label)| label.clone()).collect(),
+            onselect: move |label: String| {
+                if let Some(size) = value_of(&size_options(), &label) {
+                    storage_controller.with_mut(|i| i.filter.size = size);
+                }
+            }
+        },
+        Select {
+            initial_value: label_of(&dates, &filter.date),
+            options: dates.iter().map(|(_, label)| label.clone()).collect(),
+            onselect: move |label: String| {
+                if let Some(date) = value_of(&date_options(), &label) {
+                    storage_controller.with_mut(|i| i.filter.date = date);
+                }
+            }
+        },
+        Select {
+            initial_value: label_of(&sort_keys, &sort.key),
+            options: sort_keys.iter().map(|(_, label)| label.clone()).collect(),
+            onselect: move |label: String| {
+                if let Some(key) = SORT_KEYS.iter().find(|key| sort_key_label(**key) == label) {
+                    let sort = state.read().configuration.files.storage_sort.toggle(*key);
+                    state.write().mutate(Action::Config(ConfigAction::SetStorageSort(sort)));
+                }
+            }
+        },
+        Button {
+            icon: if sort.descending { Icon::BarsArrowDown } else { Icon::BarsArrowUp },
+            appearance: Appearance::Secondary,
+            aria_label: "storage-sort-direction".into(),
+            tooltip: cx.render(rsx!(
+                Tooltip {
+                    arrow_position: ArrowPosition::Top,
+                    text: if sort.descending { get_local_text("files.descending") } else { get_local_text("files.ascending") },
+                }
+            )),
+            onpress: move |_| {
+                let sort = state.read().configuration.files.storage_sort;
+                state.write().mutate(Action::Config(ConfigAction::SetStorageSort(sort.toggle(sort.key))));
+            },
+        },
+        Button {
+            icon: if is_list { Icon::Squares2x2 } else { Icon::ListBullet },
+            appearance: Appearance::Secondary,
+            aria_label: "storage-layout-toggle".into(),
+            tooltip: cx.render(rsx!(
+                Tooltip {
+                    arrow_position: ArrowPosition::TopRight,
+                    text: if is_list { get_local_text("files.grid-view") } else { get_local_text("files.list-view") },
+                }
+            )),
+            onpress: move |_| {
+                let layout = if is_list { StorageLayout::Grid } else { StorageLayout::List };
+                state.write().mutate(Action::Config(ConfigAction::SetStorageLayout(layout)));
+            },
+        },
+    }))
+}
+
+#[derive(Props, PartialEq)]
+pub struct SortColumnProps {
+    sort_key: SortKey,
+}
+
+/// A column header of the list layout. Clicking it sorts by that column
+#[allow(non_snake_case)]
+pub fn SortColumn(cx: Scope<SortColumnProps>) -> Element {
+    let state = use_shared_state::<State>(cx)?;
+    let key = cx.props.sort_key;
+    let sort = state.read().configuration.files.storage_sort;
+    let arrow = match (sort.key == key, sort.descending) {
+        (false, _) => "",
+        (true, true) => "\u{2193}",
+        (true, false) => "\u{2191}",
+    };
+    cx.render(rsx!(div {
+        class: format_args!("sort-column {}", if sort.key == key { "active" } else { "" }),
+        aria_label: "sort-column",
+        onclick: move |_| {
+            let sort = state.read().configuration.files.storage_sort.toggle(key);
+            state.write().mutate(Action::Config(ConfigAction::SetStorageSort(sort)));
+        },
+        "{sort_key_label(key)} {arrow}"
+    }))
+}
@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::transfers::{Transfer, TransferKind, TransferStatus, TRANSFERS};
+use common::utils::media_preview::format_duration;
+use dioxus::prelude::*;
+use kit::elements::{
+    button::Button,
+    label::Label,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+
+use super::controller::StorageController;
+use crate::layouts::storage::functions::{format_item_size, ChanCmd};
+
+// how often the list is refreshed while it is open
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+fn status_text(transfer: &Transfer) -> String {
+    match &transfer.status {
+        TransferStatus::Active => match transfer.eta() {
+            Some(eta) => {
+                get_local_text_with_args("files.transfer-eta", vec![("time", format_duration(eta))])
+            }
+            None => String::new(),
+        },
+        TransferStatus::Paused => get_local_text("files.transfer-paused"),
+        TransferStatus::Failed(reason) if reason.is_empty() => {
+            get_local_text("files.transfer-interrupted")
+        }
+        TransferStatus::Failed(reason) => {
+            get_local_text_with_args("files.transfer-failed", vec![("reason", reason.clone())])
+        }
+        TransferStatus::Completed => get_local_text("files.transfer-completed"),
+        TransferStatus::Cancelled => get_local_text("files.transfer-cancelled"),
+    }
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+    ch: &'a Coroutine<ChanCmd>,
+}
+
+/// Uploads and downloads, which can be paused, resumed and cancelled
+#[allow(non_snake_case)]
+pub fn TransfersView<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let storage_controller = cx.props.storage_controller;
+    let ch = cx.props.ch;
+    let transfers = use_state(cx, || TRANSFERS.list());
+
+    use_future(cx, (), |_| {
+        to_owned![transfers];
+        async move {
+            loop {
+                tokio::time::sleep(REFRESH_INTERVAL).await;
+                let list = TRANSFERS.list();
+                if *transfers.current() != list {
+                    transfers.set(list);
+                }
+            }
+        }
+    });
+
+    let has_finished = transfers
+        .iter()
+        .any(|t| t.is_finished() && !t.is_resumable());
+
+    cx.render(rsx!(
+        div {
+            class: "trash-header",
+            aria_label: "transfers-header",
+            Button {
+                icon: Icon::ArrowLongLeft,
+                appearance: Appearance::Secondary,
+                aria_label: "transfers-back".into(),
+                tooltip: cx.render(rsx!(
+                    Tooltip {
+                        arrow_position: ArrowPosition::TopLeft,
+                        text: get_local_text("files.back-to-files"),
+                    }
+                )),
+                onpress: move |_| {
+                    storage_controller.with_mut(|i| i.show_transfers = false);
+                },
+            },
+            div {
+                class: "trash-title",
+                p { get_local_text("files.transfers") },
+            },
+            Button {
+                icon: Icon::XMark,
+                text: get_local_text("files.clear-finished"),
+                appearance: Appearance::Secondary,
+                aria_label: "clear-finished-transfers".into(),
+                disabled: !has_finished,
+                onpress: move |_| {
+                    TRANSFERS.clear_finished();
+                    transfers.set(TRANSFERS.list());
+                },
+            },
+        },
+        span {
+            class: "file-parent",
+            div {
+                class: "files-list list transfers-list",
+                aria_label: "transfers-list",
+                transfers.is_empty().then(|| rsx!(
+                    div {
+                        class: "no-files-div",
+                        Label {
+                            text: get_local_text("files.no-transfers"),
+                        }
+                    }
+                )),
+                transfers.iter().map(|transfer| {
+                    let id = transfer.id;
+                    let name = transfer.name.clone();
+                    let icon = match transfer.kind {
+                        TransferKind::Upload => Icon::ArrowUpTray,
+                        TransferKind::Download => Icon::ArrowDownTray,
+                    };
+                    let percentage = transfer.percentage();
+                    let progress = format!(
+                        "{} / {}",
+                        format_item_size(transfer.transferred),
+                        format_item_size(transfer.size)
+                    );
+                    let speed = if transfer.status == TransferStatus::Active {
+                        get_local_text_with_args(
+                            "files.transfer-speed",
+                            vec![("size", format_item_size(transfer.speed as usize))],
+                        )
+                    } else {
+                        String::new()
+                    };
+                    let status = status_text(transfer);
+                    let can_pause = transfer.status == TransferStatus::Active;
+                    let can_resume = transfer.status == TransferStatus::Paused || transfer.is_resumable();
+                    let can_cancel = !transfer.is_finished() || transfer.is_resumable();
+                    // a failed transfer starts over from the beginning
+                    let (resume_icon, resume_text) = if transfer.is_resumable() {
+                        (Icon::ArrowPath, "files.retry")
+                    } else {
+                        (Icon::Play, "files.resume")
+                    };
+                    rsx!(div {
+                        key: "{id}-transfer",
+                        class: "transfer-entry",
+                        aria_label: "transfer-entry",
+                        title: "{name}",
+                        div {
+                            class: "search-result-name",
+                            IconElement {
+                                icon: icon,
+                            },
+                            div {
+                                p { "{name}" },
+                                div {
+                                    class: "transfer-progress",
+                                    div {
+                                        class: "transfer-progress-fill",
+                                        style: "width: {percentage}%",
+                                    }
+                                }
+                            }
+                        },
+                        span { class: "item-detail", "{progress}" },
+                        span { class: "item-detail", "{speed}" },
+                        span { class: "item-detail", aria_label: "transfer-status", "{status}" },
+                        div {
+                            class: "trash-entry-buttons",
+                            can_pause.then(|| rsx!(Button {
+                                icon: Icon::Pause,
+                                appearance: Appearance::Secondary,
+                                aria_label: "transfer-pause".into(),
+                                tooltip: cx.render(rsx!(
+                                    Tooltip {
+                                        arrow_position: ArrowPosition::Top,
+                                        text: get_local_text("files.pause"),
+                                    }
+                                )),
+                                onpress: move |_| {
+                                    TRANSFERS.pause(id);
+                                    transfers.set(TRANSFERS.list());
+                                },
+                            })),
+                            can_resume.then(|| rsx!(Button {
+                                icon: resume_icon,
+                                appearance: Appearance::Secondary,
+                                aria_label: "transfer-resume".into(),
+                                tooltip: cx.render(rsx!(
+                                    Tooltip {
+                                        arrow_position: ArrowPosition::Top,
+                                        text: get_local_text(resume_text),
+                                    }
+                                )),
+                                onpress: move |_| {
+                                    if !TRANSFERS.resume(id) {
+                                        ch.send(ChanCmd::ResumeTransfer(id));
+                                    }
+                                    transfers.set(TRANSFERS.list());
+                                },
+                            })),
+                            can_cancel.then(|| rsx!(Button {
+                                icon: Icon::XMark,
+                                appearance: Appearance::Danger,
+                                aria_label: "transfer-cancel".into(),
+                                tooltip: cx.render(rsx!(
+                                    Tooltip {
+                                        arrow_position: ArrowPosition::TopRight,
+                                        text: get_local_text("uplink.cancel"),
+                                    }
+                                )),
+                                onpress: move |_| {
+                                    TRANSFERS.cancel(id);
+                                    transfers.set(TRANSFERS.list());
+                                },
+                            })),
+                        }
+                    })
+                })
+            }
+        }
+    ))
+}
@@ -0,0 +1,203 @@
+use chrono::Utc;
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::return_correct_icon;
+use common::state::State;
+use dioxus::prelude::*;
+use kit::elements::{
+    button::Button,
+    label::Label,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+use kit::layout::modal::Modal;
+
+use super::controller::StorageController;
+use crate::layouts::storage::functions::{format_item_size, ChanCmd};
+
+#[derive(Props)]
+pub struct Props<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+    ch: &'a Coroutine<ChanCmd>,
+}
+
+/// Deleted items, which can be restored to where they were or deleted permanently
+#[allow(non_snake_case)]
+pub fn TrashView<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let storage_controller = cx.props.storage_controller;
+    let ch = cx.props.ch;
+    let confirm_empty = use_state(cx, || false);
+    let entries = storage_controller.read().trash.clone();
+    let retention_days = state.read().configuration.files.trash_retention_days;
+    let home = get_local_text("uplink.home");
+    let now = Utc::now();
+    let description = if retention_days == 0 {
+        get_local_text("files.trash-description-never")
+    } else {
+        get_local_text_with_args(
+            "files.trash-description",
+            vec![("days", retention_days.to_string())],
+        )
+    };
+    let all_ids: Vec<_> = entries.iter().map(|entry| entry.id).collect();
+
+    cx.render(rsx!(
+        confirm_empty.get().then(|| rsx!(Modal {
+            open: true,
+            transparent: false,
+            with_title: get_local_text("files.empty-trash"),
+            onclose: move |_| confirm_empty.set(false),
+            div {
+                class: "name-conflict",
+                aria_label: "empty-trash-confirm",
+                p {
+                    get_local_text_with_args(
+                        "files.empty-trash-confirm",
+                        vec![("count", all_ids.len().to_string())],
+                    )
+                },
+                div {
+                    class: "name-conflict-buttons",
+                    Button {
+                        text: get_local_text("files.empty-trash"),
+                        aria_label: "empty-trash-confirm-button".into(),
+                        appearance: Appearance::Danger,
+                        onpress: move |_| {
+                            confirm_empty.set(false);
+                            ch.send(ChanCmd::DeleteFromTrash(all_ids.clone()));
+                        },
+                    },
+                    Button {
+                        text: get_local_text("uplink.cancel"),
+                        aria_label: "empty-trash-cancel-button".into(),
+                        appearance: Appearance::Secondary,
+                        onpress: move |_| confirm_empty.set(false),
+                    },
+                }
+            }
+        })),
+        div {
+            class: "trash-header",
+            aria_label: "trash-header",
+            Button {
+                icon: Icon::ArrowLongLeft,
+                appearance: Appearance::Secondary,
+                aria_label: "trash-back".into(),
+                tooltip: cx.render(rsx!(
+                    Tooltip {
+                        arrow_position: ArrowPosition::TopLeft,
+                        text: get_local_text("files.back-to-files"),
+                    }
+                )),
+                onpress: move |_| {
+                    storage_controller.with_mut(|i| i.show_trash = false);
+                },
+            },
+            div {
+                class: "trash-title",
+                p { get_local_text("files.trash") },
+                p {
+                    class: "trash-description",
+                    "{description}"
+                }
+            },
+            Button {
+                icon: Icon::Trash,
+                text: get_local_text("files.empty-trash"),
+                appearance: Appearance::Danger,
+                aria_label: "empty-trash".into(),
+                disabled: entries.is_empty(),
+                onpress: move |_| confirm_empty.set(true),
+            },
+        },
+        span {
+            class: "file-parent",
+            div {
+                class: "files-list list trash-list",
+                aria_label: "trash-list",
+                entries.is_empty().then(|| rsx!(
+                    div {
+                        class: "no-files-div",
+                        Label {
+                            text: get_local_text("files.trash-empty"),
+                        }
+                    }
+                )),
+                entries.iter().map(|entry| {
+                    let id = entry.id;
+                    let name = entry.name.clone();
+                    let icon = if entry.is_directory { Icon::Folder } else { return_correct_icon(&name) };
+                    let location = std::iter::once(home.clone())
+                        .chain(entry.original_path.split('/').filter(|dir| !dir.is_empty()).map(str::to_string))
+                        .collect::<Vec<_>>()
+                        .join(" / ");
+                    let size = format_item_size(entry.size);
+                    let deleted_at = entry
+                        .deleted_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string();
+                    let days_left = entry
+                        .days_left(retention_days, now)
+                        .map(|days| get_local_text_with_args("files.days-left", vec![("days", days.to_string())]))
+                        .unwrap_or_default();
+                    rsx!(div {
+                        key: "{id}-trash",
+                        class: "trash-entry",
+                        aria_label: "trash-entry",
+                        title: "{name}",
+                        div {
+                            class: "search-result-name",
+                            IconElement {
+                                icon: icon,
+                            },
+                            div {
+                                p { "{name}" },
+                                p {
+                                    class: "search-result-location",
+                                    get_local_text_with_args("files.deleted-from", vec![("path", location)])
+                                }
+                            }
+                        },
+                        span { class: "item-detail", "{size}" },
+                        span { class: "item-detail", "{deleted_at}" },
+                        span { class: "item-detail", "{days_left}" },
+                        div {
+                            class: "trash-entry-buttons",
+                            Button {
+                                icon: Icon::ArrowUturnLeft,
+                                appearance: Appearance::Secondary,
+                                aria_label: "trash-restore".into(),
+                                tooltip: cx.render(rsx!(
+                                    Tooltip {
+                                        arrow_position: ArrowPosition::Top,
+                                        text: get_local_text("files.restore"),
+                                    }
+                                )),
+                                onpress: move |_| {
+                                    ch.send(ChanCmd::RestoreFromTrash(vec![id]));
+                                },
+                            },
+                            Button {
+                                icon: Icon::Trash,
+                                appearance: Appearance::Danger,
+                                aria_label: "trash-delete".into(),
+                                tooltip: cx.render(rsx!(
+                                    Tooltip {
+                                        arrow_position: ArrowPosition::TopRight,
+                                        text: get_local_text("files.delete-permanently"),
+                                    }
+                                )),
+                                onpress: move |_| {
+                                    ch.send(ChanCmd::DeleteFromTrash(vec![id]));
+                                },
+                            },
+                        }
+                    })
+                })
+            }
+        }
+    ))
+}
@@ -0,0 +1,314 @@
+use common::icons::outline::Shape as Icon;
+use common::icons::Icon as IconElement;
+use common::language::{get_local_text, get_local_text_with_args};
+use common::return_correct_icon;
+use common::state::{storage::FileKind, State};
+use dioxus::prelude::*;
+use kit::elements::{
+    button::Button,
+    label::Label,
+    tooltip::{ArrowPosition, Tooltip},
+    Appearance,
+};
+
+use super::controller::StorageController;
+use crate::layouts::storage::functions::{download_file, format_item_size, ChanCmd};
+
+fn kind_name(kind: Option<FileKind>) -> String {
+    match kind {
+        Some(FileKind::Image) => get_local_text("files.images"),
+        Some(FileKind::Video) => get_local_text("files.videos"),
+        Some(FileKind::Audio) => get_local_text("files.audio"),
+        Some(FileKind::Document) => get_local_text("files.documents"),
+        None => get_local_text("files.other-files"),
+    }
+}
+
+// each kind has its own color in the chart
+fn kind_class(kind: Option<FileKind>) -> &'static str {
+    match kind {
+        Some(FileKind::Image) => "kind-image",
+        Some(FileKind::Video) => "kind-video",
+        Some(FileKind::Audio) => "kind-audio",
+        Some(FileKind::Document) => "kind-document",
+        None => "kind-other",
+    }
+}
+
+// the folders of a path from the root, without the file name
+fn parent_folders(path: &str) -> Vec<String> {
+    let mut names: Vec<String> = path
+        .split('/')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    names.pop();
+    names
+}
+
+// goes to a folder the same way a search result is opened, starting from the root
+fn open_folders(
+    storage_controller: &UseRef<StorageController>,
+    ch: &Coroutine<ChanCmd>,
+    folders: &[String],
+) {
+    if let Some(root) = storage_controller.read().dirs_opened_ref.first().cloned() {
+        ch.send(ChanCmd::BackToPreviousDirectory(root));
+    }
+    for folder in folders {
+        ch.send(ChanCmd::OpenDirectory(folder.clone()));
+    }
+}
+
+#[derive(Props)]
+pub struct Props<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+    ch: &'a Coroutine<ChanCmd>,
+}
+
+/// What the used space is taken by, with shortcuts to clean up the biggest parts
+#[allow(non_snake_case)]
+pub fn UsageView<'a>(cx: Scope<'a, Props<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let storage_controller = cx.props.storage_controller;
+    let ch = cx.props.ch;
+    let usage = storage_controller.read().usage.clone();
+    let (max_size, current_size) = {
+        let storage = &state.read().storage;
+        (storage.max_size, storage.current_size)
+    };
+    let quota = if max_size == 0 {
+        0.0
+    } else {
+        (current_size as f64 * 100.0 / max_size as f64).min(100.0)
+    };
+    let description = get_local_text_with_args(
+        "files.usage-quota",
+        vec![
+            ("used", format_item_size(current_size)),
+            ("max", format_item_size(max_size)),
+        ],
+    );
+    let home = get_local_text("uplink.home");
+
+    cx.render(rsx!(
+        div {
+            class: "trash-header",
+            aria_label: "usage-header",
+            Button {
+                icon: Icon::ArrowLongLeft,
+                appearance: Appearance::Secondary,
+                aria_label: "usage-back".into(),
+                tooltip: cx.render(rsx!(
+                    Tooltip {
+                        arrow_position: ArrowPosition::TopLeft,
+                        text: get_local_text("files.back-to-files"),
+                    }
+                )),
+                onpress: move |_| {
+                    storage_controller.with_mut(|i| i.show_usage = false);
+                },
+            },
+            div {
+                class: "trash-title",
+                p { get_local_text("files.usage") },
+                p {
+                    class: "trash-description",
+                    "{description}"
+                }
+            },
+        },
+        span {
+            class: "file-parent",
+            div {
+                class: "files-list list usage-list",
+                aria_label: "usage-list",
+                div {
+                    class: "usage-quota",
+                    div {
+                        class: "usage-quota-fill",
+                        style: "width: {quota}%",
+                    }
+                },
+                if usage.largest.is_empty() {
+                    rsx!(div {
+                        class: "no-files-div",
+                        Label {
+                            text: get_local_text("files.no-usage"),
+                        }
+                    })
+                } else {
+                    rsx!(
+                        p { class: "usage-section-title", get_local_text("files.usage-by-type") },
+                        div {
+                            class: "usage-chart",
+                            aria_label: "usage-chart",
+                            usage.by_kind.iter().map(|kind| {
+                                let class = kind_class(kind.kind);
+                                let width = usage.percentage(kind.size);
+                                let title = format!("{}: {}", kind_name(kind.kind), format_item_size(kind.size));
+                                rsx!(div {
+                                    key: "{class}-segment",
+                                    class: "usage-chart-segment {class}",
+                                    style: "width: {width}%",
+                                    title: "{title}",
+                                })
+                            })
+                        },
+                        usage.by_kind.iter().map(|kind| {
+                            let class = kind_class(kind.kind);
+                            let name = kind_name(kind.kind);
+                            let size = format_item_size(kind.size);
+                            let count = get_local_text_with_args("files.usage-files", vec![("count", kind.count.to_string())]);
+                            rsx!(div {
+                                key: "{class}-legend",
+                                class: "usage-entry",
+                                aria_label: "usage-by-type",
+                                div {
+                                    class: "search-result-name",
+                                    span { class: "usage-legend {class}" },
+                                    p { "{name}" },
+                                },
+                                span { class: "item-detail", "{count}" },
+                                span { class: "item-detail", "{size}" },
+                            })
+                        }),
+                        p { class: "usage-section-title", get_local_text("files.usage-by-folder") },
+                        usage.by_folder.iter().map(|folder| {
+                            let name = folder.name.clone().unwrap_or_else(|| home.clone());
+                            let width = usage.percentage(folder.size);
+                            let size = format_item_size(folder.size);
+                            let count = get_local_text_with_args("files.usage-files", vec![("count", folder.count.to_string())]);
+                            let folders: Vec<String> = folder.name.iter().cloned().collect();
+                            rsx!(div {
+                                key: "{name}-folder-usage",
+                                class: "usage-entry",
+                                aria_label: "usage-by-folder",
+                                div {
+                                    class: "search-result-name",
+                                    IconElement {
+                                        icon: Icon::Folder,
+                                    },
+                                    div {
+                                        p { "{name}" },
+                                        div {
+                                            class: "usage-bar",
+                                            div {
+                                                class: "usage-bar-fill",
+                                                style: "width: {width}%",
+                                            }
+                                        }
+                                    }
+                                },
+                                span { class: "item-detail", "{count}" },
+                                span { class: "item-detail", "{size}" },
+                                div {
+                                    class: "trash-entry-buttons",
+                                    Button {
+                                        icon: Icon::FolderOpen,
+                                        appearance: Appearance::Secondary,
+                                        aria_label: "usage-open-folder".into(),
+                                        tooltip: cx.render(rsx!(
+                                            Tooltip {
+                                                arrow_position: ArrowPosition::TopRight,
+                                                text: get_local_text("files.open-folder"),
+                                            }
+                                        )),
+                                        onpress: move |_| {
+                                            open_folders(storage_controller, ch, &folders);
+                                            storage_controller.with_mut(|i| i.show_usage = false);
+                                        },
+                                    },
+                                }
+                            })
+                        }),
+                        p { class: "usage-section-title", get_local_text("files.largest-files") },
+                        usage.largest.iter().map(|file| {
+                            let id = file.id;
+                            let path = file.path.clone();
+                            let path2 = file.path.clone();
+                            let name = path.rsplit('/').next().unwrap_or_default().to_string();
+                            let name2 = name.clone();
+                            let folders = parent_folders(&path);
+                            let folders2 = folders.clone();
+                            let location = std::iter::once(home.clone())
+                                .chain(folders.iter().cloned())
+                                .collect::<Vec<_>>()
+                                .join(" / ");
+                            let size = format_item_size(file.size);
+                            rsx!(div {
+                                key: "{id}-largest",
+                                class: "usage-entry",
+                                aria_label: "largest-file",
+                                title: "{path}",
+                                div {
+                                    class: "search-result-name",
+                                    IconElement {
+                                        icon: return_correct_icon(&name),
+                                    },
+                                    div {
+                                        p { "{name}" },
+                                        p {
+                                            class: "search-result-location",
+                                            "{location}"
+                                        }
+                                    }
+                                },
+                                span { class: "item-detail" },
+                                span { class: "item-detail", "{size}" },
+                                div {
+                                    class: "trash-entry-buttons",
+                                    Button {
+                                        icon: Icon::FolderOpen,
+                                        appearance: Appearance::Secondary,
+                                        aria_label: "usage-open-folder".into(),
+                                        tooltip: cx.render(rsx!(
+                                            Tooltip {
+                                                arrow_position: ArrowPosition::Top,
+                                                text: get_local_text("files.open-folder"),
+                                            }
+                                        )),
+                                        onpress: move |_| {
+                                            open_folders(storage_controller, ch, &folders);
+                                            storage_controller.with_mut(|i| i.show_usage = false);
+                                        },
+                                    },
+                                    Button {
+                                        icon: Icon::ArrowDownCircle,
+                                        appearance: Appearance::Secondary,
+                                        aria_label: "usage-download".into(),
+                                        tooltip: cx.render(rsx!(
+                                            Tooltip {
+                                                arrow_position: ArrowPosition::Top,
+                                                text: get_local_text("files.download"),
+                                            }
+                                        )),
+                                        onpress: move |_| {
+                                            // files are downloaded from the current directory
+                                            open_folders(storage_controller, ch, &folders2);
+                                            download_file(&name2, ch, None);
+                                        },
+                                    },
+                                    Button {
+                                        icon: Icon::Trash,
+                                        appearance: Appearance::Danger,
+                                        aria_label: "usage-move-to-trash".into(),
+                                        tooltip: cx.render(rsx!(
+                                            Tooltip {
+                                                arrow_position: ArrowPosition::TopRight,
+                                                text: get_local_text("files.move-to-trash"),
+                                            }
+                                        )),
+                                        onpress: move |_| {
+                                            ch.send(ChanCmd::MoveFileToTrash(path2.clone()));
+                                        },
+                                    },
+                                }
+                            })
+                        })
+                    )
+                }
+            }
+        }
+    ))
+}
@@ -2,8 +2,13 @@
 use crate::utils::get_drag_event;
 use common::{
     language::{get_local_text, get_local_text_with_args},
-    state::{storage::Storage, Action, State, ToastNotification},
+    state::{
+        storage::{ConflictResolution, Storage},
+        Action, State, ToastNotification,
+    },
+    transfers::{Transfer, TransferStatus, TRANSFERS},
     upload_file_channel::{UploadFileAction, UPLOAD_FILE_LISTENER},
+    utils::{folder_upload::FolderUpload, image_processing::process_files},
     warp_runner::{ConstellationCmd, WarpCmd},
     WARP_CMD_CH,
 };
@@ -15,18 +20,23 @@ use dioxus_desktop::DesktopContext;
 use dioxus_hooks::{
     to_owned, use_coroutine, use_future, Coroutine, UnboundedReceiver, UseRef, UseSharedState,
 };
+use dioxus_html::input_data::keyboard_types::Modifiers;
 use futures::{channel::oneshot, StreamExt};
 use rfd::FileDialog;
 use std::{ffi::OsStr, path::PathBuf, rc::Rc, time::Duration};
 use tokio::time::sleep;
+use uuid::Uuid;
 use warp::constellation::{directory::Directory, item::Item};
 
 use crate::{
     components::files::upload_progress_bar,
-    utils::{async_task_queue::download_stream_handler, download::get_download_path},
+    utils::{
+        async_task_queue::download_stream_handler,
+        download::{finish_download, get_download_path},
+    },
 };
 
-use super::files_layout::controller::{StorageController, UploadFileController};
+use super::files_layout::controller::{ItemsTransfer, StorageController, UploadFileController};
 
 pub type UseEvalFn = Rc<dyn Fn(&str) -> Result<UseEval, EvalError>>;
 
@@ -65,12 +75,23 @@ pub fn run_verifications_and_update_storage(
     }
 }
 
-pub fn get_items_from_current_directory(cx: &ScopeState, ch: &Coroutine<ChanCmd>) {
+pub fn get_items_from_current_directory(
+    cx: &ScopeState,
+    ch: &Coroutine<ChanCmd>,
+    state: &UseSharedState<State>,
+) {
     use_future(cx, (), |_| {
-        to_owned![ch];
+        to_owned![ch, state];
         async move {
             sleep(Duration::from_secs(1)).await;
+            // items which have been in the trash for too long are deleted first. the items are
+            // listed whether or not that works
+            let retention_days = state.read().configuration.files.trash_retention_days;
+            if retention_days != 0 {
+                ch.send(ChanCmd::PurgeTrash(retention_days));
+            }
             ch.send(ChanCmd::GetItemsFromCurrentDirectory);
+            ch.send(ChanCmd::GetTrash);
         }
     });
 }
@@ -141,6 +162,26 @@ pub fn format_item_size(item_size: usize) -> String {
     size_formatted_string
 }
 
+/// the size, date and type shown in the list layout
+pub fn item_details(item: &Item) -> (String, String, String) {
+    let kind = if item.is_directory() {
+        get_local_text("files.folder")
+    } else {
+        std::path::Path::new(&item.name())
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_uppercase())
+            .unwrap_or_else(|| get_local_text("files.file"))
+    };
+    (
+        format_item_size(item.size()),
+        item.creation()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        kind,
+    )
+}
+
 pub fn download_file(
     file_name: &str,
     ch: &Coroutine<ChanCmd>,
@@ -178,6 +219,79 @@ pub fn download_file(
     });
 }
 
+/// Downloads the items as one zip, after asking where to save it
+pub fn download_items_as_zip(items: Vec<Item>, ch: &Coroutine<ChanCmd>) {
+    let file_stem = match items.as_slice() {
+        [item] => item.name(),
+        _ => get_local_text("files.files"),
+    };
+    let local_path = match FileDialog::new()
+        .set_directory(".")
+        .set_file_name(&format!("{file_stem}.zip"))
+        .add_filter("", &["zip"])
+        .save_file()
+    {
+        Some(path) => path,
+        None => return,
+    };
+    ch.send(ChanCmd::DownloadItemsAsZip { items, local_path });
+}
+
+pub fn enter_drop_target(controller: &UseRef<StorageController>, id: Uuid) {
+    let is_new_target =
+        controller.with(|i| !i.dragged_items.is_empty() && i.drop_target != Some(id));
+    if is_new_target {
+        controller.with_mut(|i| i.drop_target = Some(id));
+    }
+}
+
+pub fn leave_drop_target(controller: &UseRef<StorageController>, id: Uuid) {
+    if controller.read().drop_target == Some(id) {
+        controller.with_mut(|i| i.drop_target = None);
+    }
+}
+
+// like most file managers, holding ctrl (or option on macOS) copies instead of moving
+pub fn is_copy_drop(modifiers: Modifiers) -> bool {
+    modifiers.intersects(Modifiers::CONTROL | Modifiers::ALT)
+}
+
+/// Moves or copies the dragged items into a directory. If some names are taken there, the user is asked what to do first
+pub fn transfer_dragged_items(
+    controller: &UseRef<StorageController>,
+    ch: &Coroutine<ChanCmd>,
+    destination: Directory,
+    copy: bool,
+) {
+    let items = controller.with_mut(|i| {
+        i.drop_target = None;
+        std::mem::take(&mut i.dragged_items)
+    });
+    if !copy && destination.id() == controller.read().current_dir.id() {
+        return;
+    }
+    let items: Vec<Item> = items
+        .into_iter()
+        .filter(|item| item.id() != destination.id())
+        .collect();
+    if items.is_empty() {
+        return;
+    }
+    let transfer = ItemsTransfer {
+        items,
+        destination,
+        copy,
+    };
+    if transfer.conflicts().is_empty() {
+        ch.send(ChanCmd::TransferItems {
+            transfer,
+            resolution: ConflictResolution::default(),
+        });
+    } else {
+        controller.with_mut(|i| i.pending_transfer = Some(transfer));
+    }
+}
+
 pub fn add_files_in_queue_to_upload(
     files_in_queue_to_upload: &UseRef<Vec<PathBuf>>,
     files_path: Vec<PathBuf>,
@@ -233,11 +347,31 @@ pub enum ChanCmd {
         local_path_to_save_file: PathBuf,
         notification_download_status: bool,
     },
+    // folders and multi-selections are downloaded as a zip
+    DownloadItemsAsZip {
+        items: Vec<Item>,
+        local_path: PathBuf,
+    },
     RenameItem {
         old_name: String,
         new_name: String,
     },
     DeleteItems(Item),
+    // a file which isn't necessarily in the current directory, by its path from the root
+    MoveFileToTrash(String),
+    RestoreFromTrash(Vec<Uuid>),
+    DeleteFromTrash(Vec<Uuid>),
+    PurgeTrash(u32),
+    // these are loaded on their own, as they are not part of the current directory
+    GetTrash,
+    GetDuplicates,
+    GetStorageUsage,
+    // starts a failed upload or download again
+    ResumeTransfer(Uuid),
+    TransferItems {
+        transfer: ItemsTransfer,
+        resolution: ConflictResolution,
+    },
 }
 
 pub fn init_coroutine<'a>(
@@ -274,6 +408,14 @@ pub fn init_coroutine<'a>(
                             }
                             Err(e) => {
                                 log::error!("failed to add new directory: {}", e);
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        get_local_text("warning-messages.error"),
+                                        e.to_string(),
+                                        None,
+                                        3,
+                                    ),
+                                ));
                                 continue;
                             }
                         }
@@ -295,6 +437,14 @@ pub fn init_coroutine<'a>(
                             }
                             Err(e) => {
                                 log::error!("failed to add new directory: {}", e);
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        get_local_text("warning-messages.error"),
+                                        e.to_string(),
+                                        None,
+                                        3,
+                                    ),
+                                ));
                                 continue;
                             }
                         }
@@ -353,17 +503,27 @@ pub fn init_coroutine<'a>(
                         local_path_to_save_file,
                         notification_download_status,
                     } => {
-                        let (local_path_to_save_file, on_finish) =
-                            get_download_path(local_path_to_save_file);
+                        let (partial_path, on_finish) =
+                            get_download_path(local_path_to_save_file.clone());
+                        let transfer = Transfer::download(
+                            file_name.clone(),
+                            local_path_to_save_file,
+                            partial_path.clone(),
+                            0,
+                        );
+                        let transfer_id = transfer.id;
+                        TRANSFERS.start(transfer);
                         let (tx, rx) = oneshot::channel();
 
                         if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
                             ConstellationCmd::DownloadFile {
                                 file_name: file_name.clone(),
-                                local_path_to_save_file,
+                                local_path_to_save_file: partial_path,
+                                transfer: transfer_id,
                                 rsp: tx,
                             },
                         )) {
+                            TRANSFERS.finish(transfer_id, TransferStatus::Failed(e.to_string()));
                             if notification_download_status {
                                 state.write().mutate(Action::AddToastNotification(
                                     ToastNotification::init(
@@ -389,9 +549,12 @@ pub fn init_coroutine<'a>(
                                     file_name,
                                     on_finish,
                                     notification_download_status,
+                                    transfer_id,
                                 ));
                             }
                             Err(error) => {
+                                TRANSFERS
+                                    .finish(transfer_id, TransferStatus::Failed(error.to_string()));
                                 if notification_download_status {
                                     state.write().mutate(Action::AddToastNotification(
                                         ToastNotification::init(
@@ -410,6 +573,64 @@ pub fn init_coroutine<'a>(
                             }
                         }
                     }
+                    ChanCmd::DownloadItemsAsZip { items, local_path } => {
+                        let file_name = local_path
+                            .file_name()
+                            .map(|name| name.to_string_lossy().to_string())
+                            .unwrap_or_default();
+                        let (partial_path, on_finish) = get_download_path(local_path.clone());
+                        // a zip can't be resumed, so no storage directory is set for it
+                        let transfer = Transfer::download(
+                            file_name.clone(),
+                            local_path,
+                            partial_path.clone(),
+                            items.iter().map(|item| item.size()).sum(),
+                        );
+                        let transfer_id = transfer.id;
+                        TRANSFERS.start(transfer);
+                        let (tx, rx) = oneshot::channel();
+
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                            ConstellationCmd::DownloadItemsAsZip {
+                                items,
+                                local_path: partial_path,
+                                rsp: tx,
+                            },
+                        )) {
+                            TRANSFERS.finish(transfer_id, TransferStatus::Failed(e.to_string()));
+                            log::error!("failed to download items as zip {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(stream) => {
+                                download_queue.write().append((
+                                    stream,
+                                    file_name,
+                                    on_finish,
+                                    true,
+                                    transfer_id,
+                                ));
+                            }
+                            Err(error) => {
+                                TRANSFERS
+                                    .finish(transfer_id, TransferStatus::Failed(error.to_string()));
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        "".into(),
+                                        get_local_text_with_args(
+                                            "files.download-failed",
+                                            vec![("file", file_name)],
+                                        ),
+                                        None,
+                                        2,
+                                    ),
+                                ));
+                                log::error!("failed to download items as zip: {}", error);
+                            }
+                        }
+                    }
                     ChanCmd::RenameItem { old_name, new_name } => {
                         let (tx, rx) = oneshot::channel::<Result<Storage, warp::error::Error>>();
 
@@ -434,6 +655,39 @@ pub fn init_coroutine<'a>(
                                     "failed to update uplink storage with renamed item: {}",
                                     e
                                 );
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        get_local_text("warning-messages.error"),
+                                        e.to_string(),
+                                        None,
+                                        3,
+                                    ),
+                                ));
+                                continue;
+                            }
+                        }
+                    }
+                    ChanCmd::MoveFileToTrash(path) => {
+                        let (tx, rx) = oneshot::channel::<Result<Storage, warp::error::Error>>();
+
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                            ConstellationCmd::MoveFileToTrash {
+                                path: path.clone(),
+                                rsp: tx,
+                            },
+                        )) {
+                            log::error!("failed to move {path} to the trash {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(storage) => {
+                                controller.with_mut(|i| i.storage_state = Some(storage));
+                                reload_trash_views(&controller).await;
+                            }
+                            Err(e) => {
+                                log::error!("failed to move {path} to the trash {}", e);
                                 continue;
                             }
                         }
@@ -455,6 +709,7 @@ pub fn init_coroutine<'a>(
                         match rsp {
                             Ok(storage) => {
                                 controller.with_mut(|i| i.storage_state = Some(storage));
+                                reload_trash_views(&controller).await;
                             }
                             Err(e) => {
                                 log::error!("failed to delete items {}, item {:?}", e, item.name());
@@ -462,6 +717,207 @@ pub fn init_coroutine<'a>(
                             }
                         }
                     }
+                    ChanCmd::RestoreFromTrash(ids) => {
+                        let (tx, rx) = oneshot::channel::<Result<Storage, warp::error::Error>>();
+
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                            ConstellationCmd::RestoreFromTrash { ids, rsp: tx },
+                        )) {
+                            log::error!("failed to restore items {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(storage) => {
+                                controller.with_mut(|i| i.storage_state = Some(storage));
+                                reload_trash_views(&controller).await;
+                            }
+                            Err(e) => {
+                                log::error!("failed to restore items {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    ChanCmd::DeleteFromTrash(ids) => {
+                        let (tx, rx) = oneshot::channel::<Result<Storage, warp::error::Error>>();
+
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                            ConstellationCmd::DeleteFromTrash { ids, rsp: tx },
+                        )) {
+                            log::error!("failed to delete items from the trash {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(storage) => {
+                                controller.with_mut(|i| i.storage_state = Some(storage));
+                                reload_trash_views(&controller).await;
+                            }
+                            Err(e) => {
+                                log::error!("failed to delete items from the trash {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    ChanCmd::PurgeTrash(retention_days) => {
+                        let (tx, rx) = oneshot::channel::<Result<Storage, warp::error::Error>>();
+
+                        if let Err(e) =
+                            warp_cmd_tx.send(WarpCmd::Constellation(ConstellationCmd::PurgeTrash {
+                                retention_days,
+                                rsp: tx,
+                            }))
+                        {
+                            log::error!("failed to purge the trash {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(storage) => {
+                                controller.with_mut(|i| i.storage_state = Some(storage));
+                                reload_trash_views(&controller).await;
+                            }
+                            Err(e) => {
+                                log::error!("failed to purge the trash {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    ChanCmd::GetTrash => load_trash(&controller).await,
+                    ChanCmd::GetDuplicates => load_duplicates(&controller).await,
+                    ChanCmd::GetStorageUsage => load_storage_usage(&controller).await,
+                    ChanCmd::ResumeTransfer(id) => {
+                        let (tx, rx) = oneshot::channel();
+
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                            ConstellationCmd::ResumeTransfer { id, rsp: tx },
+                        )) {
+                            log::error!("failed to resume transfer {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        match rsp {
+                            Ok(Some(stream)) => {
+                                let transfer = match TRANSFERS.get(id) {
+                                    Some(transfer) => transfer,
+                                    None => continue,
+                                };
+                                let partial_path = transfer
+                                    .partial_path
+                                    .unwrap_or_else(|| transfer.local_path.clone());
+                                download_queue.write().append((
+                                    stream,
+                                    transfer.name,
+                                    finish_download(partial_path, transfer.local_path),
+                                    true,
+                                    id,
+                                ));
+                            }
+                            // uploads report their progress like any other upload
+                            Ok(None) => {}
+                            Err(e) => {
+                                log::error!("failed to resume transfer {}", e);
+                                let file = TRANSFERS.get(id).map(|t| t.name).unwrap_or_default();
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        get_local_text_with_args(
+                                            "files.retry-failed",
+                                            vec![("file", file)],
+                                        ),
+                                        e.to_string(),
+                                        None,
+                                        5,
+                                    ),
+                                ));
+                                continue;
+                            }
+                        }
+                    }
+                    ChanCmd::TransferItems {
+                        transfer,
+                        resolution,
+                    } => {
+                        let (tx, rx) = oneshot::channel::<
+                            Result<(Storage, Vec<(String, String)>), warp::error::Error>,
+                        >();
+                        let ItemsTransfer {
+                            items,
+                            destination,
+                            copy,
+                        } = transfer;
+                        let cmd = if copy {
+                            ConstellationCmd::CopyItems {
+                                items,
+                                destination,
+                                resolution,
+                                rsp: tx,
+                            }
+                        } else {
+                            ConstellationCmd::MoveItems {
+                                items,
+                                destination,
+                                resolution,
+                                rsp: tx,
+                            }
+                        };
+                        if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(cmd)) {
+                            log::error!("failed to move items {}", e);
+                            continue;
+                        }
+
+                        let rsp = rx.await.expect("command canceled");
+                        let failures_text = if copy {
+                            "files.copy-failures"
+                        } else {
+                            "files.move-failures"
+                        };
+                        let failures = match rsp {
+                            Ok((storage, failures)) => {
+                                controller.with_mut(|i| {
+                                    i.clear_selection();
+                                    i.storage_state = Some(storage);
+                                });
+                                reload_trash_views(&controller).await;
+                                failures
+                            }
+                            Err(e) => {
+                                log::error!("failed to move items {}", e);
+                                state.write().mutate(Action::AddToastNotification(
+                                    ToastNotification::init(
+                                        get_local_text("warning-messages.error"),
+                                        e.to_string(),
+                                        None,
+                                        5,
+                                    ),
+                                ));
+                                continue;
+                            }
+                        };
+                        if failures.is_empty() {
+                            continue;
+                        }
+                        let count = failures.len();
+                        let names = failures
+                            .into_iter()
+                            .map(|(name, _)| name)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        state.write().mutate(Action::AddToastNotification(
+                            ToastNotification::init(
+                                "".into(),
+                                get_local_text_with_args(
+                                    failures_text,
+                                    vec![("count", count.to_string()), ("names", names)],
+                                ),
+                                None,
+                                5,
+                            ),
+                        ));
+                    }
                 }
             }
         }
@@ -469,6 +925,75 @@ pub fn init_coroutine<'a>(
     ch
 }
 
+async fn load_trash(controller: &UseRef<StorageController>) {
+    let (tx, rx) = oneshot::channel();
+    if let Err(e) = WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Constellation(ConstellationCmd::GetTrash {
+            rsp: tx,
+        }))
+    {
+        log::error!("failed to get the trash {}", e);
+        return;
+    }
+    match rx.await.expect("command canceled") {
+        Ok((trash, trash_size)) => controller.with_mut(|i| {
+            i.trash = trash;
+            i.trash_size = format_item_size(trash_size);
+        }),
+        Err(e) => log::error!("failed to get the trash {}", e),
+    }
+}
+
+async fn load_duplicates(controller: &UseRef<StorageController>) {
+    let (tx, rx) = oneshot::channel();
+    if let Err(e) = WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Constellation(ConstellationCmd::GetDuplicates {
+            rsp: tx,
+        }))
+    {
+        log::error!("failed to get duplicates {}", e);
+        return;
+    }
+    match rx.await.expect("command canceled") {
+        Ok(duplicates) => controller.with_mut(|i| i.duplicates = duplicates),
+        Err(e) => log::error!("failed to get duplicates {}", e),
+    }
+}
+
+async fn load_storage_usage(controller: &UseRef<StorageController>) {
+    let (tx, rx) = oneshot::channel();
+    if let Err(e) = WARP_CMD_CH
+        .tx
+        .send(WarpCmd::Constellation(ConstellationCmd::GetStorageUsage {
+            rsp: tx,
+        }))
+    {
+        log::error!("failed to get storage usage {}", e);
+        return;
+    }
+    match rx.await.expect("command canceled") {
+        Ok(usage) => controller.with_mut(|i| i.usage = usage),
+        Err(e) => log::error!("failed to get storage usage {}", e),
+    }
+}
+
+// after items were moved in or out of the trash. duplicates and usage are only reloaded while they are shown
+async fn reload_trash_views(controller: &UseRef<StorageController>) {
+    load_trash(controller).await;
+    let (show_duplicates, show_usage) = {
+        let controller = controller.read();
+        (controller.show_duplicates, controller.show_usage)
+    };
+    if show_duplicates {
+        load_duplicates(controller).await;
+    }
+    if show_usage {
+        load_storage_usage(controller).await;
+    }
+}
+
 /// Upload files has many states to manage
 /// 1. It is necessary to check if any file is being uploaded, hence the use of `use_future`.
 /// 2. It was necessary to use the global channel to send the command to upload the files,
@@ -502,6 +1027,42 @@ pub fn start_upload_file_listener(
             while let Some(cmd) = ch.recv().await {
                 match cmd {
                     UploadFileAction::UploadFiles(files_path) => {
+                        let image_processing = state.read().configuration.files.image_processing;
+                        let (folders, files_path): (Vec<_>, Vec<_>) =
+                            files_path.into_iter().partition(|path| path.is_dir());
+                        for path in folders {
+                            // processed images keep their file name, so the folder structure stays the same
+                            let folder = tokio::task::spawn_blocking(move || {
+                                let mut folder = FolderUpload::read(&path);
+                                let (local, directories): (Vec<_>, Vec<_>) =
+                                    folder.files.into_iter().unzip();
+                                let local = process_files(local, &image_processing);
+                                folder.files = local.into_iter().zip(directories).collect();
+                                folder
+                            })
+                            .await;
+                            let folder = match folder {
+                                Ok(folder) => folder,
+                                Err(e) => {
+                                    log::error!("failed to read folder: {e}");
+                                    continue;
+                                }
+                            };
+                            if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
+                                ConstellationCmd::UploadFolder { folder },
+                            )) {
+                                log::error!("failed to upload folder {}", e);
+                            }
+                        }
+                        if files_path.is_empty() {
+                            continue;
+                        }
+                        let to_process = files_path.clone();
+                        let files_path = tokio::task::spawn_blocking(move || {
+                            process_files(to_process, &image_processing)
+                        })
+                        .await
+                        .unwrap_or(files_path);
                         if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(
                             ConstellationCmd::UploadFiles { files_path },
                         )) {
@@ -590,6 +1151,42 @@ pub fn start_upload_file_listener(
                             get_local_text("files.finishing-upload"),
                         );
                     }
+                    UploadFileAction::Duplicate(file, existing) => {
+                        state
+                            .write()
+                            .mutate(common::state::Action::AddToastNotification(
+                                ToastNotification::init(
+                                    "".into(),
+                                    get_local_text_with_args(
+                                        "files.duplicate-upload",
+                                        vec![("file", file), ("path", existing)],
+                                    ),
+                                    None,
+                                    5,
+                                ),
+                            ));
+                    }
+                    UploadFileAction::UploadFailures(failures) => {
+                        let count = failures.len();
+                        let files = failures
+                            .into_iter()
+                            .map(|(file, _)| file)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        state
+                            .write()
+                            .mutate(common::state::Action::AddToastNotification(
+                                ToastNotification::init(
+                                    "".into(),
+                                    get_local_text_with_args(
+                                        "files.upload-failures",
+                                        vec![("count", count.to_string()), ("files", files)],
+                                    ),
+                                    None,
+                                    5,
+                                ),
+                            ));
+                    }
                     UploadFileAction::Finished(storage) => {
                         if files_in_queue_to_upload.read().is_empty() {
                             *files_been_uploaded.write_silent() = false;
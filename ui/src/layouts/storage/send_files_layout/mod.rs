@@ -51,7 +51,7 @@ pub fn SendFilesLayout<'a>(cx: Scope<'a, SendFilesProps<'a>>) -> Element<'a> {
     let first_render = use_ref(cx, || true);
     let ch: &Coroutine<ChanCmd> = functions::init_coroutine(cx, storage_controller, state);
     let in_files = send_files_start_location.eq(&SendFilesStartLocation::Storage);
-    functions::get_items_from_current_directory(cx, ch);
+    functions::get_items_from_current_directory(cx, ch, state);
 
     functions::run_verifications_and_update_storage(state, storage_controller, vec![]);
 
@@ -147,7 +147,7 @@ fn ChatsToSelect<'a>(cx: Scope<'a, ChatsToSelectProps<'a>>) -> Element<'a> {
                 _ => match &unwrapped_message.attachments()[..] {
                     [] => get_local_text("sidebar.chat-new"),
                     [ file ] => file.name(),
-                    _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.username()) {
+                    _ => match participants.iter().find(|p| p.did_key()  == unwrapped_message.sender()).map(|x| x.display_name()) {
                         Some(name) => get_local_text_with_args("sidebar.subtext", vec![("user", name)]),
                         None => {
                             log::error!("error calculating subtext for sidebar chat");
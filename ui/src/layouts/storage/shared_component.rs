@@ -1,21 +1,31 @@
-use crate::layouts::storage::functions::{self, download_file, ChanCmd};
+use crate::layouts::storage::functions::{self, download_file, download_items_as_zip, ChanCmd};
 use crate::layouts::storage::send_files_layout::send_files_components::{
     toggle_selected_file, FileCheckbox,
 };
 
 use super::files_layout::controller::StorageController;
+use super::files_layout::toolbar::SortColumn;
+use chrono::Utc;
 use common::icons::outline::Shape as Icon;
 use common::icons::Icon as IconElement;
+use common::state::storage::{SortKey, StorageLayout};
 use common::state::{State, ToastNotification};
+use common::utils::local_sync::{sync_status, SyncStatus};
+use common::utils::storage_search::{
+    search_files, sort_directories, sort_files, sort_search_results,
+};
 use common::warp_runner::thumbnail_to_base64;
-use common::{is_audio, is_video};
-use common::{language::get_local_text, ROOT_DIR_NAME};
+use common::{
+    language::{get_local_text, get_local_text_with_args},
+    return_correct_icon, ROOT_DIR_NAME,
+};
 
 use dioxus::html::input_data::keyboard_types::Code;
 use dioxus::prelude::*;
 use kit::components::context_menu::{ContextItem, ContextMenu};
 use kit::elements::file::File;
 use kit::elements::folder::Folder;
+use kit::elements::label::Label;
 use warp::constellation::item::Item;
 use warp::raygun::Location;
 
@@ -40,15 +50,24 @@ pub fn FilesBreadcumbs<'a>(cx: Scope<'a, FilesBreadcumbsProps<'a>>) -> Element<'
         margin: format_args!("{}", if send_files_mode {"var(--gap) 0"} else {"var(--gap)"}),
         storage_controller.read().dirs_opened_ref.iter().enumerate().map(|(index, dir)| {
             let directory = dir.clone();
+            let directory2 = dir.clone();
+            let dir_id = dir.id();
+            let is_drop_target = storage_controller.read().drop_target == Some(dir_id);
             let dir_name = dir.name();
             if dir_name == ROOT_DIR_NAME && index == 0 {
                 let home_text = get_local_text("uplink.home");
                 rsx!(div {
-                    class: "crumb",
+                    class: format_args!("crumb {}", if is_drop_target { "drop-target" } else { "" }),
                     aria_label: "crumb",
+                    prevent_default: "ondragover ondrop",
                     onclick: move |_| {
                         ch.send(ChanCmd::BackToPreviousDirectory(directory.clone()));
                     },
+                    ondragover: move |_| functions::enter_drop_target(storage_controller, dir_id),
+                    ondragleave: move |_| functions::leave_drop_target(storage_controller, dir_id),
+                    ondrop: move |evt| {
+                        functions::transfer_dragged_items(storage_controller, ch, directory2.clone(), functions::is_copy_drop(evt.mouse.modifiers()));
+                    },
                     IconElement {
                         icon: Icon::Home,
                     },
@@ -60,10 +79,16 @@ pub fn FilesBreadcumbs<'a>(cx: Scope<'a, FilesBreadcumbsProps<'a>>) -> Element<'
             } else {
                 let folder_name_formatted = functions::format_item_name(dir_name);
                 rsx!(div {
-                    class: "crumb",
+                    class: format_args!("crumb {}", if is_drop_target { "drop-target" } else { "" }),
+                    prevent_default: "ondragover ondrop",
                     onclick: move |_| {
                         ch.send(ChanCmd::BackToPreviousDirectory(directory.clone()));
                     },
+                    ondragover: move |_| functions::enter_drop_target(storage_controller, dir_id),
+                    ondragleave: move |_| functions::leave_drop_target(storage_controller, dir_id),
+                    ondrop: move |evt| {
+                        functions::transfer_dragged_items(storage_controller, ch, directory2.clone(), functions::is_copy_drop(evt.mouse.modifiers()));
+                    },
                     aria_label: "crumb",
                     p {
                         aria_label: "{folder_name_formatted}",
@@ -89,13 +114,74 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
     let send_files_mode = cx.props.send_files_mode;
     let storage_controller = cx.props.storage_controller;
     let ch = cx.props.ch;
+    let files_config = state.read().configuration.files.clone();
+    let is_list = !send_files_mode && files_config.storage_layout == StorageLayout::List;
+    let filter = storage_controller.read().filter.clone();
+    if filter.is_search() && !send_files_mode {
+        return cx.render(rsx!(StorageSearchResults {
+            storage_controller: storage_controller,
+            ch: ch,
+        }));
+    }
+
+    let now = Utc::now();
+    // folders can't be filtered by type, size or date, so they are hidden while filtering
+    let mut directories = if filter.is_active() {
+        vec![]
+    } else {
+        storage_controller.read().directories_list.clone()
+    };
+    let mut files: Vec<_> = storage_controller
+        .read()
+        .files_list
+        .iter()
+        .filter(|file| filter.matches(file, now))
+        .cloned()
+        .collect();
+    sort_directories(&mut directories, files_config.storage_sort);
+    sort_files(&mut files, files_config.storage_sort);
+    let visible_items: Vec<_> = directories
+        .iter()
+        .map(|dir| dir.id())
+        .chain(files.iter().map(|file| file.id()))
+        .collect();
+    if storage_controller.read().visible_items != visible_items {
+        storage_controller.write_silent().visible_items = visible_items.clone();
+    }
+
     cx.render(rsx!(span {
         class: "file-parent",
         background: format_args!("{}", if send_files_mode {"var(--secondary)"} else {""}),
+        (filter.is_active() && visible_items.is_empty()).then(|| rsx!(
+            div {
+                class: "no-files-div",
+                Label {
+                    text: get_local_text("files.no-results"),
+                }
+            }
+        )),
         div {
             id: "files-list",
-            class: "files-list",
+            class: format_args!("files-list {}", if is_list { "list" } else { "" }),
             aria_label: "files-list",
+            is_list.then(|| rsx!(
+                div {
+                    class: "files-list-header",
+                    aria_label: "files-list-header",
+                    SortColumn {
+                        sort_key: SortKey::Name,
+                    },
+                    SortColumn {
+                        sort_key: SortKey::Size,
+                    },
+                    SortColumn {
+                        sort_key: SortKey::Date,
+                    },
+                    SortColumn {
+                        sort_key: SortKey::Type,
+                    },
+                }
+            )),
             storage_controller.read().add_new_folder.then(|| {
                 rsx!(
                 Folder {
@@ -123,12 +209,17 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                      }
                 })
             }),
-            storage_controller.read().directories_list.iter().map(|dir| {
+            directories.iter().map(|dir| {
                 let folder_name = dir.name();
                 let folder_name2 = dir.name();
                 let folder_name3 = dir.name();
                 let key = dir.id();
                 let dir2 = dir.clone();
+                let dir3 = dir.clone();
+                let item = Item::from(dir.clone());
+                let item2 = item.clone();
+                let is_selected = storage_controller.read().selected_items.contains(&key);
+                let is_drop_target = storage_controller.read().drop_target == Some(key);
                 rsx!(
                     ContextMenu {
                         key: "{key}-menu",
@@ -142,57 +233,106 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                                     storage_controller.with_mut(|i| i.is_renaming_map = Some(key));
                                 }
                             },
+                            (!send_files_mode).then(|| rsx!(ContextItem {
+                                icon: Icon::ArrowDownCircle,
+                                aria_label: "folder-download".into(),
+                                text: get_local_text("files.download"),
+                                onpress: move |_| {
+                                    let items = storage_controller.read().items_with_selection(item2.clone());
+                                    download_items_as_zip(items, ch);
+                                }
+                            })),
                             hr {},
                             ContextItem {
                                 icon: Icon::Trash,
                                 danger: true,
                                 aria_label: "folder-delete".into(),
-                                text: get_local_text("uplink.delete"),
+                                text: get_local_text("files.move-to-trash"),
                                 onpress: move |_| {
                                     let item = Item::from(dir2.clone());
                                     ch.send(ChanCmd::DeleteItems(item));
                                 }
                             },
                         )),
-                        Folder {
-                            key: "{key}-folder",
-                            text: dir.name(),
-                            aria_label: dir.name(),
-                            with_rename:storage_controller.with(|i| i.is_renaming_map == Some(key)),
-                            onrename: move |(val, key_code)| {
-                                if val == folder_name3 {
-                                    storage_controller.with(|i| i.is_renaming_map.is_none());
-                                    storage_controller.write().finish_renaming_item(false);
-                                    return;
-                                };
-                                if storage_controller.read().directories_list.iter().any(|dir| dir.name() == val) {
-                                    state
-                                    .write()
-                                    .mutate(common::state::Action::AddToastNotification(
-                                        ToastNotification::init(
-                                            "".into(),
-                                            get_local_text("files.directory-already-with-name"),
-                                            None,
-                                            3,
-                                        ),
-                                    ));
+                        div {
+                            class: format_args!("folder-wrap {} {}", if is_selected { "selected" } else { "" }, if is_drop_target { "drop-target" } else { "" }),
+                            draggable: "{!send_files_mode}",
+                            prevent_default: "ondragover ondrop",
+                            onclick: move |evt| {
+                                if send_files_mode {
                                     return;
                                 }
-                                storage_controller.with_mut(|i| i.is_renaming_map = None);
-                                storage_controller.write().finish_renaming_item(false);
-                                if key_code == Code::Enter {
-                                    ch.send(ChanCmd::RenameItem{old_name: folder_name2.clone(), new_name: val});
+                                if evt.modifiers().is_empty() {
+                                    storage_controller.with_mut(|i| i.clear_selection());
+                                } else {
+                                    storage_controller.with_mut(|i| i.select_item(key, evt.modifiers()));
                                 }
                             },
-                            onpress: move |_| {
-                                storage_controller.with_mut(|i| i.is_renaming_map = None);
-                                ch.send(ChanCmd::OpenDirectory(folder_name.clone()));
-                            }
+                            ondragstart: move |_| {
+                                storage_controller.with_mut(|i| i.dragged_items = i.items_with_selection(item.clone()));
+                            },
+                            ondragend: move |_| {
+                                storage_controller.with_mut(|i| {
+                                    i.dragged_items.clear();
+                                    i.drop_target = None;
+                                });
+                            },
+                            ondragover: move |_| functions::enter_drop_target(storage_controller, key),
+                            ondragleave: move |_| functions::leave_drop_target(storage_controller, key),
+                            ondrop: move |evt| {
+                                functions::transfer_dragged_items(storage_controller, ch, dir3.clone(), functions::is_copy_drop(evt.mouse.modifiers()));
+                            },
+                            Folder {
+                                key: "{key}-folder",
+                                text: dir.name(),
+                                aria_label: dir.name(),
+                                with_rename:storage_controller.with(|i| i.is_renaming_map == Some(key)),
+                                onrename: move |(val, key_code)| {
+                                    if val == folder_name3 {
+                                        storage_controller.with(|i| i.is_renaming_map.is_none());
+                                        storage_controller.write().finish_renaming_item(false);
+                                        return;
+                                    };
+                                    if storage_controller.read().directories_list.iter().any(|dir| dir.name() == val) {
+                                        state
+                                        .write()
+                                        .mutate(common::state::Action::AddToastNotification(
+                                            ToastNotification::init(
+                                                "".into(),
+                                                get_local_text("files.directory-already-with-name"),
+                                                None,
+                                                3,
+                                            ),
+                                        ));
+                                        return;
+                                    }
+                                    storage_controller.with_mut(|i| i.is_renaming_map = None);
+                                    storage_controller.write().finish_renaming_item(false);
+                                    if key_code == Code::Enter {
+                                        ch.send(ChanCmd::RenameItem{old_name: folder_name2.clone(), new_name: val});
+                                    }
+                                },
+                                onpress: move |_| {
+                                    storage_controller.with_mut(|i| i.is_renaming_map = None);
+                                    ch.send(ChanCmd::OpenDirectory(folder_name.clone()));
+                                }
+                            },
+                            SyncBadge {
+                                status: sync_status(&format!("{}/{}", storage_controller.read().current_dir_path_as_string, dir.name())),
+                            },
+                            is_list.then(|| {
+                                let (size, date, kind) = functions::item_details(&Item::from(dir.clone()));
+                                rsx!(
+                                    span { class: "item-detail", "{size}" },
+                                    span { class: "item-detail", "{date}" },
+                                    span { class: "item-detail", "{kind}" },
+                                )
+                            })
                         }
                     }
                 )
             }),
-            storage_controller.read().files_list.iter().map(|file| {
+            files.iter().map(|file| {
                 let file_name = file.name();
                 let file_name2 = file.name();
                 let file_name3 = file.name();
@@ -203,6 +343,9 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                 let file3 = file.clone();
                 let key = file.id();
                 let file_id = file.id();
+                let item = Item::from(file.clone());
+                let item2 = item.clone();
+                let is_selected = storage_controller.read().selected_items.contains(&key);
                 rsx! {
                     ContextMenu {
                         key: "{key}-menu",
@@ -236,7 +379,12 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                                     aria_label: "files-download".into(),
                                     text: get_local_text("files.download"),
                                     onpress: move |_| {
-                                        download_file(&file_name2, ch, None);
+                                        let items = storage_controller.read().items_with_selection(item2.clone());
+                                        if items.len() > 1 {
+                                            download_items_as_zip(items, ch);
+                                        } else {
+                                            download_file(&file_name2, ch, None);
+                                        }
                                     },
                                 },
                                 hr {},
@@ -244,7 +392,7 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                                     icon: Icon::Trash,
                                     danger: true,
                                     aria_label: "files-delete".into(),
-                                    text: get_local_text("uplink.delete"),
+                                    text: get_local_text("files.move-to-trash"),
                                     onpress: move |_| {
                                         let item = Item::from(file2.clone());
                                         ch.send(ChanCmd::DeleteItems(item));
@@ -253,7 +401,27 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                             }
                         )),
                         div {
-                            class: "file-wrap",
+                            class: format_args!("file-wrap {}", if is_selected { "selected" } else { "" }),
+                            draggable: "{!send_files_mode}",
+                            onclick: move |evt| {
+                                if send_files_mode {
+                                    return;
+                                }
+                                if evt.modifiers().is_empty() {
+                                    storage_controller.with_mut(|i| i.clear_selection());
+                                } else {
+                                    storage_controller.with_mut(|i| i.select_item(key, evt.modifiers()));
+                                }
+                            },
+                            ondragstart: move |_| {
+                                storage_controller.with_mut(|i| i.dragged_items = i.items_with_selection(item.clone()));
+                            },
+                            ondragend: move |_| {
+                                storage_controller.with_mut(|i| {
+                                    i.dragged_items.clear();
+                                    i.drop_target = None;
+                                });
+                            },
                             FileCheckbox {
                                 file_path: file_path.clone(),
                                 storage_controller: storage_controller.clone(),
@@ -284,19 +452,6 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                                         ));
                                         return;
                                     }
-                                    if file3.thumbnail().is_empty() && !is_video(&file3.name()) && !is_audio(&file3.name()) {
-                                        state
-                                        .write()
-                                        .mutate(common::state::Action::AddToastNotification(
-                                            ToastNotification::init(
-                                                "".into(),
-                                                get_local_text("files.no-thumbnail-preview"),
-                                                None,
-                                                3,
-                                            ),
-                                        ));
-                                        return;
-                                    }
                                     let file4 = file3.clone();
                                     storage_controller.with_mut(|i| i.show_file_modal = Some(file4));
                                 },
@@ -326,7 +481,18 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
                                         ch.send(ChanCmd::RenameItem{old_name: file_name.clone(), new_name});
                                     }
                                 }
-                            }
+                            },
+                            SyncBadge {
+                                status: sync_status(&file_path),
+                            },
+                            is_list.then(|| {
+                                let (size, date, kind) = functions::item_details(&Item::from(file.clone()));
+                                rsx!(
+                                    span { class: "item-detail", "{size}" },
+                                    span { class: "item-detail", "{date}" },
+                                    span { class: "item-detail", "{kind}" },
+                                )
+                            })
                         }
                     }
                 }
@@ -334,3 +500,140 @@ pub fn FilesAndFolders<'a>(cx: Scope<'a, FilesAndFoldersProps<'a>>) -> Element<'
         },
     }))
 }
+
+#[derive(Props)]
+pub struct StorageSearchResultsProps<'a> {
+    storage_controller: &'a UseRef<StorageController>,
+    ch: &'a Coroutine<ChanCmd>,
+}
+
+/// Files matching the search anywhere in storage. Opening one goes to its folder first
+#[allow(non_snake_case)]
+pub fn StorageSearchResults<'a>(cx: Scope<'a, StorageSearchResultsProps<'a>>) -> Element<'a> {
+    let state = use_shared_state::<State>(cx)?;
+    let storage_controller = cx.props.storage_controller;
+    let ch = cx.props.ch;
+    let root = storage_controller.read().dirs_opened_ref.first().cloned();
+    let filter = storage_controller.read().filter.clone();
+    let mut results = root
+        .as_ref()
+        .map(|root| search_files(root, &filter, Utc::now()))
+        .unwrap_or_default();
+    sort_search_results(&mut results, state.read().configuration.files.storage_sort);
+    let home = get_local_text("uplink.home");
+
+    cx.render(rsx!(span {
+        class: "file-parent",
+        div {
+            class: "files-list list search-results",
+            aria_label: "search-results",
+            div {
+                class: "files-list-header",
+                aria_label: "files-list-header",
+                SortColumn {
+                    sort_key: SortKey::Name,
+                },
+                SortColumn {
+                    sort_key: SortKey::Size,
+                },
+                SortColumn {
+                    sort_key: SortKey::Date,
+                },
+                SortColumn {
+                    sort_key: SortKey::Type,
+                },
+            },
+            results.is_empty().then(|| rsx!(
+                div {
+                    class: "no-files-div",
+                    Label {
+                        text: get_local_text("files.no-results"),
+                    }
+                }
+            )),
+            results.iter().map(|result| {
+                let file = result.file.clone();
+                let key = file.id();
+                let directories = result.directories.clone();
+                let root = root.clone();
+                let location = std::iter::once(home.clone())
+                    .chain(result.directories.iter().cloned())
+                    .collect::<Vec<_>>()
+                    .join(" / ");
+                let (size, date, kind) = functions::item_details(&Item::from(file.clone()));
+                let name = file.name();
+                rsx!(div {
+                    key: "{key}-result",
+                    class: "search-result",
+                    aria_label: "search-result",
+                    title: "{name}",
+                    onclick: move |_| {
+                        if let Some(root) = root.clone() {
+                            ch.send(ChanCmd::BackToPreviousDirectory(root));
+                        }
+                        for directory in directories.iter() {
+                            ch.send(ChanCmd::OpenDirectory(directory.clone()));
+                        }
+                        storage_controller.with_mut(|i| i.show_file_modal = Some(file.clone()));
+                    },
+                    div {
+                        class: "search-result-name",
+                        IconElement {
+                            icon: return_correct_icon(&name),
+                        },
+                        div {
+                            p { "{name}" },
+                            p {
+                                class: "search-result-location",
+                                "{location}"
+                            }
+                        }
+                    },
+                    span { class: "item-detail", "{size}" },
+                    span { class: "item-detail", "{date}" },
+                    span { class: "item-detail", "{kind}" },
+                })
+            })
+        }
+    }))
+}
+
+#[derive(Props, PartialEq)]
+pub struct SyncBadgeProps {
+    status: Option<SyncStatus>,
+}
+
+/// Shows whether a file, or the files inside a folder, are in sync with the local sync folder
+#[allow(non_snake_case)]
+pub fn SyncBadge(cx: Scope<SyncBadgeProps>) -> Element {
+    let (class, icon, text) = match cx.props.status.clone()? {
+        SyncStatus::Synced => (
+            "synced",
+            Icon::CheckCircle,
+            get_local_text("files.sync-synced"),
+        ),
+        SyncStatus::Syncing => (
+            "syncing",
+            Icon::ArrowPath,
+            get_local_text("files.sync-syncing"),
+        ),
+        SyncStatus::Conflict => (
+            "conflict",
+            Icon::ExclamationTriangle,
+            get_local_text("files.sync-conflict"),
+        ),
+        SyncStatus::Failed(reason) => (
+            "failed",
+            Icon::XCircle,
+            get_local_text_with_args("files.sync-failed", vec![("reason", reason)]),
+        ),
+    };
+    cx.render(rsx!(span {
+        class: "sync-badge {class}",
+        aria_label: "sync-badge",
+        title: "{text}",
+        IconElement {
+            icon: icon,
+        }
+    }))
+}
@@ -14,7 +14,7 @@ use common::state::settings::GlobalShortcut;
 use common::state::ToastNotification;
 use common::warp_runner::ui_adapter::MessageEvent;
 use common::warp_runner::WarpEvent;
-use common::{get_extras_dir, warp_runner, STATIC_ARGS, WARP_CMD_CH, WARP_EVENT_CH};
+use common::{warp_runner, STATIC_ARGS, WARP_CMD_CH, WARP_EVENT_CH};
 
 use dioxus::prelude::*;
 use dioxus_desktop::tao::dpi::{LogicalPosition, PhysicalPosition};
@@ -262,7 +262,7 @@ fn app_layout(cx: Scope) -> Element {
             Toasts {},
             Outlet::<UplinkRoute>{},
             AppLogger {},
-            PrismScripts {},
+            MarkdownScripts {},
         },
     }
 }
@@ -322,7 +322,7 @@ pub fn get_app_style(state: &State) -> String {
         "".into()
     };
 
-    format!("{UIKIT_STYLES} {APP_STYLE} {PRISM_STYLE} {PRISM_THEME} {theme} {accent_color} {font_style} {open_dyslexic} {font_scale}")
+    format!("{UIKIT_STYLES} {APP_STYLE} {CODE_THEME} {theme} {accent_color} {font_style} {open_dyslexic} {font_scale}")
 }
 
 fn use_auto_updater(cx: &ScopeState) -> Option<()> {
@@ -741,6 +741,76 @@ fn use_app_coroutines(cx: &ScopeState) -> Option<()> {
         }
     });
 
+    // keeps the local sync folder and its storage folder the same. local changes are
+    // synced right away, changes in storage are picked up every minute
+    let local_sync = state.read().configuration.files.local_sync.clone();
+    use_future(cx, &local_sync, |local_sync| async move {
+        let local_path = match local_sync.local_path {
+            Some(path) if local_sync.enabled && !STATIC_ARGS.use_mock => path,
+            _ => return,
+        };
+        if let Err(e) = fs::create_dir_all(&local_path) {
+            log::error!("failed to create the sync folder: {e}");
+            return;
+        }
+        let (tx, mut rx) = futures::channel::mpsc::unbounded();
+        let mut watcher = match RecommendedWatcher::new(
+            move |res| {
+                let _ = tx.unbounded_send(res);
+            },
+            notify::Config::default(),
+        ) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                log::error!("{e}");
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(&local_path, RecursiveMode::Recursive) {
+            log::error!("{e}");
+            return;
+        }
+
+        let warp_cmd_tx = WARP_CMD_CH.tx.clone();
+        // a pass which is refused is tried again on every change, so the same error is only shown once
+        let mut last_error = None;
+        loop {
+            let (tx, sync_rx) = oneshot::channel();
+            if let Err(e) = warp_cmd_tx.send(WarpCmd::Constellation(ConstellationCmd::SyncFolder {
+                local_path: local_path.clone(),
+                remote_path: local_sync.remote_path.clone(),
+                rsp: tx,
+            })) {
+                log::error!("failed to send warp command: {}", e);
+                return;
+            }
+            match sync_rx.await {
+                Ok(Ok(())) => last_error = None,
+                Ok(Err(e)) => {
+                    log::error!("failed to sync the local folder: {e}");
+                    let error = e.to_string();
+                    if last_error.as_ref() != Some(&error) {
+                        let _ = ACTION_LISTENER.tx.send(ListenerAction::ToastAction {
+                            title: get_local_text("settings-files.local-sync"),
+                            content: error.clone(),
+                            icon: None,
+                            timeout: 6,
+                        });
+                        last_error = Some(error);
+                    }
+                }
+                Err(_) => return,
+            }
+
+            if let Ok(None) = tokio::time::timeout(Duration::from_secs(60), rx.next()).await {
+                return;
+            }
+            // a change usually comes with a burst of events, which are handled together
+            sleep(Duration::from_secs(2)).await;
+            while let Ok(Some(_)) = rx.try_next() {}
+        }
+    });
+
     // detect when new extensions are placed in the "extensions" folder, and load them.
     use_future(cx, (), |_| {
         to_owned![state];
@@ -2,6 +2,7 @@ use common::{
     icons::outline::Shape as Icon,
     language::get_local_text_with_args,
     state::pending_message::PendingMessage,
+    transfers::{TransferStatus, TRANSFERS},
     warp_runner::{ui_adapter::MessageEvent, WarpEvent},
     WARP_EVENT_CH,
 };
@@ -16,6 +17,7 @@ use tokio::sync::{
 use once_cell::sync::Lazy;
 use std::sync::Arc;
 use uuid::Uuid;
+use warp::constellation::Progression;
 use warp::raygun::{AttachmentEventStream, AttachmentKind, Location};
 
 pub enum ListenerAction {
@@ -128,6 +130,7 @@ pub fn chat_upload_stream_handler(
     )
 }
 
+/// Writes downloads to disk. Each one is a transfer, which this waits on while it is paused
 pub fn download_stream_handler(
     cx: &ScopeState,
 ) -> &UseRef<
@@ -136,20 +139,69 @@ pub fn download_stream_handler(
         String,
         std::pin::Pin<Box<dyn Future<Output = ()> + Send>>,
         bool,
+        Uuid,
     )>,
 > {
     async_queue(
         cx,
-        |(mut stream, file, on_finish, should_show_toast_notification): (
+        |(mut stream, file, on_finish, should_show_toast_notification, transfer): (
             warp::constellation::ConstellationProgressStream,
             String,
             std::pin::Pin<Box<dyn Future<Output = ()> + Send>>,
             bool,
+            Uuid,
         )| {
             async move {
-                while let Some(p) = stream.next().await {
+                let control = TRANSFERS.control(transfer);
+                let mut failure = None;
+                loop {
+                    if let Some(control) = control.as_ref() {
+                        if !control.proceed().await {
+                            break;
+                        }
+                    }
+                    let p = match stream.next().await {
+                        Some(p) => p,
+                        None => break,
+                    };
                     log::debug!("download progress: {p:?}");
+                    match p {
+                        Progression::CurrentProgress { current, .. } => {
+                            TRANSFERS.progress(transfer, current)
+                        }
+                        Progression::ProgressFailed { error, .. } => {
+                            failure = Some(error.unwrap_or_default());
+                            break;
+                        }
+                        Progression::ProgressComplete { .. } => {}
+                    }
+                }
+                // the stream holds the partial file open
+                drop(stream);
+                if control.map(|c| c.is_cancelled()).unwrap_or_default() {
+                    if let Some(path) = TRANSFERS.get(transfer).and_then(|t| t.partial_path) {
+                        let _ = tokio::fs::remove_file(path).await;
+                    }
+                    TRANSFERS.finish(transfer, TransferStatus::Cancelled);
+                    return;
+                }
+                if let Some(error) = failure {
+                    log::error!("failed to download {file}: {error}");
+                    TRANSFERS.finish(transfer, TransferStatus::Failed(error));
+                    if should_show_toast_notification {
+                        let _ = ACTION_LISTENER.tx.send(ListenerAction::ToastAction {
+                            title: "".into(),
+                            content: get_local_text_with_args(
+                                "files.download-failed",
+                                vec![("file", file)],
+                            ),
+                            icon: None,
+                            timeout: 2,
+                        });
+                    }
+                    return;
                 }
+                TRANSFERS.finish(transfer, TransferStatus::Completed);
                 if should_show_toast_notification {
                     let _ = ACTION_LISTENER.tx.send(ListenerAction::ToastAction {
                         title: "".into(),
@@ -1,19 +1,84 @@
-use std::path::PathBuf;
+use std::{future::Future, path::PathBuf, pin::Pin};
 
+use common::{
+    language::get_local_text_with_args,
+    transfers::{Transfer, TransferStatus, TRANSFERS},
+    warp_runner::{RayGunCmd, WarpCmd},
+    WARP_CMD_CH,
+};
 use futures::{future::BoxFuture, FutureExt};
+use uuid::Uuid;
+use warp::constellation::{file::File, ConstellationProgressStream};
+
+use super::async_task_queue::{ListenerAction, ACTION_LISTENER};
 
 /// Returns a temporary file for downloads and a handler for when the download finishes
 pub fn get_download_path(path: PathBuf) -> (PathBuf, BoxFuture<'static, ()>) {
     let mut temp = path.clone();
     temp.set_extension(".updownload");
-    let temp2 = temp.clone();
-    (
-        temp,
-        async move {
-            if let Err(e) = tokio::fs::rename(&temp2, &path).await {
-                log::error!("Unable to rename downloaded file: {e}");
-            }
+    (temp.clone(), finish_download(temp, path))
+}
+
+/// Moves a finished download from its temporary file to where it was saved
+pub fn finish_download(temp: PathBuf, path: PathBuf) -> BoxFuture<'static, ()> {
+    async move {
+        if let Err(e) = tokio::fs::rename(&temp, &path).await {
+            log::error!("Unable to rename downloaded file: {e}");
+        }
+    }
+    .boxed()
+}
+
+/// Starts a transfer for an attachment of a message. What is returned goes to `download_stream_handler`,
+/// which writes the file to `path`. A failure is shown in a toast
+pub async fn download_attachment(
+    conv_id: Uuid,
+    msg_id: Uuid,
+    file: &File,
+    path: PathBuf,
+) -> Option<(
+    ConstellationProgressStream,
+    String,
+    Pin<Box<dyn Future<Output = ()> + Send>>,
+    bool,
+    Uuid,
+)> {
+    let (temp_file_path, on_finish) = get_download_path(path.clone());
+    let transfer = Transfer::download(file.name(), path, temp_file_path.clone(), file.size());
+    let transfer_id = transfer.id;
+    TRANSFERS.start(transfer);
+
+    let (tx, rx) = futures::channel::oneshot::channel();
+    let res = match WARP_CMD_CH
+        .tx
+        .send(WarpCmd::RayGun(RayGunCmd::DownloadAttachment {
+            conv_id,
+            msg_id,
+            file_name: file.name(),
+            file_path_to_download: temp_file_path,
+            rsp: tx,
+        })) {
+        Ok(_) => rx
+            .await
+            .expect("command canceled")
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    match res {
+        Ok(stream) => Some((stream, file.name(), on_finish, true, transfer_id)),
+        Err(e) => {
+            log::error!("failed to download attachment: {}", e);
+            TRANSFERS.finish(transfer_id, TransferStatus::Failed(e));
+            let _ = ACTION_LISTENER.tx.send(ListenerAction::ToastAction {
+                title: "".into(),
+                content: get_local_text_with_args(
+                    "files.download-failed",
+                    vec![("file", file.name())],
+                ),
+                icon: None,
+                timeout: 2,
+            });
+            None
         }
-        .boxed(),
-    )
+    }
 }